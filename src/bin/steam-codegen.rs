@@ -0,0 +1,127 @@
+//! Reports the Web API methods this crate does not implement yet, and generates endpoint modules for them.
+//!
+//! ```text
+//! steam-codegen <api_list.json> [--src <dir>] [--out <dir>]
+//! ```
+//!
+//! Needs the `codegen` feature, e.g. `cargo run --features codegen --bin steam-codegen -- api_list.json`.
+//!
+//! `api_list.json` is a saved `GetSupportedAPIList` response. Implemented methods are read from `--src` (`src` by
+//! default). With `--out`, a module is written for every missing method, without overwriting existing files.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use steam_rs::{
+    codegen::{self, Coverage},
+    steam_webapi_util::get_supported_api_list::APIList,
+};
+
+const USAGE: &str = "Usage: steam-codegen <api_list.json> [--src <dir>] [--out <dir>]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut snapshot = None;
+    let mut src = PathBuf::from("src");
+    let mut out = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--src" => src = args.next().ok_or(USAGE)?.into(),
+            "--out" => out = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ if snapshot.is_none() => snapshot = Some(arg),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let snapshot = snapshot.ok_or(USAGE)?;
+
+    let json = fs::read_to_string(&snapshot).map_err(|error| format!("{snapshot}: {error}"))?;
+    let api_list = APIList::from_json(&json).map_err(|error| format!("{snapshot}: {error}"))?;
+    let coverage = Coverage::scan(&src).map_err(|error| format!("{}: {error}", src.display()))?;
+    let missing = codegen::missing(&api_list, &coverage);
+
+    let mut by_interface: BTreeMap<&str, Vec<_>> = BTreeMap::new();
+    for method in &missing {
+        by_interface
+            .entry(method.interface.name.as_str())
+            .or_default()
+            .push(method);
+    }
+
+    for (interface, methods) in &by_interface {
+        println!("{interface}");
+        for missing in methods {
+            println!(
+                "  - [ ] {} (v{})",
+                missing.method.name, missing.method.version
+            );
+        }
+    }
+    println!("{} methods missing", missing.len());
+
+    let Some(out) = out else {
+        return Ok(());
+    };
+
+    for (interface, methods) in &by_interface {
+        let module = codegen::module_name(interface);
+        let directory = out.join(&module);
+        let existing = src.join(&module).is_dir();
+        fs::create_dir_all(&directory)
+            .map_err(|error| format!("{}: {error}", directory.display()))?;
+
+        let mut modules = Vec::new();
+        for missing in methods {
+            write_new(
+                &directory.join(format!("{}.rs", missing.function)),
+                &codegen::generate(missing),
+            )?;
+            modules.push(missing.function.clone());
+        }
+
+        if !existing {
+            write_new(
+                &directory.join("mod.rs"),
+                &codegen::generate_interface(interface, &modules),
+            )?;
+        } else {
+            println!(
+                "Add to src/{module}/mod.rs: {}",
+                modules
+                    .iter()
+                    .map(|name| format!("pub mod {name};"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes `contents` to `path`, leaving existing files alone.
+fn write_new(path: &Path, contents: &str) -> Result<(), String> {
+    if path.exists() {
+        println!("Skipping {}, it already exists", path.display());
+        return Ok(());
+    }
+
+    fs::write(path, contents).map_err(|error| format!("{}: {error}", path.display()))?;
+    println!("Wrote {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! Splitting calls that take many SteamIDs into requests Steam accepts.
+
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::Arc,
+};
+
+use tokio::{sync::Semaphore, task::JoinSet};
+
+use crate::{errors::SteamError, steam_id::SteamId, Steam};
+
+/// How many SteamIDs endpoints like `GetPlayerSummaries` accept per call. Steam silently drops the rest.
+pub(crate) const MAX_STEAM_IDS_PER_CALL: usize = 100;
+
+impl Steam {
+    /// Calls `fetch` with `steam_ids`, de-duplicated, in chunks of at most [`MAX_STEAM_IDS_PER_CALL`], with at most
+    /// [`SteamBuilder::max_concurrent_requests`](crate::SteamBuilder::max_concurrent_requests) chunks in flight.
+    ///
+    /// The results are concatenated in the order of the chunks. The first error fails the call and cancels the
+    /// chunks still in flight.
+    pub(crate) async fn fan_out<T, F, Fut>(
+        &self,
+        steam_ids: Vec<SteamId>,
+        fetch: F,
+    ) -> Result<Vec<T>, SteamError>
+    where
+        T: Send + 'static,
+        F: Fn(Steam, Vec<SteamId>) -> Fut,
+        Fut: Future<Output = Result<Vec<T>, SteamError>> + Send + 'static,
+    {
+        let mut chunks: Vec<Vec<SteamId>> = dedup(steam_ids)
+            .chunks(MAX_STEAM_IDS_PER_CALL)
+            .map(<[SteamId]>::to_vec)
+            .collect();
+
+        // A single request needs no tasks, so small calls work outside a Tokio runtime too.
+        if chunks.len() <= 1 {
+            return fetch(self.clone(), chunks.pop().unwrap_or_default()).await;
+        }
+
+        let permits = Arc::new(Semaphore::new(self.max_concurrent_requests));
+        let mut tasks = JoinSet::new();
+        let count = chunks.len();
+        for (index, chunk) in chunks.into_iter().enumerate() {
+            let permits = permits.clone();
+            let request = fetch(self.clone(), chunk);
+            tasks.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                (index, request.await)
+            });
+        }
+
+        let mut results: Vec<Option<Vec<T>>> = (0..count).map(|_| None).collect();
+        while let Some(joined) = tasks.join_next().await {
+            let (index, result) = match joined {
+                Ok(joined) => joined,
+                Err(error) => std::panic::resume_unwind(error.into_panic()),
+            };
+            results[index] = Some(result?);
+        }
+
+        Ok(results.into_iter().flatten().flatten().collect())
+    }
+}
+
+/// Removes repeated SteamIDs, keeping the first occurrence of each.
+fn dedup(steam_ids: Vec<SteamId>) -> Vec<SteamId> {
+    let mut seen = HashSet::new();
+    steam_ids
+        .into_iter()
+        .filter(|steam_id| seen.insert(*steam_id))
+        .collect()
+}
+
+/// Maps every requested SteamID to its result, or to `None` if Steam returned nothing for it.
+pub(crate) fn by_steam_id<T>(
+    steam_ids: Vec<SteamId>,
+    results: Vec<T>,
+    key: impl Fn(&T) -> SteamId,
+) -> HashMap<SteamId, Option<T>> {
+    let mut map: HashMap<SteamId, Option<T>> = steam_ids
+        .into_iter()
+        .map(|steam_id| (steam_id, None))
+        .collect();
+    for result in results {
+        map.insert(key(&result), Some(result));
+    }
+    map
+}
@@ -0,0 +1,486 @@
+//! Generates endpoint modules from a saved `GetSupportedAPIList` response.
+//!
+//! This backs the `steam-codegen` binary, which compares an [`APIList`] with the endpoints implemented in `src/` and
+//! writes a module for every method that is missing:
+//!
+//! ```text
+//! cargo run --features codegen --bin steam-codegen -- api_list.json --out generated
+//! ```
+//!
+//! It is a development tool for working on this crate, only built with the `codegen` feature and not covered by
+//! semver.
+//!
+//! Generated modules follow the layout of the hand-written ones. Their response types keep the raw JSON fields and
+//! are meant to be replaced with typed fields before the module is added to the crate.
+
+use std::{collections::BTreeSet, fmt::Write, fs, io, path::Path};
+
+use crate::steam_webapi_util::get_supported_api_list::{APIList, Interface, Method, Parameter};
+
+/// The methods already implemented by the crate, as `(interface, method)` pairs, and the function names they use.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    methods: BTreeSet<(String, String)>,
+    functions: BTreeSet<String>,
+}
+
+impl Coverage {
+    /// Collects the implemented methods from a source tree laid out like this crate's `src/` directory.
+    ///
+    /// An interface is a directory whose `mod.rs` defines `INTERFACE`, and each of its files defining `ENDPOINT`
+    /// implements one method.
+    pub fn scan(src: &Path) -> io::Result<Self> {
+        let mut coverage = Coverage::default();
+
+        for entry in fs::read_dir(src)? {
+            let directory = entry?.path();
+            let Ok(module) = fs::read_to_string(directory.join("mod.rs")) else {
+                continue;
+            };
+            let Some(interface) = string_const(&module, "INTERFACE") else {
+                continue;
+            };
+
+            for file in fs::read_dir(&directory)? {
+                let source = fs::read_to_string(file?.path())?;
+                if let Some(method) = string_const(&source, "ENDPOINT") {
+                    coverage.insert(interface, method);
+                }
+                coverage.functions.extend(function_names(&source));
+            }
+        }
+
+        Ok(coverage)
+    }
+
+    /// Marks `interface/method` as implemented.
+    pub fn insert(&mut self, interface: &str, method: &str) {
+        self.methods
+            .insert((interface.to_string(), method.to_string()));
+    }
+
+    /// Marks `function` as taken, so generated methods on `Steam` are named differently.
+    pub fn reserve(&mut self, function: &str) {
+        self.functions.insert(function.to_string());
+    }
+
+    /// Returns whether `interface/method` is implemented.
+    pub fn contains(&self, interface: &str, method: &str) -> bool {
+        self.methods
+            .contains(&(interface.to_string(), method.to_string()))
+    }
+}
+
+/// A method listed by Steam but not implemented by the crate.
+#[derive(Debug, Clone)]
+pub struct MissingMethod<'a> {
+    /// The interface the method belongs to.
+    pub interface: &'a Interface,
+
+    /// The newest version of the method.
+    pub method: &'a Method,
+
+    /// The name of the generated function and module, prefixed with the interface's module name if the plain name
+    /// is already taken.
+    pub function: String,
+}
+
+/// Lists the methods of `api_list` that `coverage` lacks, keeping only the newest version of each.
+pub fn missing<'a>(api_list: &'a APIList, coverage: &Coverage) -> Vec<MissingMethod<'a>> {
+    let mut missing: Vec<MissingMethod> = Vec::new();
+
+    for interface in &api_list.interfaces {
+        for method in &interface.methods {
+            if coverage.contains(&interface.name, &method.name) {
+                continue;
+            }
+
+            match missing.iter_mut().find(|found| {
+                found.interface.name == interface.name && found.method.name == method.name
+            }) {
+                Some(found) if found.method.version < method.version => found.method = method,
+                Some(_) => {}
+                None => missing.push(MissingMethod {
+                    interface,
+                    method,
+                    function: String::new(),
+                }),
+            }
+        }
+    }
+
+    let mut taken = coverage.functions.clone();
+    for found in &mut missing {
+        let mut function = function_name(&found.method.name);
+        if taken.contains(&function) {
+            function = format!("{}_{function}", module_name(&found.interface.name));
+        }
+        taken.insert(function.clone());
+        found.function = function;
+    }
+
+    missing
+}
+
+/// The module name used for an interface, e.g. `player_service` for `IPlayerService`.
+pub fn module_name(interface: &str) -> String {
+    snake_case(interface.strip_prefix('I').unwrap_or(interface))
+}
+
+/// The function name used for a method, e.g. `get_supported_api_list` for `GetSupportedAPIList`.
+pub fn function_name(method: &str) -> String {
+    snake_case(method)
+}
+
+/// Generates the `mod.rs` of a new interface module declaring the given endpoint modules.
+pub fn generate_interface(interface: &str, modules: &[String]) -> String {
+    let mut source = String::new();
+    writeln!(source, "//! # Implements the `{interface}` interface").unwrap();
+    writeln!(source).unwrap();
+    writeln!(source, "const INTERFACE: &str = \"{interface}\";").unwrap();
+    writeln!(source).unwrap();
+    for module in modules {
+        writeln!(source, "pub mod {module};").unwrap();
+    }
+    source
+}
+
+/// Generates an endpoint module for a missing method.
+///
+/// Methods with optional parameters get a builder through `EndPoint!`, the others a plain method on `Steam`.
+pub fn generate(missing: &MissingMethod) -> String {
+    let MissingMethod {
+        interface,
+        method,
+        function,
+    } = missing;
+    // Builder and response types are named after the method, and the interface too if the function is prefixed.
+    let name = match function.strip_suffix(&function_name(&method.name)) {
+        Some("") | None => method.name.clone(),
+        Some(_) => format!(
+            "{}{}",
+            pascal_case(&module_name(&interface.name)),
+            method.name
+        ),
+    };
+    let response = format!("{name}Response");
+    let params: Vec<Param> = method
+        .parameters
+        .iter()
+        .flatten()
+        .filter(|parameter| parameter.name != "key")
+        .map(Param::new)
+        .collect();
+    let has_key = method
+        .parameters
+        .iter()
+        .flatten()
+        .any(|parameter| parameter.name == "key");
+    let builder = params.iter().any(|param| param.optional);
+    let uses_steam_id = params.iter().any(|param| param.ty == "SteamId");
+    let post = if method.http_method.eq_ignore_ascii_case("POST") {
+        "POST "
+    } else {
+        ""
+    };
+
+    let mut source = String::new();
+    let out = &mut source;
+    writeln!(out, "//! Implements the `{}` endpoint", method.name).unwrap();
+    writeln!(out, "//!").unwrap();
+    writeln!(
+        out,
+        "//! Generated from `GetSupportedAPIList`, replace the fields of [`{response}`] with typed ones."
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use serde::{{Deserialize, Serialize}};").unwrap();
+    writeln!(out, "use serde_json::{{Map, Value}};").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use crate::{{").unwrap();
+    writeln!(out, "    errors::SteamError,").unwrap();
+    if builder {
+        writeln!(out, "    macros::{{do_http, EndPoint}},").unwrap();
+    } else {
+        writeln!(out, "    macros::do_http,").unwrap();
+    }
+    writeln!(out, "    query::Query,").unwrap();
+    if uses_steam_id {
+        writeln!(out, "    steam_id::SteamId,").unwrap();
+    }
+    if !builder {
+        writeln!(out, "    Steam,").unwrap();
+    }
+    writeln!(out, "}};").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use super::INTERFACE;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "const ENDPOINT: &str = \"{}\";", method.name).unwrap();
+    writeln!(out, "const VERSION: &str = \"{}\";", method.version).unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "/// The response of `{}`.", method.name).unwrap();
+    writeln!(out, "#[derive(Debug, Clone, Deserialize, Serialize)]").unwrap();
+    writeln!(out, "pub struct {response} {{").unwrap();
+    writeln!(out, "    /// The fields returned by Steam.").unwrap();
+    writeln!(out, "    #[serde(flatten)]").unwrap();
+    writeln!(out, "    pub fields: Map<String, Value>,").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    let summary = format!(
+        "Calls the `{}` method of `{}`.",
+        method.name, interface.name
+    );
+    let mut query = String::from("Query::new()");
+    if has_key {
+        let key = if builder {
+            "&self.steam.api_key"
+        } else {
+            "&self.api_key"
+        };
+        write!(query, "\n            .param(\"key\", {key})").unwrap();
+    }
+    for param in &params {
+        let value = if builder {
+            format!("self.{}", param.ident)
+        } else {
+            param.ident.clone()
+        };
+        let call = match (param.array, param.optional) {
+            (true, true) => format!(".array(\"{}\", {value}.unwrap_or_default())", param.base),
+            (true, false) => format!(".array(\"{}\", {value})", param.base),
+            (false, true) => format!(".optional(\"{}\", {value})", param.name),
+            (false, false) => format!(".param(\"{}\", {value})", param.name),
+        };
+        write!(query, "\n            {call}").unwrap();
+    }
+
+    if builder {
+        writeln!(out, "EndPoint!(").unwrap();
+        writeln!(out, "    /// {summary}").unwrap();
+        writeln!(out, "    {function} -> {name}<'a> => {response} {{").unwrap();
+        for (label, optional) in [("required", false), ("optional", true)] {
+            writeln!(out, "        {label} {{").unwrap();
+            for param in params.iter().filter(|param| param.optional == optional) {
+                if let Some(description) = &param.description {
+                    writeln!(out, "            /// {description}").unwrap();
+                }
+                writeln!(
+                    out,
+                    "            {}: {},",
+                    param.ident,
+                    param.rust_type("'a ")
+                )
+                .unwrap();
+            }
+            writeln!(out, "        }}").unwrap();
+        }
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, ");").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "impl {name}<'_> {{").unwrap();
+        writeln!(out, "    /// Sends the request.").unwrap();
+        writeln!(
+            out,
+            "    pub async fn send(self) -> Result<{response}, SteamError> {{"
+        )
+        .unwrap();
+        writeln!(out, "        let url = format!(").unwrap();
+        writeln!(out, "            \"{{}}/{{}}/{{}}/v{{}}/\",").unwrap();
+        writeln!(
+            out,
+            "            self.steam.base_url, INTERFACE, ENDPOINT, VERSION"
+        )
+        .unwrap();
+        writeln!(out, "        );").unwrap();
+        writeln!(out, "        let query = {query};").unwrap();
+        writeln!(
+            out,
+            "        let response = do_http!(self.steam, {post}url, {response}, query);"
+        )
+        .unwrap();
+    } else {
+        writeln!(out, "impl Steam {{").unwrap();
+        writeln!(out, "    /// {summary}").unwrap();
+        if !params.is_empty() {
+            writeln!(out, "    ///").unwrap();
+            writeln!(out, "    /// # Arguments").unwrap();
+            writeln!(out, "    ///").unwrap();
+            for param in &params {
+                let description = param.description.as_deref().unwrap_or_default();
+                writeln!(out, "    /// * `{}` - {description}", param.ident).unwrap();
+            }
+        }
+        let arguments: String = params
+            .iter()
+            .map(|param| format!(", {}: {}", param.ident, param.rust_type("")))
+            .collect();
+        writeln!(
+            out,
+            "    pub async fn {function}(&self{arguments}) -> Result<{response}, SteamError> {{"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        let url = format!(\"{{}}/{{}}/{{}}/v{{}}/\", self.base_url, INTERFACE, ENDPOINT, VERSION);"
+        )
+        .unwrap();
+        writeln!(out, "        let query = {query};").unwrap();
+        writeln!(
+            out,
+            "        let response = do_http!(self, {post}url, {response}, query);"
+        )
+        .unwrap();
+    }
+    writeln!(out, "        Ok(response)").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    source
+}
+
+/// A method parameter as it appears in generated code.
+struct Param {
+    /// The name sent to Steam, e.g. `publishedfileids[0]`.
+    name: String,
+    /// The name without the array index, e.g. `publishedfileids`.
+    base: String,
+    /// The Rust identifier.
+    ident: String,
+    /// The Rust type of a single value.
+    ty: &'static str,
+    array: bool,
+    optional: bool,
+    description: Option<String>,
+}
+
+impl Param {
+    fn new(parameter: &Parameter) -> Self {
+        let (base, array) = match parameter.name.strip_suffix("[0]") {
+            Some(base) => (base.to_string(), true),
+            None => (parameter.name.clone(), false),
+        };
+        let ty = match parameter.r#type.as_str() {
+            "uint64" | "fixed64" if base == "steamid" => "SteamId",
+            "uint32" => "u32",
+            "uint64" | "fixed64" => "u64",
+            "int32" => "i32",
+            "int64" => "i64",
+            "bool" => "bool",
+            "float" => "f32",
+            "double" => "f64",
+            _ => "str",
+        };
+        let description = parameter
+            .description
+            .as_deref()
+            .map(|description| description.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|description| !description.is_empty());
+
+        Param {
+            ident: identifier(&base),
+            name: parameter.name.clone(),
+            base,
+            ty,
+            array,
+            optional: parameter.optional,
+            description,
+        }
+    }
+
+    /// The type of the argument, borrowing with `lifetime` (e.g. `"'a "`) where needed.
+    fn rust_type(&self, lifetime: &str) -> String {
+        match (self.array, self.ty) {
+            (true, "str") => format!("&{lifetime}[&{lifetime}str]"),
+            (true, ty) => format!("&{lifetime}[{ty}]"),
+            (false, "str") => format!("&{lifetime}str"),
+            (false, ty) => ty.to_string(),
+        }
+    }
+}
+
+/// Lists the names of the functions defined in Rust source, including those generated by `EndPoint!`.
+fn function_names(source: &str) -> impl Iterator<Item = String> + '_ {
+    source.lines().filter_map(|line| {
+        let line = line.trim_start();
+        let name = if let Some(rest) = line
+            .strip_prefix("pub async fn ")
+            .or_else(|| line.strip_prefix("pub fn "))
+        {
+            rest
+        } else if line.contains(" -> ") && line.ends_with('{') && !line.contains('(') {
+            line
+        } else {
+            return None;
+        };
+
+        let end = name.find(|c: char| !c.is_alphanumeric() && c != '_')?;
+        Some(name[..end].to_string())
+    })
+}
+
+/// Extracts the value of `const NAME: &str = "...";` from Rust source.
+fn string_const<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let start = source.find(&format!("const {name}: &str = \""))?;
+    let value = &source[start..];
+    let value = &value[value.find('"')? + 1..];
+    Some(&value[..value.find('"')?])
+}
+
+fn pascal_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            // The `s` of a plural acronym such as `IDs` is not the start of a word.
+            let plural = chars.get(i + 1) == Some(&'s')
+                && chars.get(i + 2).is_none_or(|after| after.is_uppercase());
+            let next_is_lower = !plural && chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
+
+/// Turns a parameter name into a valid Rust identifier.
+fn identifier(name: &str) -> String {
+    let mut ident: String = snake_case(name)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+
+    match ident.as_str() {
+        "as" | "async" | "await" | "box" | "break" | "const" | "continue" | "do" | "dyn"
+        | "else" | "enum" | "extern" | "false" | "final" | "fn" | "for" | "if" | "impl" | "in"
+        | "let" | "loop" | "match" | "mod" | "move" | "mut" | "override" | "priv" | "pub"
+        | "ref" | "return" | "static" | "struct" | "trait" | "true" | "try" | "type" | "typeof"
+        | "unsafe" | "use" | "virtual" | "where" | "while" | "yield" => format!("r#{ident}"),
+        _ => ident,
+    }
+}
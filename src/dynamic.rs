@@ -0,0 +1,392 @@
+//! Calls to arbitrary Web API methods, validated against the list Steam publishes.
+//!
+//! [`Steam::call_raw`] sends whatever it is given, so a misspelt method or parameter only shows up as a `404` or
+//! as Steam silently ignoring the parameter. A [`DynamicClient`] first checks each call against the
+//! [`APIList`] returned by [`Steam::get_supported_api_list`], which only lists the methods available to the
+//! client's API key, and refuses calls that do not match it.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use steam_rs::{dynamic::DynamicClient, query::Query, Steam};
+//!
+//! # async fn example() -> Result<(), steam_rs::errors::SteamError> {
+//! let steam = Steam::new(&std::env::var("STEAM_API_KEY").unwrap());
+//!
+//! // Load the list once, and cache `client.snapshot()` to skip this request next time.
+//! let client = DynamicClient::load(steam).await?;
+//!
+//! let bans = client
+//!     .call_raw(
+//!         "ISteamUser",
+//!         "GetPlayerBans",
+//!         1,
+//!         Query::new().param("steamids", 76561197960287930u64),
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    errors::SteamError,
+    query::Query,
+    steam_webapi_util::get_supported_api_list::{APIList, Interface, Method},
+    Steam,
+};
+
+/// Parameters every Web API method accepts, which the list leaves out.
+const COMMON_PARAMETERS: [&str; 2] = ["format", "input_json"];
+
+/// Why a call was refused before being sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CallError {
+    /// No interface has this name.
+    UnknownInterface {
+        /// The requested interface.
+        interface: String,
+        /// The closest existing interface, if any is close.
+        suggestion: Option<String>,
+    },
+
+    /// The interface has no method with this name, or it is not available to this API key.
+    UnknownMethod {
+        /// The requested interface.
+        interface: String,
+        /// The requested method.
+        method: String,
+        /// The closest existing method, if any is close.
+        suggestion: Option<String>,
+    },
+
+    /// The method exists, but not in this version.
+    UnknownVersion {
+        /// The requested interface.
+        interface: String,
+        /// The requested method.
+        method: String,
+        /// The requested version.
+        version: u32,
+        /// The versions that exist.
+        available: Vec<u32>,
+    },
+
+    /// A required parameter was not given.
+    MissingParameter {
+        /// The name of the parameter.
+        name: String,
+    },
+
+    /// The method takes no parameter with this name.
+    UnknownParameter {
+        /// The name of the parameter.
+        name: String,
+        /// The closest existing parameter, if any is close.
+        suggestion: Option<String>,
+    },
+
+    /// A parameter's value does not match its type.
+    InvalidParameter {
+        /// The name of the parameter.
+        name: String,
+        /// The type Steam expects, e.g. `uint32`.
+        expected: String,
+        /// The value that was given.
+        value: String,
+    },
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CallError::UnknownInterface {
+                interface,
+                suggestion,
+            } => {
+                write!(f, "Unknown interface `{interface}`")?;
+                did_you_mean(f, suggestion)
+            }
+            CallError::UnknownMethod {
+                interface,
+                method,
+                suggestion,
+            } => {
+                write!(f, "Unknown method `{interface}/{method}`")?;
+                did_you_mean(f, suggestion)
+            }
+            CallError::UnknownVersion {
+                interface,
+                method,
+                version,
+                available,
+            } => {
+                let available: Vec<String> = available.iter().map(|v| format!("v{v}")).collect();
+                write!(
+                    f,
+                    "`{interface}/{method}` has no version {version}, available: {}",
+                    available.join(", ")
+                )
+            }
+            CallError::MissingParameter { name } => {
+                write!(f, "Missing required parameter `{name}`")
+            }
+            CallError::UnknownParameter { name, suggestion } => {
+                write!(f, "Unknown parameter `{name}`")?;
+                did_you_mean(f, suggestion)
+            }
+            CallError::InvalidParameter {
+                name,
+                expected,
+                value,
+            } => write!(
+                f,
+                "Parameter `{name}` expects a value of type {expected}, got '{value}'"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CallError {}
+
+fn did_you_mean(f: &mut fmt::Formatter<'_>, suggestion: &Option<String>) -> fmt::Result {
+    match suggestion {
+        Some(suggestion) => write!(f, ", did you mean `{suggestion}`?"),
+        None => Ok(()),
+    }
+}
+
+/// A client that validates calls against the [`APIList`] before sending them with [`Steam::call`].
+#[derive(Debug, Clone)]
+pub struct DynamicClient {
+    steam: Steam,
+    api_list: APIList,
+}
+
+impl DynamicClient {
+    /// Creates a client validating calls against `api_list`.
+    pub fn new(steam: Steam, api_list: APIList) -> Self {
+        DynamicClient { steam, api_list }
+    }
+
+    /// Creates a client validating calls against the list of methods currently available to the client's API key.
+    pub async fn load(steam: Steam) -> Result<Self, SteamError> {
+        let api_list = steam.get_supported_api_list().await?;
+        Ok(DynamicClient::new(steam, api_list))
+    }
+
+    /// Creates a client from a snapshot taken with [`DynamicClient::snapshot`].
+    ///
+    /// The raw response of `GetSupportedAPIList` is accepted as well.
+    pub fn from_snapshot(steam: Steam, snapshot: &str) -> Result<Self, SteamError> {
+        Ok(DynamicClient::new(steam, APIList::from_json(snapshot)?))
+    }
+
+    /// Serializes the list of methods as JSON, to be cached and passed to [`DynamicClient::from_snapshot`].
+    pub fn snapshot(&self) -> String {
+        serde_json::to_string(&self.api_list).expect("APIList always serializes")
+    }
+
+    /// The list calls are validated against.
+    pub fn api_list(&self) -> &APIList {
+        &self.api_list
+    }
+
+    /// Checks a call against the list, returning the method it refers to.
+    ///
+    /// The `key` parameter may be left out if the client has an API key, since [`Steam::call`] adds it. `format` and
+    /// `input_json` are accepted by every method, so they are allowed without being listed.
+    pub fn validate(
+        &self,
+        interface: &str,
+        method: &str,
+        version: u32,
+        params: &Query,
+    ) -> Result<&Method, CallError> {
+        let found = self.find_interface(interface)?;
+        let method = find_method(found, method, version)?;
+        let parameters = method.parameters.as_deref().unwrap_or_default();
+
+        for (name, value) in params.pairs() {
+            if COMMON_PARAMETERS.contains(&name.as_str()) {
+                continue;
+            }
+
+            let parameter = parameters
+                .iter()
+                .find(|parameter| parameter.name == schema_name(name))
+                .ok_or_else(|| CallError::UnknownParameter {
+                    name: name.clone(),
+                    suggestion: closest(name, parameters.iter().map(|p| p.name.as_str())),
+                })?;
+
+            if !accepts(&parameter.r#type, value) {
+                return Err(CallError::InvalidParameter {
+                    name: name.clone(),
+                    expected: parameter.r#type.clone(),
+                    value: value.clone(),
+                });
+            }
+        }
+
+        // Arguments may also be given as the fields of `input_json`, with arrays as JSON arrays.
+        let input_json: Option<Value> = params
+            .pairs()
+            .iter()
+            .find(|(name, _)| name == "input_json")
+            .and_then(|(_, value)| serde_json::from_str(value).ok());
+
+        for parameter in parameters.iter().filter(|parameter| !parameter.optional) {
+            let given = params
+                .pairs()
+                .iter()
+                .any(|(name, _)| schema_name(name) == parameter.name)
+                || input_json
+                    .as_ref()
+                    .is_some_and(|json| json.get(parameter.name.trim_end_matches("[0]")).is_some());
+            let key_added = parameter.name == "key" && !self.steam.api_key.is_empty();
+
+            if !given && !key_added {
+                return Err(CallError::MissingParameter {
+                    name: parameter.name.clone(),
+                });
+            }
+        }
+
+        Ok(method)
+    }
+
+    /// Validates and sends a call, returning the decoded JSON response.
+    ///
+    /// The HTTP method is taken from the list.
+    pub async fn call_raw(
+        &self,
+        interface: &str,
+        method: &str,
+        version: u32,
+        params: Query,
+    ) -> Result<Value, SteamError> {
+        self.call(interface, method, version, params).await
+    }
+
+    /// Validates and sends a call, deserializing the JSON response into `T`.
+    pub async fn call<T: DeserializeOwned>(
+        &self,
+        interface: &str,
+        method: &str,
+        version: u32,
+        params: Query,
+    ) -> Result<T, SteamError> {
+        let found = self.validate(interface, method, version, &params)?;
+        let http_method = reqwest::Method::from_bytes(found.http_method.as_bytes())
+            .unwrap_or(reqwest::Method::GET);
+
+        self.steam
+            .call(interface, method, version, http_method, params)
+            .await
+    }
+
+    fn find_interface(&self, interface: &str) -> Result<&Interface, CallError> {
+        let interfaces = &self.api_list.interfaces;
+
+        interfaces
+            .iter()
+            .find(|found| found.name == interface)
+            .ok_or_else(|| CallError::UnknownInterface {
+                interface: interface.to_string(),
+                suggestion: closest(interface, interfaces.iter().map(|i| i.name.as_str())),
+            })
+    }
+}
+
+fn find_method<'a>(
+    interface: &'a Interface,
+    method: &str,
+    version: u32,
+) -> Result<&'a Method, CallError> {
+    let versions: Vec<&Method> = interface
+        .methods
+        .iter()
+        .filter(|found| found.name == method)
+        .collect();
+
+    if versions.is_empty() {
+        return Err(CallError::UnknownMethod {
+            interface: interface.name.clone(),
+            method: method.to_string(),
+            suggestion: closest(method, interface.methods.iter().map(|m| m.name.as_str())),
+        });
+    }
+
+    versions
+        .iter()
+        .find(|found| u32::from(found.version) == version)
+        .copied()
+        .ok_or_else(|| CallError::UnknownVersion {
+            interface: interface.name.clone(),
+            method: method.to_string(),
+            version,
+            available: versions.iter().map(|found| found.version.into()).collect(),
+        })
+}
+
+/// Maps an array element such as `publishedfileids[3]` to the name the list uses for it, `publishedfileids[0]`.
+fn schema_name(name: &str) -> String {
+    match name.split_once('[') {
+        Some((base, index))
+            if index
+                .strip_suffix(']')
+                .is_some_and(|index| index.parse::<u32>().is_ok()) =>
+        {
+            format!("{base}[0]")
+        }
+        _ => name.to_string(),
+    }
+}
+
+/// Returns whether `value` is valid for a parameter of type `kind`. Types that can't be checked as text are accepted.
+fn accepts(kind: &str, value: &str) -> bool {
+    match kind {
+        "uint32" => value.parse::<u32>().is_ok(),
+        "uint64" | "fixed64" => value.parse::<u64>().is_ok(),
+        "int32" => value.parse::<i32>().is_ok(),
+        "int64" => value.parse::<i64>().is_ok(),
+        "float" | "double" => value.parse::<f64>().is_ok(),
+        "bool" => matches!(value, "true" | "false" | "1" | "0"),
+        _ => true,
+    }
+}
+
+/// Finds the candidate closest to a misspelt `name`, if one is close enough to be a plausible typo.
+fn closest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<String> {
+    let name = name.to_lowercase();
+    let threshold = (name.chars().count() / 3).max(2);
+
+    candidates
+        .map(|candidate| (distance(&name, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+/// The Levenshtein distance between two strings.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
@@ -1,15 +1,18 @@
 use serde::Deserialize;
 use serde::Serialize;
-use serde_json::Value;
 
-use crate::errors::ErrorHandle;
 use crate::{
-    errors::EconServiceError,
-    macros::{do_http, gen_args},
-    Steam,
+    errors::SteamError,
+    ids::{AppId, AssetId, ClassId, InstanceId, TradeId},
+    macros::{do_http, EndPoint},
+    query::Query,
+    steam_id::SteamId,
 };
 
-const END_POINT: &str = "https://api.steampowered.com/IEconService/GetTradeHistory/v1/?";
+use super::INTERFACE;
+
+const ENDPOINT: &str = "GetTradeHistory";
+const VERSION: &str = "1";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeHistory {
@@ -20,8 +23,8 @@ pub struct TradeHistory {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Trade {
-    pub tradeid: String,
-    pub steamid_other: String,
+    pub tradeid: TradeId,
+    pub steamid_other: SteamId,
     pub time_init: u32,
     pub status: u32,
     pub assets_given: Option<Vec<Asset>>,
@@ -30,13 +33,13 @@ pub struct Trade {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Asset {
-    pub appid: u32,
+    pub appid: AppId,
     pub contextid: String,
-    pub assetid: String,
+    pub assetid: AssetId,
     pub amount: String,
-    pub classid: String,
-    pub instanceid: String,
-    pub new_assetid: String,
+    pub classid: ClassId,
+    pub instanceid: InstanceId,
+    pub new_assetid: AssetId,
     pub new_contextid: String,
 }
 
@@ -45,36 +48,50 @@ struct Wrapper {
     response: TradeHistory,
 }
 
-impl Steam {
-    pub async fn get_trade_history(
-        &self,
-        max_trades: u32,
-        start_after_time: u32,
-        start_after_trade_id: u64,
-        navigating_back: bool,
-        get_descriptions: bool,
-        language: &str,
-        include_failed: bool,
-        include_total: bool,
-    ) -> Result<TradeHistory, EconServiceError> {
-        let key = &self.api_key.clone();
-        let args = gen_args!(
-            key,
-            max_trades,
-            start_after_time,
-            start_after_trade_id,
-            navigating_back,
-            get_descriptions,
-            language,
-            include_failed,
-            include_total
-        );
-        let url = format!("{END_POINT}{args}");
-        let data = do_http!(url, Value, ErrorHandle, EconServiceError::GetTradeHistory);
-        let trade_history: Wrapper = ErrorHandle!(
-            serde_json::from_value(data.to_owned()),
-            EconServiceError::GetTradeHistory
+EndPoint!(
+    /// Get a history of trades.
+    get_trade_history -> GetTradeHistory<'a> => TradeHistory {
+        required {
+            /// The number of trades to return information for.
+            max_trades: u32,
+        }
+        optional {
+            /// The unix timestamp of the last trade shown on the previous page of results, or the current time.
+            start_after_time: u32,
+            /// The trade ID of the last trade shown on the previous page of results.
+            start_after_trade_id: TradeId,
+            /// The user wants the previous page of results, so return the previous `max_trades` trades before the start time and ID.
+            navigating_back: bool,
+            /// If set, the item display data for the items included in the returned trades will also be returned.
+            get_descriptions: bool,
+            /// The language to use when loading item display data.
+            language: &'a str,
+            /// If set, trades in status rollback_failed, escrow_rollback_failed, and failed will be included.
+            include_failed: bool,
+            /// If set, the total number of trades the account has participated in will be included in the response.
+            include_total: bool,
+        }
+    }
+);
+
+impl GetTradeHistory<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<TradeHistory, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("max_trades", self.max_trades)
+            .optional("start_after_time", self.start_after_time)
+            .optional("start_after_trade_id", self.start_after_trade_id)
+            .optional("navigating_back", self.navigating_back)
+            .optional("get_descriptions", self.get_descriptions)
+            .optional("language", self.language)
+            .optional("include_failed", self.include_failed)
+            .optional("include_total", self.include_total);
+        let trade_history: Wrapper = do_http!(self.steam, url, Wrapper, query);
         Ok(trade_history.response)
     }
 }
@@ -1,20 +1,22 @@
 use serde::Deserialize;
 use serde::Serialize;
-use serde_json::Value;
 
-use crate::errors::ErrorHandle;
 use crate::{
-    errors::EconServiceError,
-    macros::{do_http, gen_args},
-    Steam,
+    errors::SteamError,
+    ids::{AppId, AssetId, ClassId, InstanceId, TradeOfferId},
+    macros::{do_http, EndPoint},
+    query::Query,
 };
 
-const END_POINT: &str = "https://api.steampowered.com/IEconService/GetTradeOffer/v1/?";
+use super::INTERFACE;
+
+const ENDPOINT: &str = "GetTradeOffer";
+const VERSION: &str = "1";
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TradeOffer {
     #[serde(rename = "tradeofferid")]
-    pub id: String,
+    pub id: TradeOfferId,
     pub accountid_other: u32,
     pub message: String,
     pub expiration_time: u32,
@@ -32,11 +34,11 @@ pub struct TradeOffer {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Item {
-    pub appid: u32,
+    pub appid: AppId,
     pub contextid: String,
-    pub assetid: String,
-    pub classid: String,
-    pub instanceid: String,
+    pub assetid: AssetId,
+    pub classid: ClassId,
+    pub instanceid: InstanceId,
     pub amount: String,
     pub missing: bool,
     pub est_usd: String,
@@ -52,20 +54,32 @@ struct Wrapper {
     response: Offer,
 }
 
-impl Steam {
-    pub async fn get_trade_offer(
-        &self,
-        tradeofferid: u64,
-        language: &str,
-    ) -> Result<TradeOffer, EconServiceError> {
-        let key = &self.api_key.clone();
-        let args = gen_args!(key, tradeofferid, language);
-        let url = format!("{END_POINT}{args}");
-        let data = do_http!(url, Value, ErrorHandle, EconServiceError::GetTradeOffer);
-        let trade_offer: Wrapper = ErrorHandle!(
-            serde_json::from_value(data.to_owned()),
-            EconServiceError::GetTradeOffer
+EndPoint!(
+    /// Get details about a single trade offer.
+    get_trade_offer -> GetTradeOffer<'a> => TradeOffer {
+        required {
+            /// The trade offer to get details about.
+            tradeofferid: TradeOfferId,
+        }
+        optional {
+            /// The language to use for item descriptions.
+            language: &'a str,
+        }
+    }
+);
+
+impl GetTradeOffer<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<TradeOffer, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("tradeofferid", self.tradeofferid)
+            .optional("language", self.language);
+        let trade_offer: Wrapper = do_http!(self.steam, url, Wrapper, query);
         Ok(trade_offer.response.offer)
     }
 }
@@ -1,15 +1,17 @@
 use serde::Deserialize;
 use serde::Serialize;
-use serde_json::Value;
 
-use crate::errors::ErrorHandle;
 use crate::{
-    errors::EconServiceError,
-    macros::{do_http, gen_args},
-    Steam,
+    errors::SteamError,
+    ids::{AppId, AssetId, ClassId, InstanceId, TradeOfferId},
+    macros::{do_http, EndPoint},
+    query::Query,
 };
 
-const END_POINT: &str = "https://api.steampowered.com/IEconService/GetTradeOffers/v1/?";
+use super::INTERFACE;
+
+const ENDPOINT: &str = "GetTradeOffers";
+const VERSION: &str = "1";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeOffer {
@@ -19,7 +21,7 @@ pub struct TradeOffer {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OfferReceived {
-    pub tradeofferid: String,
+    pub tradeofferid: TradeOfferId,
     pub accountid_other: u32,
     pub message: String,
     pub expiration_time: u32,
@@ -29,11 +31,11 @@ pub struct OfferReceived {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Item {
-    pub appid: u32,
+    pub appid: AppId,
     pub contextid: String,
-    pub assetid: String,
-    pub classid: String,
-    pub instanceid: String,
+    pub assetid: AssetId,
+    pub classid: ClassId,
+    pub instanceid: InstanceId,
     pub amount: String,
     pub missing: bool,
 }
@@ -43,34 +45,47 @@ struct Wrapper {
     response: TradeOffer,
 }
 
-impl Steam {
-    pub async fn get_trade_offers(
-        &self,
-        get_sent_offers: bool,
-        get_received_offers: bool,
-        get_descriptions: bool,
-        language: &str,
-        active_only: bool,
-        historical_only: bool,
-        time_historical_cutoff: u32,
-    ) -> Result<TradeOffer, EconServiceError> {
-        let key = &self.api_key.clone();
-        let args = gen_args!(
-            key,
-            get_sent_offers,
-            get_received_offers,
-            get_descriptions,
-            language,
-            active_only,
-            historical_only,
-            time_historical_cutoff
-        );
-        let url = format!("{END_POINT}{args}");
-        let data: Value = do_http!(url, Value, ErrorHandle, EconServiceError::GetTradeOffers);
-        let trade_offer: Wrapper = ErrorHandle!(
-            serde_json::from_value(data.to_owned()),
-            EconServiceError::GetTradeOffers
+EndPoint!(
+    /// Get a list of sent or received trade offers.
+    get_trade_offers -> GetTradeOffers<'a> => TradeOffer {
+        required {
+        }
+        optional {
+            /// Request the list of sent offers.
+            get_sent_offers: bool,
+            /// Request the list of received offers.
+            get_received_offers: bool,
+            /// If set, the item display data for the items included in the returned trade offers will also be returned.
+            get_descriptions: bool,
+            /// The language to use when loading item display data.
+            language: &'a str,
+            /// Indicates we should only return offers which are still active, or offers that have changed in state since `time_historical_cutoff`.
+            active_only: bool,
+            /// Indicates we should only return offers which are not active.
+            historical_only: bool,
+            /// When `active_only` is set, offers updated since this unix timestamp will also be returned.
+            time_historical_cutoff: u32,
+        }
+    }
+);
+
+impl GetTradeOffers<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<TradeOffer, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .optional("get_sent_offers", self.get_sent_offers)
+            .optional("get_received_offers", self.get_received_offers)
+            .optional("get_descriptions", self.get_descriptions)
+            .optional("language", self.language)
+            .optional("active_only", self.active_only)
+            .optional("historical_only", self.historical_only)
+            .optional("time_historical_cutoff", self.time_historical_cutoff);
+        let trade_offer: Wrapper = do_http!(self.steam, url, Wrapper, query);
         Ok(trade_offer.response)
     }
 }
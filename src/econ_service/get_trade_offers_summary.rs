@@ -1,15 +1,16 @@
 use serde::Deserialize;
 use serde::Serialize;
-use serde_json::Value;
 
-use crate::errors::ErrorHandle;
 use crate::{
-    errors::EconServiceError,
-    macros::{do_http, gen_args},
-    Steam,
+    errors::SteamError,
+    macros::{do_http, EndPoint},
+    query::Query,
 };
 
-const END_POINT: &str = "https://api.steampowered.com/IEconService/GetTradeOffersSummary/v1/?";
+use super::INTERFACE;
+
+const ENDPOINT: &str = "GetTradeOffersSummary";
+const VERSION: &str = "1";
 
 //NOT FINISHED BRUH, DONT RUN OR COMPUTER BUSTS
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,24 +32,29 @@ struct Wrapper {
     response: TradeOffersSummary,
 }
 
-impl Steam {
-    pub async fn get_trade_offers_summary(
-        &self,
-        time_last_visit: u32,
-    ) -> Result<TradeOffersSummary, EconServiceError> {
-        let key = &self.api_key.clone();
-        let args = gen_args!(key, time_last_visit);
-        let url = format!("{END_POINT}{args}");
-        let data = do_http!(
-            url,
-            Value,
-            ErrorHandle,
-            EconServiceError::GetTradeOffersSummary
-        );
-        let trade_offers_summary: Wrapper = ErrorHandle!(
-            serde_json::from_value(data.to_owned()),
-            EconServiceError::GetTradeOffersSummary
+EndPoint!(
+    /// Get counts of pending and new trade offers.
+    get_trade_offers_summary -> GetTradeOffersSummary<'a> => TradeOffersSummary {
+        required {
+        }
+        optional {
+            /// A unix timestamp of the last time the user visited the trade offers page. Offers newer than this are counted as new.
+            time_last_visit: u32,
+        }
+    }
+);
+
+impl GetTradeOffersSummary<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<TradeOffersSummary, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .optional("time_last_visit", self.time_last_visit);
+        let trade_offers_summary: Wrapper = do_http!(self.steam, url, Wrapper, query);
         Ok(trade_offers_summary.response)
     }
 }
@@ -11,7 +11,10 @@
 //! Endpoints that require a publisher key are not likely to be
 //! implemented in the near future, as they cannot be tested by developers.
 
+const INTERFACE: &str = "IEconService";
+
 pub mod get_trade_history;
 pub mod get_trade_offer;
 pub mod get_trade_offers;
 pub mod get_trade_offers_summary;
+pub mod trade_url;
@@ -0,0 +1,172 @@
+//! Parsing and building trade offer URLs.
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+    query::Query,
+    steam_id::{community_url, AccountType, SteamId},
+};
+
+use super::{get_trade_offer::TradeOffer, get_trade_offers::OfferReceived};
+
+/// A trade offer URL, which lets anyone send its owner a trade offer.
+///
+/// It is made of the owner's account ID and a token the owner can regenerate to invalidate old links, e.g.
+/// `https://steamcommunity.com/tradeoffer/new/?partner=22202&token=AbCd1234`.
+///
+/// # Examples
+///
+/// ```
+/// use steam_rs::{econ_service::trade_url::TradeUrl, steam_id::SteamId};
+///
+/// let url: TradeUrl = "https://steamcommunity.com/tradeoffer/new/?partner=22202&token=AbCd1234"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(url.partner(), SteamId(76561197960287930));
+/// assert_eq!(url.token(), "AbCd1234");
+///
+/// let url = TradeUrl::new(SteamId(76561197960287930), "AbCd1234").unwrap();
+/// assert_eq!(
+///     url.to_string(),
+///     "https://steamcommunity.com/tradeoffer/new/?partner=22202&token=AbCd1234"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TradeUrl {
+    partner: SteamId,
+    token: String,
+}
+
+impl TradeUrl {
+    /// Creates the trade URL of `partner` with the given token.
+    ///
+    /// Fails if `partner` is not an individual account or `token` is not 8 letters, digits, `-` or `_`.
+    pub fn new(partner: SteamId, token: &str) -> Result<Self, TradeUrlError> {
+        if partner.get_account_type() != AccountType::Individual {
+            return Err(TradeUrlError::InvalidPartner(partner.to_string()));
+        }
+        if !is_token(token) {
+            return Err(TradeUrlError::InvalidToken(token.to_string()));
+        }
+
+        Ok(TradeUrl {
+            partner,
+            token: token.to_string(),
+        })
+    }
+
+    /// Parses a trade URL. The scheme and `www.` may be left out.
+    pub fn parse(input: &str) -> Result<Self, TradeUrlError> {
+        let invalid = || TradeUrlError::NotATradeUrl(input.to_string());
+
+        let (path, query) = community_url(input.trim())
+            .filter(|(host, _)| *host == "steamcommunity.com")
+            .and_then(|(_, path)| path.split_once('?'))
+            .ok_or_else(invalid)?;
+        if path.trim_end_matches('/') != "tradeoffer/new" {
+            return Err(invalid());
+        }
+
+        let query = Query::parse(query.split('#').next().unwrap_or_default());
+        let param = |name: &str| {
+            query
+                .pairs()
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        let partner = param("partner").ok_or_else(invalid)?;
+        let partner = partner
+            .parse()
+            .ok()
+            .filter(|account_id| *account_id != 0)
+            .map(SteamId::from_individual_account_id)
+            .ok_or_else(|| TradeUrlError::InvalidPartner(partner.to_string()))?;
+        let token = param("token").ok_or(TradeUrlError::MissingToken)?;
+
+        Self::new(partner, token)
+    }
+
+    /// Get the account trade offers made through this URL are sent to.
+    pub fn partner(&self) -> SteamId {
+        self.partner
+    }
+
+    /// Get the token authorizing trade offers to the partner.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl fmt::Display for TradeUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let query = Query::new()
+            .param("partner", self.partner.get_account_id())
+            .param("token", &self.token);
+        write!(
+            f,
+            "https://steamcommunity.com/tradeoffer/new/?{}",
+            query.encode()
+        )
+    }
+}
+
+impl FromStr for TradeUrl {
+    type Err = TradeUrlError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+/// Tokens are 8 letters, digits, `-` or `_`.
+fn is_token(token: &str) -> bool {
+    token.len() == 8
+        && token
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_')
+}
+
+/// Why a trade URL could not be parsed or built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TradeUrlError {
+    /// The input is not a `steamcommunity.com/tradeoffer/new/` link with a partner.
+    NotATradeUrl(String),
+    /// The partner is not the account ID or `SteamId` of an individual account.
+    InvalidPartner(String),
+    /// The link has no token.
+    MissingToken,
+    /// The token is not 8 letters, digits, `-` or `_`.
+    InvalidToken(String),
+}
+
+impl fmt::Display for TradeUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotATradeUrl(input) => write!(f, "`{input}` is not a trade offer URL"),
+            Self::InvalidPartner(partner) => {
+                write!(f, "`{partner}` is not an individual account")
+            }
+            Self::MissingToken => write!(f, "The trade offer URL has no token"),
+            Self::InvalidToken(token) => write!(f, "`{token}` is not a trade offer token"),
+        }
+    }
+}
+
+impl std::error::Error for TradeUrlError {}
+
+impl OfferReceived {
+    /// Get the `SteamId` of the other party of the trade offer.
+    pub fn partner(&self) -> SteamId {
+        SteamId::from_individual_account_id(self.accountid_other)
+    }
+}
+
+impl TradeOffer {
+    /// Get the `SteamId` of the other party of the trade offer.
+    pub fn partner(&self) -> SteamId {
+        SteamId::from_individual_account_id(self.accountid_other)
+    }
+}
@@ -1,6 +1,212 @@
 //! Definitions for errors
+//!
+//! Every endpoint returns a [`SteamError`]. The per-interface enums generated by the `error!` macro predate it and
+//! are kept so that existing code using them keeps compiling; they convert into [`SteamError`] with `?`.
 
-use crate::macros::error;
+use std::{error::Error, fmt, sync::Arc, time::Duration};
+
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{dynamic::CallError, macros::error, rate_limit::QuotaExceeded, schema::Drift};
+
+/// An error returned by a Steam Web API request.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum SteamError {
+    /// The request could not be sent, or its response could not be read.
+    Network(Arc<dyn Error + Send + Sync>),
+
+    /// Steam answered with an unexpected HTTP status.
+    Status {
+        /// The HTTP status code.
+        status: StatusCode,
+        /// The response body.
+        body: String,
+    },
+
+    /// The response body did not match the expected shape.
+    Decode {
+        /// Where in the document decoding failed, e.g. `response.players[0].steamid`.
+        path: String,
+        /// What went wrong at that path.
+        message: String,
+        /// The response body.
+        body: String,
+    },
+
+    /// Steam answered with `429 Too Many Requests`, even after retrying.
+    RateLimited {
+        /// How long Steam asked us to wait, if it said so.
+        retry_after: Option<Duration>,
+    },
+
+    /// Steam rejected the API key, or the key is missing (`401` or `403`).
+    Unauthorized {
+        /// The HTTP status code.
+        status: StatusCode,
+        /// The response body.
+        body: String,
+    },
+
+    /// The method or the requested resource does not exist (`404`).
+    NotFound {
+        /// The response body.
+        body: String,
+    },
+
+    /// Steam reported a failure in the response payload, e.g. `"success": false` alongside an error message.
+    Steam {
+        /// The message reported by Steam.
+        message: String,
+    },
+
+    /// The requested data belongs to a profile that is not public.
+    PrivateProfile,
+
+    /// The request was not sent because the daily quota is spent.
+    QuotaExceeded(QuotaExceeded),
+
+    /// The call was refused before being sent because it does not match the published list of methods.
+    InvalidCall(CallError),
+
+    /// The response contains fields the crate does not model, in [`SchemaMode::Strict`](crate::schema::SchemaMode::Strict).
+    SchemaDrift(Drift),
+
+    /// An error only described by a message, converted from one of the legacy per-interface error enums.
+    Other(String),
+}
+
+impl fmt::Display for SteamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SteamError::Network(error) => write!(f, "Network error: {error}"),
+            SteamError::Status { status, body } => {
+                write!(f, "Unexpected status {status}, alongside data: '{body}'")
+            }
+            SteamError::Decode { path, message, .. } => {
+                write!(f, "Failed to decode response at `{path}`: {message}")
+            }
+            SteamError::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(f, "Rate limited, retry after {}s", retry_after.as_secs()),
+            SteamError::RateLimited { retry_after: None } => write!(f, "Rate limited"),
+            SteamError::Unauthorized { status, .. } => {
+                write!(f, "Unauthorized ({status}), check the API key")
+            }
+            SteamError::NotFound { .. } => write!(f, "Not found"),
+            SteamError::Steam { message } => write!(f, "Steam reported an error: {message}"),
+            SteamError::PrivateProfile => write!(f, "The profile is not public"),
+            SteamError::QuotaExceeded(error) => write!(f, "{error}"),
+            SteamError::InvalidCall(error) => write!(f, "{error}"),
+            SteamError::SchemaDrift(drift) => write!(f, "{drift}"),
+            SteamError::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl Error for SteamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SteamError::Network(error) => Some(error.as_ref()),
+            SteamError::QuotaExceeded(error) => Some(error),
+            SteamError::InvalidCall(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<QuotaExceeded> for SteamError {
+    fn from(error: QuotaExceeded) -> Self {
+        SteamError::QuotaExceeded(error)
+    }
+}
+
+impl From<CallError> for SteamError {
+    fn from(error: CallError) -> Self {
+        SteamError::InvalidCall(error)
+    }
+}
+
+impl From<reqwest::Error> for SteamError {
+    fn from(error: reqwest::Error) -> Self {
+        SteamError::Network(Arc::new(error))
+    }
+}
+
+impl SteamError {
+    /// Classifies an unsuccessful response.
+    pub(crate) fn from_response(
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        body: String,
+    ) -> Self {
+        if let Some(error) = serde_json::from_str(&body)
+            .ok()
+            .and_then(|json: Value| reported_error(&json))
+        {
+            return error;
+        }
+
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                SteamError::Unauthorized { status, body }
+            }
+            StatusCode::NOT_FOUND => SteamError::NotFound { body },
+            StatusCode::TOO_MANY_REQUESTS => SteamError::RateLimited { retry_after },
+            _ => SteamError::Status { status, body },
+        }
+    }
+}
+
+/// Decodes a successful response body, surfacing failures Steam reports in the payload.
+pub(crate) fn decode<T: DeserializeOwned>(body: &str) -> Result<T, SteamError> {
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+    serde_path_to_error::deserialize(deserializer).map_err(|error| decode_error(body, error))
+}
+
+/// Converts a failure to decode `body`, surfacing failures Steam reports in the payload.
+pub(crate) fn decode_error(
+    body: &str,
+    error: serde_path_to_error::Error<serde_json::Error>,
+) -> SteamError {
+    // A payload that doesn't match is often Steam reporting an error in its own format.
+    if let Some(reported) = serde_json::from_str(body)
+        .ok()
+        .and_then(|json: Value| reported_error(&json))
+    {
+        return reported;
+    }
+
+    SteamError::Decode {
+        path: error.path().to_string(),
+        message: error.inner().to_string(),
+        body: body.to_string(),
+    }
+}
+
+fn reported_error(json: &Value) -> Option<SteamError> {
+    let objects = std::iter::once(json).chain(json.as_object()?.values());
+
+    objects
+        .filter(|object| object.get("success").and_then(Value::as_bool) == Some(false))
+        .map(|object| {
+            let message = object
+                .get("error")
+                .or_else(|| object.get("message"))
+                .and_then(Value::as_str)
+                .unwrap_or("Request failed")
+                .to_string();
+
+            if message.contains("not public") {
+                SteamError::PrivateProfile
+            } else {
+                SteamError::Steam { message }
+            }
+        })
+        .next()
+}
 
 error!(PublishedFileServiceError{
     QueryFiles(String)
@@ -77,11 +283,3 @@
 error!(SteamUserAuthError{
     AuthenticateUserTicket(String)
 });
-
-macro_rules! ErrorHandle {
-    ($function:expr, $error:expr) => {
-        $function.map_err(move |error| $error(error.to_string()))?
-    };
-}
-
-pub(crate) use ErrorHandle;
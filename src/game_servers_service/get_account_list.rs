@@ -1,13 +1,9 @@
 //! Implements the 'GetAccountList' endpoint.
 
 use serde::{Deserialize, Serialize};
-use serde_json::{from_value, Value};
 
 use crate::{
-    errors::{ErrorHandle, GameServersServiceError},
-    macros::do_http,
-    steam_id::SteamId,
-    Steam, BASE,
+    errors::SteamError, ids::AppId, macros::do_http, query::Query, steam_id::SteamId, Steam,
 };
 
 use super::INTERFACE;
@@ -19,7 +15,7 @@
 pub struct Server {
     #[serde(rename = "steamid")]
     pub server_steam_id: SteamId,
-    pub appid: u32,
+    pub appid: AppId,
     pub login_token: String,
     pub memo: String,
     pub is_deleted: bool,
@@ -43,19 +39,10 @@ struct Wrapper {
 
 impl Steam {
     /// Get the List of server accounts linked to the account the steam key is connected to
-    pub async fn get_account_list(&self) -> Result<AccountsResponse, GameServersServiceError> {
-        let query = format!("?key={}", &self.api_key);
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-        let json = do_http!(
-            url,
-            Value,
-            ErrorHandle,
-            GameServersServiceError::GetAccountList
-        );
-        let wrapper: Wrapper = ErrorHandle!(
-            from_value(json.to_owned()),
-            GameServersServiceError::GetAccountList
-        );
+    pub async fn get_account_list(&self) -> Result<AccountsResponse, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new().param("key", &self.api_key);
+        let wrapper: Wrapper = do_http!(self, url, Wrapper, query);
         Ok(wrapper.response)
     }
 }
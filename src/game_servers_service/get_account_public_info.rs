@@ -1,13 +1,9 @@
 //! Implements the 'GetAccountPublicInfo' endpoint.
 
 use serde::{Deserialize, Serialize};
-use serde_json::{from_value, Value};
 
 use crate::{
-    errors::{ErrorHandle, GameServersServiceError},
-    macros::do_http,
-    steam_id::SteamId,
-    Steam, BASE,
+    errors::SteamError, ids::AppId, macros::do_http, query::Query, steam_id::SteamId, Steam,
 };
 
 use super::INTERFACE;
@@ -24,7 +20,7 @@ pub struct Wrapper {
 pub struct PublicInfoResponse {
     #[serde(rename = "steamid")]
     server_steam_id: Option<SteamId>,
-    appid: Option<u32>,
+    appid: Option<AppId>,
 }
 
 impl Steam {
@@ -35,19 +31,12 @@ impl Steam {
     pub async fn get_account_public_info(
         &self,
         server_steam_id: SteamId,
-    ) -> Result<PublicInfoResponse, GameServersServiceError> {
-        let query = format!("?key={}&steamid={}", &self.api_key, server_steam_id.0);
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-        let json = do_http!(
-            url,
-            Value,
-            ErrorHandle,
-            GameServersServiceError::GetAccountPublicInfo
-        );
-        let wrapper: Wrapper = ErrorHandle!(
-            from_value(json.to_owned()),
-            GameServersServiceError::GetAccountPublicInfo
-        );
+    ) -> Result<PublicInfoResponse, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new()
+            .param("key", &self.api_key)
+            .param("steamid", server_steam_id);
+        let wrapper: Wrapper = do_http!(self, url, Wrapper, query);
         Ok(wrapper.response)
     }
 }
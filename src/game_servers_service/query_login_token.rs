@@ -1,14 +1,8 @@
 //! Implements the `QueryLoginToken` endpoint
 
 use serde::{Deserialize, Serialize};
-use serde_json::{from_value, Value};
 
-use crate::{
-    errors::{ErrorHandle, GameServersServiceError},
-    macros::do_http,
-    steam_id::SteamId,
-    Steam, BASE,
-};
+use crate::{errors::SteamError, macros::do_http, query::Query, steam_id::SteamId, Steam};
 
 use super::INTERFACE;
 
@@ -36,19 +30,12 @@ impl Steam {
     pub async fn query_login_token(
         &self,
         login_token: &str,
-    ) -> Result<LoginTokenResponse, GameServersServiceError> {
-        let query = format!("?key={}&login_token={}", &self.api_key, login_token);
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-        let json = do_http!(
-            url,
-            Value,
-            ErrorHandle,
-            GameServersServiceError::QueryLoginToken
-        );
-        let wrapper: Wrapper = ErrorHandle!(
-            from_value(json.to_owned()),
-            GameServersServiceError::QueryLoginToken
-        );
+    ) -> Result<LoginTokenResponse, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new()
+            .param("key", &self.api_key)
+            .param("login_token", login_token);
+        let wrapper: Wrapper = do_http!(self, url, Wrapper, query);
         Ok(wrapper.response)
     }
 }
@@ -0,0 +1,213 @@
+//! The HTTP path shared by every endpoint.
+
+use std::{io, sync::Arc};
+
+use reqwest::{
+    header::{self, HeaderValue},
+    Method,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    errors::{decode, SteamError},
+    query::Query,
+    retry::{parse_retry_after, RetryPolicy},
+    schema::{self, SchemaMode},
+    transport::{HttpRequest, HttpResponse, TransportError},
+    Steam,
+};
+
+impl Steam {
+    /// Sends a `GET` request and decodes the JSON response.
+    pub(crate) async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: Query,
+    ) -> Result<T, SteamError> {
+        let body = self.get(url, query).await?;
+        self.decode(url, &body)
+    }
+
+    /// Sends a `POST` request and decodes the JSON response.
+    pub(crate) async fn post_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: Query,
+    ) -> Result<T, SteamError> {
+        let body = self.post(url, query).await?;
+        self.decode(url, &body)
+    }
+
+    /// Decodes the JSON response to a request to `url` according to the client's [`SchemaMode`], reporting drift.
+    pub(crate) fn decode<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: &str,
+    ) -> Result<T, SteamError> {
+        if self.schema_mode == SchemaMode::Default {
+            return decode(body);
+        }
+
+        let endpoint = self.endpoint_of(url);
+        let result = schema::decode_with(self.schema_mode, endpoint, body);
+        let drift = match &result {
+            Ok((_, drift)) => drift,
+            Err(SteamError::SchemaDrift(drift)) => drift,
+            Err(_) => return result.map(|(value, _)| value),
+        };
+        if let Some(hook) = self.on_schema_drift.as_ref().filter(|_| !drift.is_empty()) {
+            (hook.0)(drift);
+        }
+
+        result.map(|(value, _)| value)
+    }
+
+    /// Sends a `GET` request, retrying according to the client's [`RetryPolicy`], and returns the body of a successful response.
+    pub(crate) async fn get(&self, url: &str, query: Query) -> Result<String, SteamError> {
+        self.request(Method::GET, url, query).await
+    }
+
+    /// Sends a `POST` request with the parameters in its body, retrying according to the client's [`RetryPolicy`],
+    /// and returns the body of a successful response.
+    pub(crate) async fn post(&self, url: &str, query: Query) -> Result<String, SteamError> {
+        self.request(Method::POST, url, query).await
+    }
+
+    /// Sends a request and returns the body of a successful response.
+    ///
+    /// `GET` requests carry the parameters in the query string, other methods carry them in a form-encoded body.
+    pub(crate) async fn request(
+        &self,
+        method: Method,
+        url: &str,
+        query: Query,
+    ) -> Result<String, SteamError> {
+        let response = self.send(build_request(method, url, query)).await?;
+        let body = read_bytes(response)?;
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+
+    /// Sends a request like [`Steam::request`], returning the raw bytes of a successful response.
+    #[cfg(feature = "protobuf")]
+    pub(crate) async fn request_bytes(
+        &self,
+        method: Method,
+        url: &str,
+        query: Query,
+    ) -> Result<Vec<u8>, SteamError> {
+        let response = self.send(build_request(method, url, query)).await?;
+        read_bytes(response)
+    }
+
+    /// Sends a request through the client's transport, applying rate limits, the quota and the [`RetryPolicy`].
+    pub(crate) async fn send(&self, request: HttpRequest) -> Result<HttpResponse, SteamError> {
+        let retry = self.retry_policy.allows(&request.method);
+        let interface = self.interface_of(&request.url);
+        let mut attempt = 1;
+
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire(interface).await;
+            }
+            if let Some(quota) = &self.quota {
+                quota.consume()?;
+            }
+
+            let result = self.transport.send(request.clone()).await;
+            if !retry || attempt >= self.retry_policy.get_max_attempts() {
+                return result.map_err(network_error);
+            }
+
+            let delay = match &result {
+                Ok(response) if RetryPolicy::is_retryable(response.status) => {
+                    self.retry_policy.delay(attempt, retry_after(response))
+                }
+                Err(error) if is_transient(error.as_ref()) => {
+                    self.retry_policy.delay(attempt, None)
+                }
+                _ => return result.map_err(network_error),
+            };
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Extracts the interface name (e.g. `ISteamUser`) from a request URL.
+    fn interface_of<'a>(&self, url: &'a str) -> Option<&'a str> {
+        let path = url
+            .strip_prefix(self.base_url.as_str())
+            .or_else(|| url.strip_prefix(self.partner_url.as_str()))?;
+
+        path.trim_start_matches('/').split(['/', '?']).next()
+    }
+
+    /// Extracts the method (e.g. `ISteamUser/GetFriendList/v1`) from a request URL.
+    fn endpoint_of<'a>(&self, url: &'a str) -> &'a str {
+        let path = url
+            .strip_prefix(self.base_url.as_str())
+            .or_else(|| url.strip_prefix(self.partner_url.as_str()))
+            .unwrap_or(url);
+
+        path.split('?').next().unwrap_or_default().trim_matches('/')
+    }
+}
+
+/// Builds a request carrying `query` in the query string for `GET`, and in a form-encoded body otherwise.
+fn build_request(method: Method, url: &str, query: Query) -> HttpRequest {
+    let mut request = HttpRequest::new(method, url);
+    if request.method == Method::GET {
+        request.query = query.into();
+    } else {
+        request.headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+        request.body = Some(query.encode().into_bytes());
+    }
+    request
+}
+
+/// Reads the body of a response, turning unsuccessful statuses into the matching [`SteamError`].
+fn read_bytes(response: HttpResponse) -> Result<Vec<u8>, SteamError> {
+    if response.status.is_success() {
+        return Ok(response.body);
+    }
+
+    let retry_after = retry_after(&response);
+    let body = String::from_utf8_lossy(&response.body).into_owned();
+    Err(SteamError::from_response(
+        response.status,
+        retry_after,
+        body,
+    ))
+}
+
+fn retry_after(response: &HttpResponse) -> Option<std::time::Duration> {
+    response
+        .headers
+        .get(header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_retry_after)
+}
+
+fn network_error(error: TransportError) -> SteamError {
+    SteamError::Network(Arc::from(error))
+}
+
+/// Returns whether a transport error is worth retrying: failing to connect, or timing out.
+fn is_transient(error: &(dyn std::error::Error + 'static)) -> bool {
+    if let Some(error) = error.downcast_ref::<reqwest::Error>() {
+        return error.is_connect() || error.is_timeout();
+    }
+
+    error.downcast_ref::<io::Error>().is_some_and(|error| {
+        matches!(
+            error.kind(),
+            io::ErrorKind::ConnectionRefused
+                | io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::TimedOut
+        )
+    })
+}
@@ -0,0 +1,160 @@
+//! Strongly typed IDs for apps, Workshop files, economy items and the like.
+//!
+//! Steam sends the same ID as a number in one response and as a string in another, so every type here deserializes
+//! from both. They serialize the way Steam usually sends them: 32-bit IDs as numbers, 64-bit IDs as strings, which
+//! keeps them exact in JavaScript.
+//!
+//! # Examples
+//!
+//! ```
+//! use steam_rs::ids::{AppId, PublishedFileId};
+//!
+//! let app: AppId = serde_json::from_str("440").unwrap();
+//! assert_eq!(app, AppId(440));
+//!
+//! let file: PublishedFileId = serde_json::from_str(r#""2007484441""#).unwrap();
+//! assert_eq!(file, PublishedFileId(2007484441));
+//! assert_eq!(serde_json::to_string(&file).unwrap(), r#""2007484441""#);
+//! ```
+
+use std::{fmt, marker::PhantomData, num::ParseIntError, str::FromStr};
+
+use serde::{
+    de::{self, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+macro_rules! id {
+    ($(#[$meta:meta])* $name:ident($inner:ty) as $form:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(pub $inner);
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl From<$inner> for $name {
+            fn from(value: $inner) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for $inner {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                value.parse().map($name)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_any(IdVisitor::<$inner>(PhantomData)).map($name)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                id!(@serialize $form, self, serializer)
+            }
+        }
+    };
+
+    (@serialize number, $id:expr, $serializer:expr) => {
+        $id.0.serialize($serializer)
+    };
+
+    (@serialize string, $id:expr, $serializer:expr) => {
+        $serializer.collect_str(&$id.0)
+    };
+}
+
+id!(
+    /// An app, e.g. `440` for Team Fortress 2.
+    AppId(u32) as number
+);
+
+id!(
+    /// A depot, the unit of content an app is downloaded in.
+    DepotId(u32) as number
+);
+
+id!(
+    /// A file published to the Steam Workshop, including collections.
+    PublishedFileId(u64) as string
+);
+
+id!(
+    /// The class of an economy item, shared by every item with the same appearance and description.
+    ClassId(u64) as string
+);
+
+id!(
+    /// The instance of an economy item class, distinguishing items of a class with differing properties.
+    InstanceId(u64) as string
+);
+
+id!(
+    /// An economy item in an inventory. It changes when the item is traded.
+    AssetId(u64) as string
+);
+
+id!(
+    /// A trade offer.
+    TradeOfferId(u64) as string
+);
+
+id!(
+    /// A completed trade, as listed in the trade history. It differs from the ID of the offer that led to it.
+    TradeId(u64) as string
+);
+
+id!(
+    /// A Steam group, as Steam's group endpoints name it (`gid`).
+    GroupId(u64) as string
+);
+
+/// Accepts an ID as a number, or as a string holding one.
+pub(crate) struct IdVisitor<T>(pub(crate) PhantomData<T>);
+
+impl<T> Visitor<'_> for IdVisitor<T>
+where
+    T: TryFrom<u64> + FromStr,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an ID as an integer or a string of digits")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        T::try_from(value).map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u64::try_from(value)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+            .and_then(|value| self.visit_u64(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+    }
+}
@@ -73,24 +73,264 @@
 pub mod steam_user_stats;
 pub mod steam_webapi_util;
 
+use std::sync::Arc;
+
+use rate_limit::{Quota, RateLimiter};
+use retry::RetryPolicy;
+use schema::{Drift, DriftHook, SchemaMode};
+use transport::{ReqwestTransport, Transport};
+
+mod bulk;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+pub mod dynamic;
 pub mod errors;
+mod http;
+pub mod ids;
 mod macros; // This remains private
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+pub mod query;
+pub mod rate_limit;
+mod raw;
+pub mod retry;
+pub mod schema;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod transport;
+pub mod vcr;
+
+// What `steam-codegen` generates for `tests/codegen.rs`, built with the tests to check generated modules compile.
+#[cfg(test)]
+#[path = "../tests/fixtures/codegen/store_service/mod.rs"]
+mod generated;
+
+/// Base URL of the public Steam Web API.
+pub const PUBLIC_BASE_URL: &str = "https://api.steampowered.com";
+
+/// Base URL of the Steamworks partner Web API, used by endpoints that require a publisher key.
+pub const PARTNER_BASE_URL: &str = "https://partner.steam-api.com";
 
 #[cfg(not(feature = "partner"))]
-const BASE: &str = "https://api.steampowered.com";
+const DEFAULT_BASE_URL: &str = PUBLIC_BASE_URL;
 
 #[cfg(feature = "partner")]
-const BASE: &str = "https://partner.steam-api.com";
+const DEFAULT_BASE_URL: &str = PARTNER_BASE_URL;
+
+/// How many requests a split call sends at once unless configured otherwise.
+const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 4;
 
 #[derive(Debug, Clone)]
 pub struct Steam {
     api_key: String,
+    base_url: String,
+    partner_url: String,
+    transport: Arc<dyn Transport>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    quota: Option<Quota>,
+    schema_mode: SchemaMode,
+    on_schema_drift: Option<DriftHook>,
+    max_concurrent_requests: usize,
 }
 
 impl Steam {
     pub fn new(api_key: &str) -> Steam {
+        Steam::builder().api_key(api_key).build()
+    }
+
+    /// Creates a [`SteamBuilder`] to configure a client before constructing it.
+    ///
+    /// ```
+    /// use steam_rs::Steam;
+    ///
+    /// // Point the client at a local stand-in server instead of Steam.
+    /// let steam = Steam::builder()
+    ///     .api_key("XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")
+    ///     .base_url("http://127.0.0.1:8080")
+    ///     .build();
+    ///
+    /// assert_eq!(steam.base_url(), "http://127.0.0.1:8080");
+    /// ```
+    pub fn builder() -> SteamBuilder {
+        SteamBuilder::default()
+    }
+
+    /// The base URL used for regular Web API requests.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// The base URL used for endpoints that require a publisher key.
+    pub fn partner_url(&self) -> &str {
+        &self.partner_url
+    }
+
+    /// How responses that don't match the crate's types are handled.
+    pub fn schema_mode(&self) -> SchemaMode {
+        self.schema_mode
+    }
+
+    /// The daily quota requests are counted against, if one was configured.
+    pub fn quota(&self) -> Option<&Quota> {
+        self.quota.as_ref()
+    }
+}
+
+impl Default for Steam {
+    /// Creates a client without an API key, which is enough for endpoints that do not require one.
+    fn default() -> Self {
+        Steam::builder().build()
+    }
+}
+
+/// Builder for a [`Steam`] client, created with [`Steam::builder()`].
+#[derive(Debug, Clone)]
+pub struct SteamBuilder {
+    api_key: String,
+    base_url: String,
+    partner_url: String,
+    transport: Option<Arc<dyn Transport>>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    quota: Option<Quota>,
+    schema_mode: SchemaMode,
+    on_schema_drift: Option<DriftHook>,
+    max_concurrent_requests: usize,
+}
+
+impl Default for SteamBuilder {
+    fn default() -> Self {
+        SteamBuilder {
+            api_key: String::new(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            partner_url: PARTNER_BASE_URL.to_string(),
+            transport: None,
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
+            quota: None,
+            schema_mode: SchemaMode::Default,
+            on_schema_drift: None,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+        }
+    }
+}
+
+impl SteamBuilder {
+    /// Sets the Steam Web API key sent with requests that need one.
+    pub fn api_key(mut self, api_key: &str) -> Self {
+        self.api_key = api_key.to_string();
+        self
+    }
+
+    /// Sets the base URL for regular Web API requests.
+    ///
+    /// Defaults to [`PUBLIC_BASE_URL`], or [`PARTNER_BASE_URL`] when the `partner` feature is enabled.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the base URL for endpoints that require a publisher key.
+    ///
+    /// Defaults to [`PARTNER_BASE_URL`].
+    pub fn partner_url(mut self, partner_url: &str) -> Self {
+        self.partner_url = partner_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the HTTP client used for every request.
+    ///
+    /// Use this to configure timeouts, proxies, a user agent or TLS settings.
+    /// The client is shared by every clone of the resulting [`Steam`], so connections are pooled.
+    /// This is shorthand for passing a [`ReqwestTransport`] to [`SteamBuilder::transport`].
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use steam_rs::Steam;
+    ///
+    /// let client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .user_agent("my-app/1.0")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let steam = Steam::builder().client(client).build();
+    /// ```
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.transport = Some(Arc::new(ReqwestTransport::new(client)));
+        self
+    }
+
+    /// Sets the [`Transport`] every request is sent through, replacing the default [`ReqwestTransport`].
+    ///
+    /// This overrides any client set with [`SteamBuilder::client`].
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Sets how requests failing with `429` or `5xx` responses are retried.
+    ///
+    /// Defaults to [`RetryPolicy::default()`]; use [`RetryPolicy::none()`] to disable retries.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limits how fast requests are sent. Every attempt, including retries, waits for a token.
+    ///
+    /// Pass clones of the same [`RateLimiter`] to clients sharing an API key so they share one budget.
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Counts requests against a daily [`Quota`], failing with a quota error instead of sending once it is spent.
+    pub fn quota(mut self, quota: Quota) -> Self {
+        self.quota = Some(quota);
+        self
+    }
+
+    /// Sets how responses that don't match the crate's types are handled.
+    ///
+    /// Defaults to [`SchemaMode::Default`]; see the [`schema`] module for the other modes.
+    pub fn schema_mode(mut self, schema_mode: SchemaMode) -> Self {
+        self.schema_mode = schema_mode;
+        self
+    }
+
+    /// Calls `hook` with every [`Drift`] found while decoding responses in [`SchemaMode::Lenient`] or
+    /// [`SchemaMode::Strict`].
+    pub fn on_schema_drift(mut self, hook: impl Fn(&Drift) + Send + Sync + 'static) -> Self {
+        self.on_schema_drift = Some(DriftHook(Arc::new(hook)));
+        self
+    }
+
+    /// Limits how many requests a single call sends at once when it has to be split, e.g.
+    /// [`Steam::get_player_summaries`] with more than 100 SteamIDs.
+    ///
+    /// Defaults to 4. A limit of 0 is treated as 1.
+    pub fn max_concurrent_requests(mut self, limit: usize) -> Self {
+        self.max_concurrent_requests = limit.max(1);
+        self
+    }
+
+    /// Constructs the [`Steam`] client.
+    pub fn build(self) -> Steam {
         Steam {
-            api_key: api_key.to_string(),
+            api_key: self.api_key,
+            base_url: self.base_url,
+            partner_url: self.partner_url,
+            transport: self
+                .transport
+                .unwrap_or_else(|| Arc::new(ReqwestTransport::default())),
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limiter,
+            quota: self.quota,
+            schema_mode: self.schema_mode,
+            on_schema_drift: self.on_schema_drift,
+            max_concurrent_requests: self.max_concurrent_requests,
         }
     }
 }
@@ -1,106 +1,11 @@
 macro_rules! do_http {
-    ($url:ident, $output_type:ty, $error_handle:ident, $error:expr) => {
-        if let Ok(response) = reqwest::get($url).await {
-
-            let status = response.status();
-
-            match status {
-                reqwest::StatusCode::OK => {$error_handle!({
-                    let text = response.text().await.unwrap();
-                    #[cfg(test)] println!("{}", text);
-                    serde_json::from_str::<$output_type>(&text)
-                }, $error)}
-                reqwest::StatusCode::NOT_FOUND => {$error_handle!(response.json::<$output_type>().await, $error)}
-                _ => {
-                    let data = response.text().await;
-                    let remark;
-                    if data.is_ok() {
-                        remark = format!(", alongside data: '{}'", data.unwrap());
-                    } else {
-                        remark = String::new();
-                    }
-
-                    return Err($error(format!(
-                        "Expected 200 Status, got {}{}",
-                        status, remark
-                    )));
-                }
-            }
-        } else {
-            // TODO: Make this more descriptive
-            return Err($error("HTTPS Error".to_string()));
-        }
-    };
-
     // Post Support
-    ($url:ident, $output_type:ty, $error_handle:ident, $error:expr, $json_data:ident) => {
-        if let Ok(response) = reqwest::Client::new()
-            .post($url)
-            .header("Content-Type", "application/json")
-            .body($json_data.to_owned())
-            .send()
-            .await
-        {
-            match response.status() {
-                reqwest::StatusCode::OK => {
-                    $error_handle!(response.json::<$output_type>().await, $error)
-                }
-
-                _ => {
-                    return Err($error(format!(
-                        "Expected 200 Status, got {}",
-                        response.status()
-                    )));
-                }
-            }
-        } else {
-            // TODO: Make this more descriptive
-            return Err($error("HTTPS Error".to_string()));
-        }
-    };
-
-    // Post support with debugging
-    ($url:ident, $output_type:ty, $error_handle:ident, $error:expr, $json_data:ident, $debug:literal) => {
-        if let Ok(response) = reqwest::post($url)
-            .with_header("Content-Type", "application/json")
-            .with_body($json_data.to_owned())
-            .send()
-        {
-            match response.status_code {
-                200 => $error_handle!(response.text().await, $error),
-
-                _ => {
-                    return Err($error(format!(
-                        "Expected 200 Status, got {}",
-                        response.status_code
-                    )));
-                }
-            }
-        } else {
-            // TODO: Make this more descriptive
-            return Err($error("HTTPS Error".to_string()));
-        }
-    };
-
-    ($url:ident, $output_type:ty) => {
-        reqwest::get($url).await?.json::<$output_type>()?
+    ($steam:expr, POST $url:ident, $output_type:ty, $query:expr) => {
+        $steam.post_json::<$output_type>(&$url, $query).await?
     };
 
-    ($url:ident, $error_handle:ident, $error:expr) => {
-        $error_handle!(
-            $error_handle!(reqwest::get($url).await?, $error).json()?,
-            $error
-        )
-    };
-    ($url:ident, $error:expr) => {
-        use crate::errors::ErrorHandle;
-        ErrorHandle!(
-            ErrorHandle!(reqwest::get($url).await?, $error).json().await,
-            $error
-        )
-    };
-    ($url:ident) => {
-        reqwest::get($url).await?.as_str()?
+    ($steam:expr, $url:ident, $output_type:ty, $query:expr) => {
+        $steam.get_json::<$output_type>(&$url, $query).await?
     };
 }
 
@@ -122,233 +27,150 @@ fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         }
 
         impl std::error::Error for $enum_name {}
-    };
-}
-
-pub(crate) use error;
-
-macro_rules! optional_argument {
-    () => { String::new() };
-
-    ($key:ident, $rename:literal) => {
-        match $key {
-            Some(value) => format!("&{}={}", String::from($rename), value),
-            None => String::new()
-        }
-    };
 
-    ($first:expr $(, $rest:expr)*) => {
-        match $first {
-            Some(value) => format!("&{}={:?}{}", stringify!($first), value,optional_argument!($($rest),*)),
-            None => format!("{}", optional_argument!($($rest),*) )
+        impl From<$enum_name> for $crate::errors::SteamError {
+            fn from(error: $enum_name) -> Self {
+                $crate::errors::SteamError::Other(error.to_string())
+            }
         }
     };
-
-
 }
 
-pub(crate) use optional_argument;
-
-#[macro_export]
-macro_rules! gen_args {
-    // Base case: When there are no variables left to concatenate, return an empty string.
-    () => { String::new() };
-
-    // Recursive case: Concatenate the first variable with the rest of the variables recursively.
-    ($first:expr $(, $rest:expr)*) => {
-        format!("&{}={}{}", stringify!($first), $first, gen_args!($($rest),*))
-    };
-}
-
-pub(crate) use gen_args;
+pub(crate) use error;
 
 // 2025 Marco
 
-// TODO: I am yet to decided whether this is needed
-// pub trait SteamAPI {
-//     fn endpoint_name() -> &'static str;
-// }
-
-/// Macro to generate a builder pattern for an endpoint if it is needed
+/// Generates a builder for an endpoint with optional parameters.
+///
+/// The builder is created by a method on [`Steam`](crate::Steam) taking the required arguments, has a setter for
+/// every optional argument, and is sent with `.send()` or by awaiting it. Unset options are left out of the request.
+///
+/// The endpoint module implements `send(self)` itself, mapping the fields onto the query:
+///
+/// ```ignore
+/// EndPoint!(
+///     /// Gets information about a player's recently played games.
+///     get_recently_played_games -> GetRecentlyPlayedGames<'a> => RecentlyPlayedGames {
+///         required {
+///             /// The SteamID of the player we're asking about.
+///             steam_id: SteamId,
+///         }
+///         optional {
+///             /// The number of games to return.
+///             count: u64,
+///         }
+///     }
+/// );
+///
+/// impl GetRecentlyPlayedGames<'_> {
+///     pub async fn send(self) -> Result<RecentlyPlayedGames, SteamError> { ... }
+/// }
+/// ```
 macro_rules! EndPoint {
-    (
-        $fn_name:ident, // Name of the function to generate
-        $struct_name:ident, // Name of the Builder struct (i.e. RequestNameReq or RequestNameBuilder)
-        $url:expr, // The endpoint URL, without any ?
-        $output_type:ty, // The Finial output type
-        ( $( $arg_name1:ident : $arg_ty1:ty ),* ),  // Required arguments
-        [ $( $arg_name2:ident : Option<$arg_ty2:ty> ),* ],  // Optional arguments
-        $body:item // the HTTP handler
+    (@constructor
+        $(#[$fn_meta:meta])*
+        $fn_name:ident -> $struct_name:ident<$lt:lifetime>
+        required {}
+        optional { $( $optional:ident ),* }
     ) => {
-        use serde::ser::{Serializer, SerializeStruct};
-        use crate::gen_args;
-
-        #[derive(Debug)]
-        pub struct $struct_name {
-            // #[serde(skip_serializing)]
-            _steam: Steam,
-            $(
-                $arg_name1: $arg_ty1,
-            )*
-            $(
-                $arg_name2: Option<$arg_ty2>,
-            )*
-        }
-
-        impl $struct_name {
-            $(
-                pub fn $arg_name2(mut self, value: $arg_ty2) -> Self {
-                    self.$arg_name2 = Some(value);
-                    self
+        impl $crate::Steam {
+            $(#[$fn_meta])*
+            ///
+            #[doc = concat!("Parameters are set on the returned [`", stringify!($struct_name), "`].")]
+            pub fn $fn_name<$lt>(&$lt self) -> $struct_name<$lt> {
+                $struct_name {
+                    steam: self,
+                    $(
+                        $optional: None,
+                    )*
                 }
-            )*
-
-            fn to_args(&self) -> String {
-                let mut req = String::new();
-                let key = self._steam.api_key.clone();
-                req.push_str(&gen_args!(key));
-                $(
-                    let $arg_name1 = self.$arg_name1.clone();
-                    req.push_str(&gen_args!($arg_name1));
-                )*
-                $(
-                    let $arg_name2 = &self.$arg_name2;
-                    req.push_str(&optional_argument!(&$arg_name2));
-                )*
-                req
-            }
-
-            fn to_url(&self) -> String {
-                format!("{}{}", $url, self.to_args())
-            }
-
-            $body
-
-            pub async fn send(&self) -> Result<$output_type, Box<dyn std::error::Error>> {
-                let url = self.to_url();
-                Ok(Self::internal(url).await?)
-            }
-        }
-
-        impl Serialize for $struct_name {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: Serializer,
-            {
-                let mut state = serializer.serialize_struct(stringify!($struct_name), 3)?;
-                $(
-                    state.serialize_field(stringify!($arg_name1), &self.$arg_name1)?;
-                )*
-                $(
-                    state.serialize_field(stringify!($arg_name2), &self.$arg_name2.as_ref().unwrap())?;
-                )*
-                state.end()
             }
         }
+    };
 
-        // impl SteamAPI for $struct_name {
-        //     fn endpoint_name() -> &'static str {
-        //         stringify!($fn_name)
-        //     }
-        // }
-
-        impl Steam {
-            pub fn $fn_name(&self, $( $arg_name1 : $arg_ty1 ),*) -> $struct_name {
-                let request = $struct_name {
-                    _steam: self.clone(),
+    (@constructor
+        $(#[$fn_meta:meta])*
+        $fn_name:ident -> $struct_name:ident<$lt:lifetime>
+        required { $( $(#[$required_meta:meta])* $required:ident : $required_ty:ty ),+ }
+        optional { $( $optional:ident ),* }
+    ) => {
+        impl $crate::Steam {
+            $(#[$fn_meta])*
+            ///
+            /// # Arguments
+            ///
+            $(
+                #[doc = concat!("* `", stringify!($required), "` -")]
+                $(#[$required_meta])*
+            )+
+            ///
+            #[doc = concat!("Optional parameters are set on the returned [`", stringify!($struct_name), "`].")]
+            pub fn $fn_name<$lt>(&$lt self, $( $required: $required_ty ),+) -> $struct_name<$lt> {
+                $struct_name {
+                    steam: self,
                     $(
-                        $arg_name1: $arg_name1,
-                    )*
+                        $required,
+                    )+
                     $(
-                        $arg_name2: None,
+                        $optional: None,
                     )*
-                };
-                request
+                }
             }
         }
     };
 
-    // DO NOT USE THIS PATTERN
     (
-        $fn_name:ident,
-        $struct_name:ident,
-        $output_type:ty,
-        $( $arg_name:ident : Option<$arg_ty:ty> ),*
+        $(#[$fn_meta:meta])*
+        $fn_name:ident -> $struct_name:ident<$lt:lifetime> => $output_type:ty {
+            required {
+                $( $(#[$required_meta:meta])* $required:ident : $required_ty:ty ),* $(,)?
+            }
+            optional {
+                $( $(#[$optional_meta:meta])* $optional:ident : $optional_ty:ty ),* $(,)?
+            }
+        }
     ) => {
-        pub struct $struct_name {
+        #[doc = concat!("Builder for [`Steam::", stringify!($fn_name), "`](crate::Steam::", stringify!($fn_name), ").")]
+        ///
+        /// Set optional parameters with the methods of the same name, then `.await` the builder or call `send()`.
+        #[derive(Debug, Clone)]
+        #[must_use = "requests do nothing unless awaited or sent"]
+        pub struct $struct_name<$lt> {
+            steam: &$lt $crate::Steam,
             $(
-                $arg_name: Option<$arg_ty>,
+                $required: $required_ty,
             )*
-        }
-
-        impl SteamAPI for $struct_name {
-            fn endpoint_name() -> &'static str {
-                stringify!($fn_name)
-            }
-        }
-
-        impl $struct_name {
             $(
-                pub fn $arg_name2(mut self, value: $arg_ty2) -> Self {
-                    self.$arg_name2 = Some(value);
-                    self
-                }
+                $optional: Option<$optional_ty>,
             )*
         }
 
-        impl Steam {
-            complie_error!("Not implemented");
-            pub fn $fn_name(&self) -> $struct_name {
-                let request = $struct_name {
-                    $(
-                        $arg_name2: None,
-                    )*
-                };
-                request
-            }
-        }
-        
+        $crate::macros::EndPoint!(@constructor
+            $(#[$fn_meta])*
+            $fn_name -> $struct_name<$lt>
+            required { $( $(#[$required_meta])* $required : $required_ty ),* }
+            optional { $( $optional ),* }
+        );
 
-    };
-
-    // DO NOT USE THIS PATTERN
-    (
-        $fn_name:ident,
-        $struct_name:ident,
-        $output_type:ty,
-        $( $arg_name:ident : $arg_ty:ty ),*
-    ) => {
-        #[derive(Debug)]
-        pub struct $struct_name {
-            _steam: Steam,
+        impl<$lt> $struct_name<$lt> {
             $(
-                $arg_name: $arg_ty,
+                $(#[$optional_meta])*
+                pub fn $optional(mut self, $optional: $optional_ty) -> Self {
+                    self.$optional = Some($optional);
+                    self
+                }
             )*
         }
 
-        impl SteamAPI for $struct_name {
-            fn endpoint_name() -> &'static str {
-                stringify!($fn_name)
-            }
-        }
+        impl<$lt> std::future::IntoFuture for $struct_name<$lt> {
+            type Output = Result<$output_type, $crate::errors::SteamError>;
+            type IntoFuture = $crate::transport::BoxFuture<$lt, Self::Output>;
 
-        impl Steam {
-            pub fn $fn_name(&self,
-                $( $arg_name : $arg_ty ),*
-            ) -> $struct_name {
-                complie_error!("Not implemented");
-                let request = $struct_name {
-                    _steam: self.clone(),
-                    $(
-                        $arg_name: $arg_name,
-                    )*
-                };
-                request
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(self.send())
             }
         }
     };
 }
 
 pub(crate) use EndPoint;
-
-// 2025 Marco
\ No newline at end of file
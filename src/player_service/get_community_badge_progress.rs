@@ -1,13 +1,12 @@
 //! Implements the `GetCommunityBadgeProgress` endpoint.
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
 use crate::{
-    errors::{ErrorHandle, PlayerServiceError},
-    macros::{do_http, optional_argument},
+    errors::SteamError,
+    macros::{do_http, EndPoint},
+    query::Query,
     steam_id::SteamId,
-    Steam, BASE,
 };
 
 use super::INTERFACE;
@@ -34,35 +33,32 @@ struct Wrapper {
     response: CommunityBadgeProgress,
 }
 
-impl Steam {
+EndPoint!(
     /// Gets all the quests needed to get the specified badge, and which are completed.
-    ///
-    /// # Arguments
-    ///
-    /// * `steam_id` - The SteamID of the player we're asking about.
-    /// * `badge_id` - The badge we're asking about.
-    pub async fn get_community_badge_progress(
-        &self,
-        steam_id: SteamId,
-        badge_id: Option<i32>,
-    ) -> Result<CommunityBadgeProgress, PlayerServiceError> {
-        let query = format!(
-            "?key={}&steamid={}{}",
-            &self.api_key,
-            steam_id,
-            optional_argument!(badge_id, "badgeid")
-        );
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-        let json = do_http!(
-            url,
-            Value,
-            ErrorHandle,
-            PlayerServiceError::GetCommunityBadgeProgress
-        );
-        let community_badge_progress: Wrapper = ErrorHandle!(
-            serde_json::from_value(json.to_owned()),
-            PlayerServiceError::GetCommunityBadgeProgress
+    get_community_badge_progress -> GetCommunityBadgeProgress<'a> => CommunityBadgeProgress {
+        required {
+            /// The SteamID of the player we're asking about.
+            steam_id: SteamId,
+        }
+        optional {
+            /// The badge we're asking about.
+            badge_id: i32,
+        }
+    }
+);
+
+impl GetCommunityBadgeProgress<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<CommunityBadgeProgress, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("steamid", self.steam_id)
+            .optional("badgeid", self.badge_id);
+        let community_badge_progress: Wrapper = do_http!(self.steam, url, Wrapper, query);
         Ok(community_badge_progress.response)
     }
 }
@@ -3,10 +3,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::{ErrorHandle, PlayerServiceError},
-    macros::{do_http, gen_args, optional_argument},
+    errors::SteamError,
+    ids::AppId,
+    macros::{do_http, EndPoint},
+    query::Query,
     steam_id::SteamId,
-    Steam, BASE,
 };
 
 use super::INTERFACE;
@@ -27,7 +28,7 @@ pub struct OwnedGames {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Game {
-    pub appid: u32,
+    pub appid: AppId,
     pub name: Option<String>,
     pub playtime_forever: u64,
     pub img_icon_url: Option<String>,
@@ -38,45 +39,73 @@ pub struct Game {
     pub rtime_last_played: Option<u64>,
 }
 
-impl Steam {
+#[derive(Serialize)]
+struct Input<'a> {
+    steamid: SteamId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_appinfo: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_played_free_games: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    appids_filter: Option<&'a [AppId]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_free_sub: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skip_unvetted_apps: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_extended_appinfo: Option<bool>,
+}
+
+EndPoint!(
     /// Return a list of games owned by the player.
-    ///
-    /// # Arguments
-    ///
-    /// * `steamid` - The SteamID of the player we're asking about.
-    /// * `include_appinfo` - True if we want additional details (name, icon) about each game.
-    /// * `include_played_free_games` - Free games are excluded by default. If this is set, free games the user has played will be returned.
-    /// * `appids_filter` - If set, restricts result set to the passed in apps.
-    /// * `include_free_sub` - Some games are in the free sub, which are excluded by default.
-    /// * `skip_unvetted_apps` - If set, skip unvetted store apps.
-    /// * `language` - Will return data in this language (english, french, etc.).
-    /// * `include_extended_appinfo` - True if we want even more details (capsule, sortas, and capabilities) about each game. include_appinfo must also be true.
-    pub async fn get_owned_games(
-        // TODO: Extensive testing for each argument
-        &self,
-        steamid: SteamId,
-        include_appinfo: bool,
-        include_played_free_games: bool,
-        appids_filter: u32, // TODO: should this be an option?
-        include_free_sub: bool,
-        skip_unvetted_apps: Option<bool>,
-        language: &str,
-        include_extended_appinfo: bool,
-    ) -> Result<OwnedGames, PlayerServiceError> {
-        let key = &self.api_key.clone();
-        let steamid = steamid.into_u64();
-        let args = gen_args!(
-            key,
-            steamid,
-            include_appinfo,
-            include_played_free_games,
-            appids_filter,
-            include_free_sub,
-            language,
-            include_extended_appinfo
-        ) + &optional_argument!(skip_unvetted_apps);
-        let url = format!("{BASE}/{INTERFACE}/{ENDPOINT}/v{VERSION}/?{args}");
-        let wrapper = do_http!(url, Wrapper, ErrorHandle, PlayerServiceError::GetOwnedGames);
+    get_owned_games -> GetOwnedGames<'a> => OwnedGames {
+        required {
+            /// The SteamID of the player we're asking about.
+            steamid: SteamId,
+        }
+        optional {
+            /// True if we want additional details (name, icon) about each game.
+            include_appinfo: bool,
+            /// Free games are excluded by default. If this is set, free games the user has played will be returned.
+            include_played_free_games: bool,
+            /// If set, restricts result set to the passed in apps.
+            appids_filter: &'a [AppId],
+            /// Some games are in the free sub, which are excluded by default.
+            include_free_sub: bool,
+            /// If set, skip unvetted store apps.
+            skip_unvetted_apps: bool,
+            /// Will return data in this language (english, french, etc.).
+            language: &'a str,
+            /// True if we want even more details (capsule, sortas, and capabilities) about each game. include_appinfo must also be true.
+            include_extended_appinfo: bool,
+        }
+    }
+);
+
+impl GetOwnedGames<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<OwnedGames, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
+        );
+        // `appids_filter` is only understood inside `input_json`.
+        let input = Input {
+            steamid: self.steamid,
+            include_appinfo: self.include_appinfo,
+            include_played_free_games: self.include_played_free_games,
+            appids_filter: self.appids_filter,
+            include_free_sub: self.include_free_sub,
+            skip_unvetted_apps: self.skip_unvetted_apps,
+            language: self.language,
+            include_extended_appinfo: self.include_extended_appinfo,
+        };
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .input_json(input);
+        let wrapper = do_http!(self.steam, url, Wrapper, query);
         Ok(wrapper.response)
     }
 }
@@ -1,13 +1,13 @@
 //! Implements the `GetRecentlyPlayedGames` endpoint.
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
 use crate::{
-    errors::{ErrorHandle, PlayerServiceError},
-    macros::{do_http, optional_argument},
+    errors::SteamError,
+    ids::AppId,
+    macros::{do_http, EndPoint},
+    query::Query,
     steam_id::SteamId,
-    Steam, BASE,
 };
 
 use super::INTERFACE;
@@ -17,7 +17,7 @@
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Game {
-    pub appid: u32,
+    pub appid: AppId,
     pub name: Option<String>,
     pub playtime_2weeks: Option<u32>,
     pub playtime_forever: u32,
@@ -36,30 +36,32 @@ struct Response {
     response: RecentlyPlayedGames,
 }
 
-impl Steam {
+EndPoint!(
     /// Gets information about a player's recently played games
-    ///
-    /// # Arguments
-    ///
-    /// * `steam_id` - The SteamID of the player we're asking about.
-    /// * `count` - The number of games to return.
-    pub async fn get_recently_played_games(
-        &self,
-        steam_id: SteamId,
-        count: Option<u64>,
-    ) -> Result<RecentlyPlayedGames, PlayerServiceError> {
-        let query = format!(
-            "?key={}&steamid={}{}",
-            &self.api_key,
-            steam_id,
-            optional_argument!(count)
-        );
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-        let json = do_http!(url, Value, ErrorHandle, PlayerServiceError::GetOwnedGames);
-        let recently_played_games: Response = ErrorHandle!(
-            serde_json::from_value(json.to_owned()),
-            PlayerServiceError::GetRecentlyPlayedGames
+    get_recently_played_games -> GetRecentlyPlayedGames<'a> => RecentlyPlayedGames {
+        required {
+            /// The SteamID of the player we're asking about.
+            steam_id: SteamId,
+        }
+        optional {
+            /// The number of games to return.
+            count: u64,
+        }
+    }
+);
+
+impl GetRecentlyPlayedGames<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<RecentlyPlayedGames, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("steamid", self.steam_id)
+            .optional("count", self.count);
+        let recently_played_games: Response = do_http!(self.steam, url, Response, query);
         Ok(recently_played_games.response)
     }
 }
@@ -1,14 +1,8 @@
 //! Implements the `GetSteamLevel` endpoint.
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
-use crate::{
-    errors::{ErrorHandle, PlayerServiceError},
-    macros::do_http,
-    steam_id::SteamId,
-    Steam, BASE,
-};
+use crate::{errors::SteamError, macros::do_http, query::Query, steam_id::SteamId, Steam};
 
 use super::INTERFACE;
 
@@ -31,14 +25,12 @@ impl Steam {
     /// # Arguments
     ///
     /// * `steam_id` - The SteamID of the player we're asking about.
-    pub async fn get_steam_level(&self, steam_id: SteamId) -> Result<u16, PlayerServiceError> {
-        let query = format!("?key={}&steamid={}", &self.api_key, steam_id);
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-        let json = do_http!(url, Value, ErrorHandle, PlayerServiceError::GetOwnedGames);
-        let player_level: Wrapper = ErrorHandle!(
-            serde_json::from_value(json.to_owned()),
-            PlayerServiceError::GetOwnedGames
-        );
+    pub async fn get_steam_level(&self, steam_id: SteamId) -> Result<u16, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new()
+            .param("key", &self.api_key)
+            .param("steamid", steam_id);
+        let player_level: Wrapper = do_http!(self, url, Wrapper, query);
         Ok(player_level.response.player_level)
     }
 }
@@ -0,0 +1,87 @@
+//! Protobuf-encoded requests to `I*Service` interfaces.
+//!
+//! Service interfaces accept their arguments as a base64-encoded protobuf message in `input_protobuf_encoded`, and
+//! answer with a protobuf message when asked for `format=protobuf_raw`. Some fields are only available this way.
+//!
+//! This module is only available with the `protobuf` feature. JSON remains what every other method uses.
+//!
+//! Each request message implements [`ServiceMethod`], naming the method it belongs to and its response message, and
+//! is sent with [`Steam::call_protobuf`]. Messages are provided for the `IPlayerService` methods the crate wraps and
+//! for `IPublishedFileService::QueryFiles`. Others, such as the `IEconService` methods, can be added by deriving
+//! [`prost::Message`] and implementing [`ServiceMethod`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use steam_rs::{protobuf::player_service::GetOwnedGamesRequest, Steam};
+//!
+//! # async fn example(steam: Steam) -> Result<(), steam_rs::errors::SteamError> {
+//! let request = GetOwnedGamesRequest {
+//!     steamid: Some(76561197960287930),
+//!     include_appinfo: Some(true),
+//!     appids_filter: vec![440, 570],
+//!     ..Default::default()
+//! };
+//!
+//! let owned_games = steam.call_protobuf(&request).await?;
+//! println!("{} games", owned_games.game_count.unwrap_or_default());
+//! # Ok(())
+//! # }
+//! ```
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use prost::Message;
+use reqwest::Method;
+
+use crate::{errors::SteamError, query::Query, Steam};
+
+pub mod player_service;
+pub mod published_file_service;
+
+/// A protobuf request message for a service method.
+pub trait ServiceMethod: Message + Default {
+    /// The interface, e.g. `"IPlayerService"`.
+    const INTERFACE: &'static str;
+
+    /// The method, e.g. `"GetOwnedGames"`.
+    const METHOD: &'static str;
+
+    /// The method version.
+    const VERSION: u32;
+
+    /// The message Steam answers with.
+    type Response: Message + Default;
+}
+
+impl Steam {
+    /// Sends a protobuf-encoded request to a service method and decodes the protobuf response.
+    ///
+    /// The request goes through the same rate limiter, quota and retries as every other request.
+    pub async fn call_protobuf<M: ServiceMethod>(
+        &self,
+        request: &M,
+    ) -> Result<M::Response, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.base_url,
+            M::INTERFACE,
+            M::METHOD,
+            M::VERSION
+        );
+        let mut query = Query::new();
+        if !self.api_key.is_empty() {
+            query = query.param("key", &self.api_key);
+        }
+        let query = query.param("format", "protobuf_raw").param(
+            "input_protobuf_encoded",
+            STANDARD.encode(request.encode_to_vec()),
+        );
+
+        let body = self.request_bytes(Method::GET, &url, query).await?;
+        M::Response::decode(body.as_slice()).map_err(|error| SteamError::Decode {
+            path: ".".to_string(),
+            message: error.to_string(),
+            body: STANDARD.encode(&body),
+        })
+    }
+}
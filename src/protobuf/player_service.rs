@@ -0,0 +1,294 @@
+//! Messages of the `IPlayerService` interface, numbered as in Steam's `service_player.proto`.
+
+use prost::Message;
+
+use super::ServiceMethod;
+
+const INTERFACE: &str = "IPlayerService";
+
+/// Request of `GetOwnedGames`.
+#[derive(Clone, PartialEq, Message)]
+pub struct GetOwnedGamesRequest {
+    /// The SteamID of the player we're asking about.
+    #[prost(uint64, optional, tag = "1")]
+    pub steamid: Option<u64>,
+
+    /// True if we want additional details (name, icon) about each game.
+    #[prost(bool, optional, tag = "2")]
+    pub include_appinfo: Option<bool>,
+
+    /// Free games are excluded by default. If this is set, free games the user has played will be returned.
+    #[prost(bool, optional, tag = "3")]
+    pub include_played_free_games: Option<bool>,
+
+    /// If set, restricts result set to the passed in apps.
+    #[prost(uint32, repeated, packed = "false", tag = "4")]
+    pub appids_filter: Vec<u32>,
+
+    /// Some games are in the free sub, which are excluded by default.
+    #[prost(bool, optional, tag = "5")]
+    pub include_free_sub: Option<bool>,
+
+    /// If set, skip unvetted store apps.
+    #[prost(bool, optional, tag = "6")]
+    pub skip_unvetted_apps: Option<bool>,
+
+    /// Will return data in this language (english, french, etc.).
+    #[prost(string, optional, tag = "7")]
+    pub language: Option<String>,
+
+    /// True if we want even more details (capsule, sortas, and capabilities) about each game.
+    #[prost(bool, optional, tag = "8")]
+    pub include_extended_appinfo: Option<bool>,
+}
+
+impl ServiceMethod for GetOwnedGamesRequest {
+    const INTERFACE: &'static str = INTERFACE;
+    const METHOD: &'static str = "GetOwnedGames";
+    const VERSION: u32 = 1;
+    type Response = GetOwnedGamesResponse;
+}
+
+/// Response of `GetOwnedGames`.
+#[derive(Clone, PartialEq, Message)]
+pub struct GetOwnedGamesResponse {
+    /// The number of games owned.
+    #[prost(uint32, optional, tag = "1")]
+    pub game_count: Option<u32>,
+
+    /// The games owned.
+    #[prost(message, repeated, tag = "2")]
+    pub games: Vec<Game>,
+}
+
+/// A game in the responses of `GetOwnedGames` and `GetRecentlyPlayedGames`.
+#[derive(Clone, PartialEq, Message)]
+pub struct Game {
+    /// The app ID of the game.
+    #[prost(int32, optional, tag = "1")]
+    pub appid: Option<i32>,
+
+    /// The name of the game.
+    #[prost(string, optional, tag = "2")]
+    pub name: Option<String>,
+
+    /// Minutes played in the last two weeks.
+    #[prost(int32, optional, tag = "3")]
+    pub playtime_2weeks: Option<i32>,
+
+    /// Minutes played in total.
+    #[prost(int32, optional, tag = "4")]
+    pub playtime_forever: Option<i32>,
+
+    /// The hash of the game's icon.
+    #[prost(string, optional, tag = "5")]
+    pub img_icon_url: Option<String>,
+
+    /// Whether the game has community visible stats.
+    #[prost(bool, optional, tag = "7")]
+    pub has_community_visible_stats: Option<bool>,
+
+    /// Minutes played on Windows.
+    #[prost(int32, optional, tag = "8")]
+    pub playtime_windows_forever: Option<i32>,
+
+    /// Minutes played on macOS.
+    #[prost(int32, optional, tag = "9")]
+    pub playtime_mac_forever: Option<i32>,
+
+    /// Minutes played on Linux.
+    #[prost(int32, optional, tag = "10")]
+    pub playtime_linux_forever: Option<i32>,
+
+    /// When the game was last played, as a Unix timestamp.
+    #[prost(uint32, optional, tag = "11")]
+    pub rtime_last_played: Option<u32>,
+
+    /// The file name of the game's capsule image.
+    #[prost(string, optional, tag = "12")]
+    pub capsule_filename: Option<String>,
+
+    /// The name the game is sorted by.
+    #[prost(string, optional, tag = "13")]
+    pub sort_as: Option<String>,
+
+    /// Whether the game has a workshop.
+    #[prost(bool, optional, tag = "14")]
+    pub has_workshop: Option<bool>,
+
+    /// Whether the game has items on the community market.
+    #[prost(bool, optional, tag = "15")]
+    pub has_market: Option<bool>,
+
+    /// Whether the game has DLC.
+    #[prost(bool, optional, tag = "16")]
+    pub has_dlc: Option<bool>,
+
+    /// Whether the game has leaderboards.
+    #[prost(bool, optional, tag = "17")]
+    pub has_leaderboards: Option<bool>,
+
+    /// The game's content descriptors.
+    #[prost(uint32, repeated, packed = "false", tag = "18")]
+    pub content_descriptorids: Vec<u32>,
+
+    /// Minutes played on the Steam Deck.
+    #[prost(int32, optional, tag = "19")]
+    pub playtime_deck_forever: Option<i32>,
+
+    /// Minutes played while offline.
+    #[prost(int32, optional, tag = "20")]
+    pub playtime_disconnected: Option<i32>,
+}
+
+/// Request of `GetRecentlyPlayedGames`.
+#[derive(Clone, PartialEq, Message)]
+pub struct GetRecentlyPlayedGamesRequest {
+    /// The SteamID of the player we're asking about.
+    #[prost(uint64, optional, tag = "1")]
+    pub steamid: Option<u64>,
+
+    /// The number of games to return.
+    #[prost(uint32, optional, tag = "2")]
+    pub count: Option<u32>,
+}
+
+impl ServiceMethod for GetRecentlyPlayedGamesRequest {
+    const INTERFACE: &'static str = INTERFACE;
+    const METHOD: &'static str = "GetRecentlyPlayedGames";
+    const VERSION: u32 = 1;
+    type Response = GetRecentlyPlayedGamesResponse;
+}
+
+/// Response of `GetRecentlyPlayedGames`.
+#[derive(Clone, PartialEq, Message)]
+pub struct GetRecentlyPlayedGamesResponse {
+    /// The number of games played in the last two weeks.
+    #[prost(uint32, optional, tag = "1")]
+    pub total_count: Option<u32>,
+
+    /// The games played in the last two weeks.
+    #[prost(message, repeated, tag = "2")]
+    pub games: Vec<Game>,
+}
+
+/// Request of `GetSteamLevel`.
+#[derive(Clone, PartialEq, Message)]
+pub struct GetSteamLevelRequest {
+    /// The SteamID of the player we're asking about.
+    #[prost(uint64, optional, tag = "1")]
+    pub steamid: Option<u64>,
+}
+
+impl ServiceMethod for GetSteamLevelRequest {
+    const INTERFACE: &'static str = INTERFACE;
+    const METHOD: &'static str = "GetSteamLevel";
+    const VERSION: u32 = 1;
+    type Response = GetSteamLevelResponse;
+}
+
+/// Response of `GetSteamLevel`.
+#[derive(Clone, PartialEq, Message)]
+pub struct GetSteamLevelResponse {
+    /// The player's Steam level.
+    #[prost(uint32, optional, tag = "1")]
+    pub player_level: Option<u32>,
+}
+
+/// Request of `GetBadges`.
+#[derive(Clone, PartialEq, Message)]
+pub struct GetBadgesRequest {
+    /// The SteamID of the player we're asking about.
+    #[prost(uint64, optional, tag = "1")]
+    pub steamid: Option<u64>,
+}
+
+impl ServiceMethod for GetBadgesRequest {
+    const INTERFACE: &'static str = INTERFACE;
+    const METHOD: &'static str = "GetBadges";
+    const VERSION: u32 = 1;
+    type Response = GetBadgesResponse;
+}
+
+/// Response of `GetBadges`.
+#[derive(Clone, PartialEq, Message)]
+pub struct GetBadgesResponse {
+    /// The player's badges.
+    #[prost(message, repeated, tag = "1")]
+    pub badges: Vec<Badge>,
+
+    /// The player's total XP.
+    #[prost(int32, optional, tag = "2")]
+    pub player_xp: Option<i32>,
+
+    /// The player's Steam level.
+    #[prost(int32, optional, tag = "3")]
+    pub player_level: Option<i32>,
+
+    /// The XP needed to reach the next level.
+    #[prost(int32, optional, tag = "4")]
+    pub player_xp_needed_to_level_up: Option<i32>,
+
+    /// The XP needed to reach the current level.
+    #[prost(int32, optional, tag = "5")]
+    pub player_xp_needed_current_level: Option<i32>,
+}
+
+/// A badge in the response of `GetBadges`.
+#[derive(Clone, PartialEq, Message)]
+pub struct Badge {
+    /// The ID of the badge.
+    #[prost(int32, optional, tag = "1")]
+    pub badgeid: Option<i32>,
+
+    /// The level of the badge.
+    #[prost(int32, optional, tag = "2")]
+    pub level: Option<i32>,
+
+    /// When the badge was earned, as a Unix timestamp.
+    #[prost(uint32, optional, tag = "3")]
+    pub completion_time: Option<u32>,
+
+    /// The XP the badge is worth.
+    #[prost(int32, optional, tag = "4")]
+    pub xp: Option<i32>,
+}
+
+/// Request of `GetCommunityBadgeProgress`.
+#[derive(Clone, PartialEq, Message)]
+pub struct GetCommunityBadgeProgressRequest {
+    /// The SteamID of the player we're asking about.
+    #[prost(uint64, optional, tag = "1")]
+    pub steamid: Option<u64>,
+
+    /// The badge to report progress for.
+    #[prost(int32, optional, tag = "2")]
+    pub badgeid: Option<i32>,
+}
+
+impl ServiceMethod for GetCommunityBadgeProgressRequest {
+    const INTERFACE: &'static str = INTERFACE;
+    const METHOD: &'static str = "GetCommunityBadgeProgress";
+    const VERSION: u32 = 1;
+    type Response = GetCommunityBadgeProgressResponse;
+}
+
+/// Response of `GetCommunityBadgeProgress`.
+#[derive(Clone, PartialEq, Message)]
+pub struct GetCommunityBadgeProgressResponse {
+    /// The quests of the badge.
+    #[prost(message, repeated, tag = "1")]
+    pub quests: Vec<Quest>,
+}
+
+/// A quest in the response of `GetCommunityBadgeProgress`.
+#[derive(Clone, PartialEq, Message)]
+pub struct Quest {
+    /// The ID of the quest.
+    #[prost(uint32, optional, tag = "1")]
+    pub questid: Option<u32>,
+
+    /// Whether the player completed the quest.
+    #[prost(bool, optional, tag = "2")]
+    pub completed: Option<bool>,
+}
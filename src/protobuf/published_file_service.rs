@@ -0,0 +1,334 @@
+//! Messages of the `IPublishedFileService` interface, numbered as in Steam's `steammessages_publishedfile.proto`.
+
+use prost::Message;
+
+use super::ServiceMethod;
+
+const INTERFACE: &str = "IPublishedFileService";
+
+/// Request of `QueryFiles`.
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryFilesRequest {
+    /// The type of query, see `EPublishedFileQueryType`.
+    #[prost(uint32, optional, tag = "1")]
+    pub query_type: Option<u32>,
+
+    /// The page of results to return, starting at 1.
+    #[prost(uint32, optional, tag = "2")]
+    pub page: Option<u32>,
+
+    /// The number of results per page.
+    #[prost(uint32, optional, tag = "3")]
+    pub numperpage: Option<u32>,
+
+    /// The app that created the files.
+    #[prost(uint32, optional, tag = "4")]
+    pub creator_appid: Option<u32>,
+
+    /// The app that consumes the files.
+    #[prost(uint32, optional, tag = "5")]
+    pub appid: Option<u32>,
+
+    /// Tags that must be present on a file.
+    #[prost(string, repeated, tag = "6")]
+    pub requiredtags: Vec<String>,
+
+    /// Tags that must not be present on a file.
+    #[prost(string, repeated, tag = "7")]
+    pub excludedtags: Vec<String>,
+
+    /// If true, a file must have every required tag, otherwise any of them.
+    #[prost(bool, optional, tag = "8")]
+    pub match_all_tags: Option<bool>,
+
+    /// Flags that must be set on a file.
+    #[prost(string, repeated, tag = "9")]
+    pub required_flags: Vec<String>,
+
+    /// Flags that must not be set on a file.
+    #[prost(string, repeated, tag = "10")]
+    pub omitted_flags: Vec<String>,
+
+    /// Text to match in the file's title or description.
+    #[prost(string, optional, tag = "11")]
+    pub search_text: Option<String>,
+
+    /// The type of file, see `EPublishedFileInfoMatchingFileType`.
+    #[prost(uint32, optional, tag = "12")]
+    pub filetype: Option<u32>,
+
+    /// Find all items that reference the given item.
+    #[prost(fixed64, optional, tag = "13")]
+    pub child_publishedfileid: Option<u64>,
+
+    /// The number of days to count votes over when ranking by trend.
+    #[prost(uint32, optional, tag = "14")]
+    pub days: Option<u32>,
+
+    /// When ranking by trend, only return files with votes within `days`.
+    #[prost(bool, optional, tag = "15")]
+    pub include_recent_votes_only: Option<bool>,
+
+    /// Only return the total number of files.
+    #[prost(bool, optional, tag = "16")]
+    pub totalonly: Option<bool>,
+
+    /// Return vote data.
+    #[prost(bool, optional, tag = "17")]
+    pub return_vote_data: Option<bool>,
+
+    /// Return tags.
+    #[prost(bool, optional, tag = "18")]
+    pub return_tags: Option<bool>,
+
+    /// Return key-value tags.
+    #[prost(bool, optional, tag = "19")]
+    pub return_kv_tags: Option<bool>,
+
+    /// Return preview images and videos.
+    #[prost(bool, optional, tag = "20")]
+    pub return_previews: Option<bool>,
+
+    /// Return the IDs of the file's children.
+    #[prost(bool, optional, tag = "21")]
+    pub return_children: Option<bool>,
+
+    /// Return a short description instead of the full one.
+    #[prost(bool, optional, tag = "22")]
+    pub return_short_description: Option<bool>,
+
+    /// Return pricing data, if applicable.
+    #[prost(bool, optional, tag = "30")]
+    pub return_for_sale_data: Option<bool>,
+
+    /// Return the file's metadata.
+    #[prost(bool, optional, tag = "32")]
+    pub return_metadata: Option<bool>,
+
+    /// The language to search in and return results in.
+    #[prost(int32, optional, tag = "33")]
+    pub language: Option<i32>,
+
+    /// Only return the IDs of the files.
+    #[prost(bool, optional, tag = "35")]
+    pub ids_only: Option<bool>,
+
+    /// Return playtime stats for the given number of days before today.
+    #[prost(uint32, optional, tag = "36")]
+    pub return_playtime_stats: Option<u32>,
+
+    /// Return the full details of each file.
+    #[prost(bool, optional, tag = "37")]
+    pub return_details: Option<bool>,
+
+    /// Strip BBCode from the descriptions.
+    #[prost(bool, optional, tag = "38")]
+    pub strip_description_bbcode: Option<bool>,
+
+    /// Cursor to paginate through the results, `"*"` for the first request.
+    #[prost(string, optional, tag = "39")]
+    pub cursor: Option<String>,
+}
+
+impl ServiceMethod for QueryFilesRequest {
+    const INTERFACE: &'static str = INTERFACE;
+    const METHOD: &'static str = "QueryFiles";
+    const VERSION: u32 = 1;
+    type Response = QueryFilesResponse;
+}
+
+/// Response of `QueryFiles`.
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryFilesResponse {
+    /// The number of files matching the query.
+    #[prost(uint32, optional, tag = "1")]
+    pub total: Option<u32>,
+
+    /// The files on this page.
+    #[prost(message, repeated, tag = "2")]
+    pub publishedfiledetails: Vec<PublishedFileDetails>,
+
+    /// The cursor of the next page.
+    #[prost(string, optional, tag = "3")]
+    pub next_cursor: Option<String>,
+}
+
+/// A file in the response of `QueryFiles`.
+#[derive(Clone, PartialEq, Message)]
+pub struct PublishedFileDetails {
+    /// The result of looking up the file, `1` on success.
+    #[prost(uint32, optional, tag = "1")]
+    pub result: Option<u32>,
+
+    /// The ID of the file.
+    #[prost(uint64, optional, tag = "2")]
+    pub publishedfileid: Option<u64>,
+
+    /// The SteamID of the file's creator.
+    #[prost(fixed64, optional, tag = "3")]
+    pub creator: Option<u64>,
+
+    /// The app that created the file.
+    #[prost(uint32, optional, tag = "4")]
+    pub creator_appid: Option<u32>,
+
+    /// The app that consumes the file.
+    #[prost(uint32, optional, tag = "5")]
+    pub consumer_appid: Option<u32>,
+
+    /// The file name.
+    #[prost(string, optional, tag = "7")]
+    pub filename: Option<String>,
+
+    /// The size of the file in bytes.
+    #[prost(uint64, optional, tag = "8")]
+    pub file_size: Option<u64>,
+
+    /// The size of the preview image in bytes.
+    #[prost(uint64, optional, tag = "9")]
+    pub preview_file_size: Option<u64>,
+
+    /// The URL of the file.
+    #[prost(string, optional, tag = "10")]
+    pub file_url: Option<String>,
+
+    /// The URL of the preview image.
+    #[prost(string, optional, tag = "11")]
+    pub preview_url: Option<String>,
+
+    /// The URL the file links to.
+    #[prost(string, optional, tag = "13")]
+    pub url: Option<String>,
+
+    /// The title of the file.
+    #[prost(string, optional, tag = "16")]
+    pub title: Option<String>,
+
+    /// The description of the file.
+    #[prost(string, optional, tag = "17")]
+    pub file_description: Option<String>,
+
+    /// The short description of the file.
+    #[prost(string, optional, tag = "18")]
+    pub short_description: Option<String>,
+
+    /// When the file was created, as a Unix timestamp.
+    #[prost(uint32, optional, tag = "19")]
+    pub time_created: Option<u32>,
+
+    /// When the file was last updated, as a Unix timestamp.
+    #[prost(uint32, optional, tag = "20")]
+    pub time_updated: Option<u32>,
+
+    /// Who can see the file, see `ERemoteStoragePublishedFileVisibility`.
+    #[prost(uint32, optional, tag = "21")]
+    pub visibility: Option<u32>,
+
+    /// Whether the file is banned.
+    #[prost(bool, optional, tag = "28")]
+    pub banned: Option<bool>,
+
+    /// Why the file is banned.
+    #[prost(string, optional, tag = "29")]
+    pub ban_reason: Option<String>,
+
+    /// The name of the consumer app.
+    #[prost(string, optional, tag = "33")]
+    pub app_name: Option<String>,
+
+    /// The type of file, see `EWorkshopFileType`.
+    #[prost(uint32, optional, tag = "34")]
+    pub file_type: Option<u32>,
+
+    /// The number of current subscribers.
+    #[prost(uint32, optional, tag = "36")]
+    pub subscriptions: Option<u32>,
+
+    /// The number of users who favorited the file.
+    #[prost(uint32, optional, tag = "37")]
+    pub favorited: Option<u32>,
+
+    /// The number of users following the file.
+    #[prost(uint32, optional, tag = "38")]
+    pub followers: Option<u32>,
+
+    /// The number of users who ever subscribed.
+    #[prost(uint32, optional, tag = "39")]
+    pub lifetime_subscriptions: Option<u32>,
+
+    /// The number of users who ever favorited the file.
+    #[prost(uint32, optional, tag = "40")]
+    pub lifetime_favorited: Option<u32>,
+
+    /// The number of users who ever followed the file.
+    #[prost(uint32, optional, tag = "41")]
+    pub lifetime_followers: Option<u32>,
+
+    /// The number of views.
+    #[prost(uint32, optional, tag = "42")]
+    pub views: Option<u32>,
+
+    /// The number of child files.
+    #[prost(uint32, optional, tag = "49")]
+    pub num_children: Option<u32>,
+
+    /// The file's tags.
+    #[prost(message, repeated, tag = "52")]
+    pub tags: Vec<Tag>,
+
+    /// The votes on the file.
+    #[prost(message, optional, tag = "53")]
+    pub vote_data: Option<VoteData>,
+
+    /// The file's key-value tags.
+    #[prost(message, repeated, tag = "54")]
+    pub kvtags: Vec<KvTag>,
+
+    /// The file's metadata.
+    #[prost(string, optional, tag = "58")]
+    pub metadata: Option<String>,
+}
+
+/// A tag of a file.
+#[derive(Clone, PartialEq, Message)]
+pub struct Tag {
+    /// The tag.
+    #[prost(string, optional, tag = "1")]
+    pub tag: Option<String>,
+
+    /// Whether only admins can set the tag.
+    #[prost(bool, optional, tag = "2")]
+    pub adminonly: Option<bool>,
+
+    /// The name the tag is displayed as.
+    #[prost(string, optional, tag = "3")]
+    pub display_name: Option<String>,
+}
+
+/// The votes on a file.
+#[derive(Clone, PartialEq, Message)]
+pub struct VoteData {
+    /// The score of the file, between 0 and 1.
+    #[prost(float, optional, tag = "1")]
+    pub score: Option<f32>,
+
+    /// The number of up votes.
+    #[prost(uint32, optional, tag = "2")]
+    pub votes_up: Option<u32>,
+
+    /// The number of down votes.
+    #[prost(uint32, optional, tag = "3")]
+    pub votes_down: Option<u32>,
+}
+
+/// A key-value tag of a file.
+#[derive(Clone, PartialEq, Message)]
+pub struct KvTag {
+    /// The key.
+    #[prost(string, optional, tag = "1")]
+    pub key: Option<String>,
+
+    /// The value.
+    #[prost(string, optional, tag = "2")]
+    pub value: Option<String>,
+}
@@ -5,10 +5,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::{ErrorHandle, PublishedFileServiceError},
-    macros::{do_http, optional_argument},
+    errors::SteamError,
+    ids::{AppId, PublishedFileId},
+    macros::{do_http, EndPoint},
+    query::Query,
     steam_id::SteamId,
-    Steam, BASE,
 };
 
 use super::INTERFACE;
@@ -17,7 +18,7 @@
 const VERSION: &str = "1";
 
 /// Represents the query types used when querying published files on Steam Workshop.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PublishedFileQueryType {
     /// Ranked by vote.
     RankedByVote,
@@ -96,7 +97,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 }
 
 /// Represents the matching file type for published file information.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PublishedFileInfoMatchingFileType {
     /// Items.
     Items,
@@ -223,13 +224,13 @@ pub struct File {
     pub result: u64,
     /// The published file ID.
     #[serde(rename = "publishedfileid")]
-    pub published_file_id: String,
+    pub published_file_id: PublishedFileId,
     /// The Steam ID of the creator.
     pub creator: SteamId,
     /// The ID of the application (game) that created the file.
-    pub creator_appid: u32,
+    pub creator_appid: AppId,
     /// The ID of the application (game) that consumes the file.
-    pub consumer_appid: u32,
+    pub consumer_appid: AppId,
     /// The ID of the shortcut used to create the file.
     pub consumer_shortcutid: u32,
     /// The name of the file.
@@ -342,123 +343,116 @@ struct Response {
     response: PublishedFiles,
 }
 
-impl Steam {
+EndPoint!(
     /// Performs a search query for published files.
-    ///
-    /// # Arguments
-    ///
-    /// * `query_type` - Type of the query, see [PublishedFileQueryType].
-    /// * `page` - Current page. Currently there is an upper limit of 1000.
-    /// * `cursor` - Cursor to paginate through the results (set to '*' for the first request). Prefer this over using the page parameter, as it will allow you to do deep pagination. When used, the page parameter will be ignored. Use the "next_cursor" value returned in the response to set up the next query to get the next set of results.
-    /// * `numperpage` - The number of results, per page to return.
-    /// * `creator_app_id` - App that created the files.
-    /// * `app_id` - App that consumes the files.
-    /// * `required_tags` - Tags to match on. See `match_all_tags` parameter.
-    /// * `excluded_tags` - Tags that must NOT be present on a published file to satisfy the query.
-    /// * `match_all_tags` - If true, then items must have all the tags specified, otherwise they must have at least one of the tags.
-    /// * `required_flags` - Required flags that must be set on any returned items.
-    /// * `omitted_flags` - Flags that must not be set on any returned items
-    /// * `search_text` - Text to match in the item's title or description.
-    /// * `file_type` -
-    /// * `child_published_file_id` - Find all items that reference the given item.
-    /// * `days` - If `query_type` is [RankedByTrend](crate::published_file_service::query_files::PublishedFileQueryType::RankedByTrend), then this is the number of days to get votes for \[1,7\].
-    /// * `include_recent_votes_only` - If `query_type` is [RankedByTrend](crate::published_file_service::query_files::PublishedFileQueryType::RankedByTrend), then limit result set just to items that have votes within the day range given.
-    /// * `cache_max_age_seconds` - Allow stale data to be returned for the specified number of seconds.
-    /// * `language` - Language to search in and also what gets returned. Defaults to English.
-    /// * `required_kv_tags` - Required key-value tags to match on.
-    /// * `total_only` - If true, only return the total number of files that satisfy this query.
-    /// * `ids_only` - If true, only return the published file ids of files that satisfy this query.
-    /// * `return_vote_data` - Return vote data.
-    /// * `return_tags` - Return tags in the file details.
-    /// * `return_kv_tags` - Return key-value tags in the file details.
-    /// * `return_previews` - Return preview image and video details in the file details.
-    /// * `return_children` - Return child item ids in the file details.
-    /// * `return_short_description` - Populate the short_description field instead of file_description.
-    /// * `return_for_sale_data` - Return pricing information, if applicable.
-    /// * `return_metadata` - Populate the metadata.
-    /// * `return_playtime_stats` Return playtime stats for the specified number of days before today.
-    pub async fn query_files(
-        &self,
-        query_type: PublishedFileQueryType,
-        page: u32,
-        cursor: &str,
-        numperpage: Option<u32>, // numperpage
-        creator_app_id: u32,
-        app_id: u32,
-        required_tags: &str,
-        excluded_tags: &str,
-        match_all_tags: Option<bool>,
-        required_flags: &str,
-        omitted_flags: &str,
-        search_text: &str,
-        file_type: PublishedFileInfoMatchingFileType,
-        child_published_file_id: u64,
-        days: u32,
-        include_recent_votes_only: bool,
-        cache_max_age_seconds: Option<u32>,
-        language: Option<i32>,
-        required_kv_tags: &str, // Documentation says this is type {message} ??
-        total_only: bool,
-        ids_only: bool,
-        return_vote_data: bool,
-        return_tags: bool,
-        return_kv_tags: bool,
-        return_previews: bool,
-        return_children: bool,
-        return_short_description: bool,
-        return_for_sale_data: bool,
-        return_metadata: Option<bool>,
-        return_playtime_stats: u32,
-    ) -> Result<PublishedFiles, PublishedFileServiceError> {
-        let query = vec![
-            format!("?key={}", &self.api_key),
-            format!("&query_type={}", query_type),
-            format!("&page={}", page),
-            format!("&cursor={}", cursor),
-            format!("&creator_appid={}", creator_app_id),
-            format!("&appid={}", app_id),
-            format!("&requiredtags={}", required_tags),
-            format!("&excludedtags={}", excluded_tags),
-            format!("&required_flags={}", required_flags),
-            format!("&omitted_flags={}", omitted_flags),
-            format!("&search_text={}", search_text),
-            format!("&filetype={}", file_type),
-            format!("&child_publishedfileid={}", child_published_file_id),
-            format!("&days={}", days),
-            format!("&include_recent_votes_only={}", include_recent_votes_only),
-            format!("&required_kv_tags={}", required_kv_tags),
-            format!("&totalonly={}", total_only),
-            format!("&ids_only={}", ids_only),
-            format!("&return_vote_data={}", return_vote_data),
-            format!("&return_tags={}", return_tags),
-            format!("&return_kv_tags={}", return_kv_tags),
-            format!("&return_previews={}", return_previews),
-            format!("&return_children={}", return_children),
-            format!("&return_short_description={}", return_short_description),
-            format!("&return_for_sale_data={}", return_for_sale_data),
-            format!("&return_playtime_stats={}", return_playtime_stats),
-            optional_argument!(numperpage),
-            optional_argument!(match_all_tags),
-            optional_argument!(cache_max_age_seconds),
-            optional_argument!(language),
-            optional_argument!(return_metadata),
-        ];
+    query_files -> QueryFiles<'a> => PublishedFiles {
+        required {
+            /// Type of the query, see [PublishedFileQueryType].
+            query_type: PublishedFileQueryType,
+        }
+        optional {
+            /// Current page. Currently there is an upper limit of 1000.
+            page: u32,
+            /// Cursor to paginate through the results (set to '*' for the first request). Prefer this over using the page parameter, as it will allow you to do deep pagination. When used, the page parameter will be ignored. Use the "next_cursor" value returned in the response to set up the next query to get the next set of results.
+            cursor: &'a str,
+            /// The number of results, per page to return.
+            numperpage: u32,
+            /// App that created the files.
+            creator_app_id: AppId,
+            /// App that consumes the files.
+            app_id: AppId,
+            /// Tags to match on. See `match_all_tags` parameter.
+            required_tags: &'a [&'a str],
+            /// Tags that must NOT be present on a published file to satisfy the query.
+            excluded_tags: &'a [&'a str],
+            /// If true, then items must have all the tags specified, otherwise they must have at least one of the tags.
+            match_all_tags: bool,
+            /// Required flags that must be set on any returned items.
+            required_flags: &'a str,
+            /// Flags that must not be set on any returned items
+            omitted_flags: &'a str,
+            /// Text to match in the item's title or description.
+            search_text: &'a str,
+            /// Type of files to match, see [PublishedFileInfoMatchingFileType].
+            file_type: PublishedFileInfoMatchingFileType,
+            /// Find all items that reference the given item.
+            child_published_file_id: PublishedFileId,
+            /// If `query_type` is [RankedByTrend](crate::published_file_service::query_files::PublishedFileQueryType::RankedByTrend), then this is the number of days to get votes for \[1,7\].
+            days: u32,
+            /// If `query_type` is [RankedByTrend](crate::published_file_service::query_files::PublishedFileQueryType::RankedByTrend), then limit result set just to items that have votes within the day range given.
+            include_recent_votes_only: bool,
+            /// Allow stale data to be returned for the specified number of seconds.
+            cache_max_age_seconds: u32,
+            /// Language to search in and also what gets returned. Defaults to English.
+            language: i32,
+            /// Required key-value tags to match on.
+            required_kv_tags: &'a str,
+            /// If true, only return the total number of files that satisfy this query.
+            total_only: bool,
+            /// If true, only return the published file ids of files that satisfy this query.
+            ids_only: bool,
+            /// Return vote data.
+            return_vote_data: bool,
+            /// Return tags in the file details.
+            return_tags: bool,
+            /// Return key-value tags in the file details.
+            return_kv_tags: bool,
+            /// Return preview image and video details in the file details.
+            return_previews: bool,
+            /// Return child item ids in the file details.
+            return_children: bool,
+            /// Populate the short_description field instead of file_description.
+            return_short_description: bool,
+            /// Return pricing information, if applicable.
+            return_for_sale_data: bool,
+            /// Populate the metadata.
+            return_metadata: bool,
+            /// Return playtime stats for the specified number of days before today.
+            return_playtime_stats: u32,
+        }
+    }
+);
 
+impl QueryFiles<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<PublishedFiles, SteamError> {
         let url = format!(
-            "{}/{}/{}/v{}/{}",
-            BASE,
-            INTERFACE,
-            ENDPOINT,
-            VERSION,
-            query.concat()
-        );
-
-        let response = do_http!(
-            url,
-            Response,
-            ErrorHandle,
-            PublishedFileServiceError::QueryFiles
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("query_type", self.query_type)
+            .optional("page", self.page)
+            .optional("cursor", self.cursor)
+            .optional("creator_appid", self.creator_app_id)
+            .optional("appid", self.app_id)
+            .array("requiredtags", self.required_tags.unwrap_or_default())
+            .array("excludedtags", self.excluded_tags.unwrap_or_default())
+            .optional("required_flags", self.required_flags)
+            .optional("omitted_flags", self.omitted_flags)
+            .optional("search_text", self.search_text)
+            .optional("filetype", self.file_type)
+            .optional("child_publishedfileid", self.child_published_file_id)
+            .optional("days", self.days)
+            .optional("include_recent_votes_only", self.include_recent_votes_only)
+            .optional("required_kv_tags", self.required_kv_tags)
+            .optional("totalonly", self.total_only)
+            .optional("ids_only", self.ids_only)
+            .optional("return_vote_data", self.return_vote_data)
+            .optional("return_tags", self.return_tags)
+            .optional("return_kv_tags", self.return_kv_tags)
+            .optional("return_previews", self.return_previews)
+            .optional("return_children", self.return_children)
+            .optional("return_short_description", self.return_short_description)
+            .optional("return_for_sale_data", self.return_for_sale_data)
+            .optional("return_playtime_stats", self.return_playtime_stats)
+            .optional("numperpage", self.numperpage)
+            .optional("match_all_tags", self.match_all_tags)
+            .optional("cache_max_age_seconds", self.cache_max_age_seconds)
+            .optional("language", self.language)
+            .optional("return_metadata", self.return_metadata);
+        let response = do_http!(self.steam, url, Response, query);
 
         Ok(response.response)
     }
@@ -0,0 +1,159 @@
+//! Query parameters for Web API requests.
+//!
+//! Parameters are kept as typed name/value pairs and only percent-encoded by the [`Transport`](crate::transport::Transport),
+//! so values containing spaces, `&` or `#` arrive intact.
+
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// An ordered list of query parameters.
+///
+/// # Examples
+///
+/// ```
+/// use steam_rs::query::Query;
+///
+/// let query = Query::new()
+///     .param("appid", 440)
+///     .optional("l", Some("english"))
+///     .optional::<u32>("count", None)
+///     .array("name", ["kills", "deaths"]);
+///
+/// assert_eq!(
+///     query.encode(),
+///     "appid=440&l=english&name%5B0%5D=kills&name%5B1%5D=deaths"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    params: Vec<(String, String)>,
+}
+
+impl Query {
+    /// Creates an empty query.
+    pub fn new() -> Self {
+        Query::default()
+    }
+
+    /// Adds a parameter.
+    pub fn param(mut self, name: &str, value: impl Display) -> Self {
+        self.params.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds a parameter if `value` is `Some`.
+    pub fn optional<T: Display>(self, name: &str, value: Option<T>) -> Self {
+        match value {
+            Some(value) => self.param(name, value),
+            None => self,
+        }
+    }
+
+    /// Adds an array parameter as `name[0]`, `name[1]`, ….
+    pub fn array<T: Display>(mut self, name: &str, values: impl IntoIterator<Item = T>) -> Self {
+        for (index, value) in values.into_iter().enumerate() {
+            self.params
+                .push((format!("{name}[{index}]"), value.to_string()));
+        }
+        self
+    }
+
+    /// Adds a list parameter as a single comma-separated value.
+    pub fn list<T: Display>(self, name: &str, values: impl IntoIterator<Item = T>) -> Self {
+        let values = values
+            .into_iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        self.param(name, values)
+    }
+
+    /// Adds `value` as JSON in the `input_json` parameter.
+    ///
+    /// `I*Service` interfaces read their arguments from `input_json` when it is present, which is the only way to
+    /// send nested values or arrays some of their methods expect. The API key still goes in its own `key` parameter.
+    ///
+    /// # Panics
+    ///
+    /// If `value` can't be represented as JSON, e.g. a map with non-string keys.
+    pub fn input_json(self, value: impl Serialize) -> Self {
+        let json = serde_json::to_string(&value).expect("input_json must serialize to JSON");
+        self.param("input_json", json)
+    }
+
+    /// Decodes `application/x-www-form-urlencoded` parameters, e.g. a query string without its leading `?`.
+    pub fn parse(encoded: &str) -> Self {
+        let params = encoded
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (decode_component(name), decode_component(value))
+            })
+            .collect();
+        Query { params }
+    }
+
+    /// The parameters in the order they were added.
+    pub fn pairs(&self) -> &[(String, String)] {
+        &self.params
+    }
+
+    /// Returns whether no parameters have been added.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Percent-encodes the parameters as `application/x-www-form-urlencoded`.
+    pub fn encode(&self) -> String {
+        self.params
+            .iter()
+            .map(|(name, value)| format!("{}={}", encode_component(name), encode_component(value)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+impl From<Query> for Vec<(String, String)> {
+    fn from(query: Query) -> Self {
+        query.params
+    }
+}
+
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+fn decode_component(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = bytes
+            .get(index + 1..index + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], escaped) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+                continue;
+            }
+            (b'+', _) => decoded.push(b' '),
+            (byte, _) => decoded.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
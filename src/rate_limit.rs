@@ -0,0 +1,318 @@
+//! Client-side rate limiting and daily quota accounting.
+//!
+//! Steam allows roughly 100,000 calls per API key per day, and throttles keys that send bursts of requests.
+//! A [`RateLimiter`] spaces requests out using token buckets, while a [`Quota`] counts calls and refuses to send
+//! more once the daily budget is spent.
+//!
+//! Both are handles to shared state, so clients using the same API key can share them by passing clones to
+//! [`SteamBuilder::rate_limiter`](crate::SteamBuilder::rate_limiter) and [`SteamBuilder::quota`](crate::SteamBuilder::quota).
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// The number of calls Steam allows per API key and day.
+pub const DEFAULT_DAILY_LIMIT: u64 = 100_000;
+
+/// A rate of `capacity` requests per `period`, allowing bursts of up to `capacity` requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    capacity: u32,
+    period: Duration,
+}
+
+impl RateLimit {
+    /// Allows `capacity` requests per `period`. A `capacity` below 1 is treated as 1.
+    pub fn new(capacity: u32, period: Duration) -> Self {
+        RateLimit {
+            capacity: capacity.max(1),
+            period,
+        }
+    }
+
+    /// Allows `capacity` requests per second.
+    pub fn per_second(capacity: u32) -> Self {
+        RateLimit::new(capacity, Duration::from_secs(1))
+    }
+
+    /// Allows `capacity` requests per minute.
+    pub fn per_minute(capacity: u32) -> Self {
+        RateLimit::new(capacity, Duration::from_secs(60))
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    limit: RateLimit,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit) -> Self {
+        Bucket {
+            limit,
+            tokens: limit.capacity as f64,
+            updated: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        let rate = self.limit.capacity as f64 / self.limit.period.as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate).min(self.limit.capacity as f64);
+        self.updated = now;
+    }
+
+    /// How long until a token is available, or zero if one is available now.
+    fn wait_time(&self) -> Duration {
+        if self.tokens >= 1.0 {
+            return Duration::ZERO;
+        }
+
+        let rate = self.limit.capacity as f64 / self.limit.period.as_secs_f64();
+        Duration::from_secs_f64((1.0 - self.tokens) / rate)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Buckets {
+    global: Option<Bucket>,
+    interfaces: HashMap<String, Bucket>,
+}
+
+/// A token-bucket rate limiter applied to every request sent by a [`Steam`](crate::Steam) client.
+///
+/// # Examples
+///
+/// ```
+/// use steam_rs::{
+///     rate_limit::{RateLimit, RateLimiter},
+///     Steam,
+/// };
+///
+/// // At most 10 requests per second overall, and 1 per second to `ISteamUser`.
+/// let limiter = RateLimiter::new(RateLimit::per_second(10))
+///     .interface("ISteamUser", RateLimit::per_second(1));
+///
+/// let steam = Steam::builder().rate_limiter(limiter).build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<Buckets>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter that applies `limit` to every request.
+    pub fn new(limit: RateLimit) -> Self {
+        RateLimiter {
+            buckets: Arc::new(Mutex::new(Buckets {
+                global: Some(Bucket::new(limit)),
+                interfaces: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Creates a limiter without an overall limit, to be combined with [`RateLimiter::interface`].
+    pub fn unlimited() -> Self {
+        RateLimiter::default()
+    }
+
+    /// Additionally applies `limit` to requests made to `interface` (e.g. `"ISteamUser"`).
+    pub fn interface(self, interface: &str, limit: RateLimit) -> Self {
+        self.buckets
+            .lock()
+            .unwrap()
+            .interfaces
+            .insert(interface.to_string(), Bucket::new(limit));
+        self
+    }
+
+    /// Waits until a request to `interface` is allowed, then takes a token from every bucket that applies to it.
+    pub(crate) async fn acquire(&self, interface: Option<&str>) {
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().unwrap();
+                let Buckets { global, interfaces } = &mut *buckets;
+                let now = Instant::now();
+
+                let mut applicable: Vec<&mut Bucket> = global.iter_mut().collect();
+                if let Some(bucket) = interface.and_then(|name| interfaces.get_mut(name)) {
+                    applicable.push(bucket);
+                }
+
+                applicable.iter_mut().for_each(|bucket| bucket.refill(now));
+                let wait = applicable
+                    .iter()
+                    .map(|bucket| bucket.wait_time())
+                    .max()
+                    .unwrap_or_default();
+
+                if wait.is_zero() {
+                    applicable
+                        .iter_mut()
+                        .for_each(|bucket| bucket.tokens -= 1.0);
+                    return;
+                }
+
+                wait
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Error returned when a request would exceed the daily [`Quota`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuotaExceeded {
+    /// The daily limit that was reached.
+    pub limit: u64,
+}
+
+impl fmt::Display for QuotaExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Daily quota of {} requests exceeded", self.limit)
+    }
+}
+
+impl std::error::Error for QuotaExceeded {}
+
+/// The state of a [`Quota`] at a point in time, used to persist it across restarts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaSnapshot {
+    /// The UTC day the count belongs to, as days since the Unix epoch.
+    pub day: u64,
+
+    /// The number of requests sent on that day.
+    pub used: u64,
+}
+
+#[derive(Debug)]
+struct QuotaState {
+    limit: u64,
+    day: u64,
+    used: u64,
+}
+
+impl QuotaState {
+    fn roll_over(&mut self) {
+        let today = today();
+        if self.day != today {
+            self.day = today;
+            self.used = 0;
+        }
+    }
+}
+
+/// Counts requests sent per UTC day and refuses to send more than the daily limit.
+///
+/// Every HTTP request counts, including retries.
+///
+/// # Examples
+///
+/// ```
+/// use steam_rs::{rate_limit::Quota, Steam};
+///
+/// let quota = Quota::daily(50_000);
+/// let steam = Steam::builder().quota(quota.clone()).build();
+///
+/// // Later on, inspect the quota or persist it.
+/// println!("{} requests left today", quota.remaining());
+/// let snapshot = serde_json::to_string(&quota.snapshot()).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Quota {
+    state: Arc<Mutex<QuotaState>>,
+}
+
+impl Quota {
+    /// Creates a quota allowing `limit` requests per day.
+    pub fn daily(limit: u64) -> Self {
+        Quota::restore(
+            limit,
+            QuotaSnapshot {
+                day: today(),
+                used: 0,
+            },
+        )
+    }
+
+    /// Creates a quota allowing `limit` requests per day, continuing from a previously saved snapshot.
+    ///
+    /// Snapshots from an earlier day are discarded.
+    pub fn restore(limit: u64, snapshot: QuotaSnapshot) -> Self {
+        let mut state = QuotaState {
+            limit,
+            day: snapshot.day,
+            used: snapshot.used,
+        };
+        state.roll_over();
+
+        Quota {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// The number of requests allowed per day.
+    pub fn limit(&self) -> u64 {
+        self.state.lock().unwrap().limit
+    }
+
+    /// The number of requests sent today.
+    pub fn used(&self) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        state.roll_over();
+        state.used
+    }
+
+    /// The number of requests that may still be sent today.
+    pub fn remaining(&self) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        state.roll_over();
+        state.limit.saturating_sub(state.used)
+    }
+
+    /// Captures the current count so it can be persisted and later passed to [`Quota::restore`].
+    pub fn snapshot(&self) -> QuotaSnapshot {
+        let mut state = self.state.lock().unwrap();
+        state.roll_over();
+        QuotaSnapshot {
+            day: state.day,
+            used: state.used,
+        }
+    }
+
+    /// Counts one request, or fails if the daily limit has been reached.
+    pub(crate) fn consume(&self) -> Result<(), QuotaExceeded> {
+        let mut state = self.state.lock().unwrap();
+        state.roll_over();
+
+        if state.used >= state.limit {
+            return Err(QuotaExceeded { limit: state.limit });
+        }
+
+        state.used += 1;
+        Ok(())
+    }
+}
+
+impl Default for Quota {
+    /// A quota of [`DEFAULT_DAILY_LIMIT`] requests per day.
+    fn default() -> Self {
+        Quota::daily(DEFAULT_DAILY_LIMIT)
+    }
+}
+
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        / 86_400
+}
@@ -0,0 +1,85 @@
+//! Untyped access to endpoints this crate does not wrap yet.
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{errors::SteamError, query::Query, Steam};
+
+impl Steam {
+    /// Calls any Web API method and returns the decoded JSON response.
+    ///
+    /// The request goes through the same path as the wrapped endpoints: it uses the client's base URL, API key,
+    /// rate limiter, quota and [`RetryPolicy`](crate::retry::RetryPolicy), and fails with the same [`SteamError`]s.
+    /// The API key is added to `params` unless the client has none or `params` already contains a `key`.
+    ///
+    /// `GET` requests send `params` in the query string, other methods send them in the body.
+    ///
+    /// # Arguments
+    ///
+    /// * `interface` - The interface, e.g. `"ISteamUser"`.
+    /// * `method` - The method, e.g. `"GetPlayerBans"`.
+    /// * `version` - The method version, e.g. `1`.
+    /// * `http_method` - The HTTP method the Web API method expects.
+    /// * `params` - The method's parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use steam_rs::{query::Query, Steam};
+    /// use reqwest::Method;
+    ///
+    /// # async fn example(steam: Steam) -> Result<(), steam_rs::errors::SteamError> {
+    /// let bans = steam
+    ///     .call_raw(
+    ///         "ISteamUser",
+    ///         "GetPlayerBans",
+    ///         1,
+    ///         Method::GET,
+    ///         Query::new().param("steamids", 76561197960287930u64),
+    ///     )
+    ///     .await?;
+    ///
+    /// println!("{}", bans["players"][0]["VACBanned"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn call_raw(
+        &self,
+        interface: &str,
+        method: &str,
+        version: u32,
+        http_method: Method,
+        params: Query,
+    ) -> Result<Value, SteamError> {
+        self.call(interface, method, version, http_method, params)
+            .await
+    }
+
+    /// Calls any Web API method and deserializes the JSON response into `T`.
+    ///
+    /// See [`Steam::call_raw`] for how the request is built.
+    pub async fn call<T: DeserializeOwned>(
+        &self,
+        interface: &str,
+        method: &str,
+        version: u32,
+        http_method: Method,
+        params: Query,
+    ) -> Result<T, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, interface, method, version);
+
+        let has_key = params.pairs().iter().any(|(name, _)| name == "key");
+        let query = if self.api_key.is_empty() || has_key {
+            params
+        } else {
+            params.pairs().iter().fold(
+                Query::new().param("key", &self.api_key),
+                |query, (name, value)| query.param(name, value),
+            )
+        };
+
+        let body = self.request(http_method, &url, query).await?;
+        self.decode(&url, &body)
+    }
+}
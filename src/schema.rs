@@ -0,0 +1,347 @@
+//! Detecting when Steam's responses drift from the types they are decoded into.
+//!
+//! Steam changes payloads without notice. By default, fields the crate does not model are ignored and a missing
+//! field fails the call with [`SteamError::Decode`]. Two opt-in [`SchemaMode`]s, set with
+//! [`SteamBuilder::schema_mode`](crate::SteamBuilder::schema_mode), change that:
+//!
+//! - [`SchemaMode::Lenient`] fills missing fields with an empty value (`null`, `0`, `false`, `""`, `[]` or `{}`,
+//!   whichever the type accepts) and keeps decoding, capturing unknown fields in [`Drift::extra`].
+//! - [`SchemaMode::Strict`] fails with [`SteamError::SchemaDrift`] when a payload contains fields the crate does not
+//!   model.
+//!
+//! In both modes, every difference found is reported as a [`Drift`] to the hook set with
+//! [`SteamBuilder::on_schema_drift`](crate::SteamBuilder::on_schema_drift), so monitoring can flag drift before it
+//! breaks anything.
+//!
+//! # Examples
+//!
+//! ```
+//! use steam_rs::{schema::SchemaMode, Steam};
+//!
+//! let steam = Steam::builder()
+//!     .schema_mode(SchemaMode::Lenient)
+//!     .on_schema_drift(|drift| eprintln!("{drift}"))
+//!     .build();
+//! ```
+
+use std::{collections::BTreeMap, fmt, sync::Arc};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::errors::{decode, decode_error, SteamError};
+
+/// How responses that don't match the crate's types are handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SchemaMode {
+    /// Unknown fields are ignored and missing fields fail the call.
+    #[default]
+    Default,
+
+    /// Missing fields are filled with an empty value and unknown fields are captured in [`Drift::extra`]. Both are
+    /// reported.
+    Lenient,
+
+    /// Unknown fields fail the call with [`SteamError::SchemaDrift`], as do missing fields.
+    Strict,
+}
+
+/// The differences between a response and the type it was decoded into.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Drift {
+    /// The method that answered, e.g. `IPublishedFileService/QueryFiles/v1`.
+    pub endpoint: String,
+
+    /// Fields the type requires but the response left out, e.g. `response.publishedfiledetails[0].ban_reason`.
+    pub missing: Vec<String>,
+
+    /// Fields of the response the type does not model, by path, with their values.
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl Drift {
+    /// Returns whether the response matched the type exactly.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Schema drift in {}", self.endpoint)?;
+        if !self.missing.is_empty() {
+            write!(f, ", missing fields: {}", self.missing.join(", "))?;
+        }
+        if !self.extra.is_empty() {
+            let unknown: Vec<&str> = self.extra.keys().map(String::as_str).collect();
+            write!(f, ", unknown fields: {}", unknown.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The hook drift is reported to.
+#[derive(Clone)]
+pub(crate) struct DriftHook(pub(crate) Arc<dyn Fn(&Drift) + Send + Sync>);
+
+impl fmt::Debug for DriftHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DriftHook")
+    }
+}
+
+/// A step in a path through a JSON document.
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// How many times lenient decoding repairs a response before giving up. A repair fills a field in every element of
+/// the arrays it is in, so this bounds the number of distinct fields rather than the size of the response.
+const MAX_REPAIRS: usize = 256;
+
+/// Decodes `body` into `T` according to `mode`, returning what drifted along with it.
+pub(crate) fn decode_with<T: DeserializeOwned>(
+    mode: SchemaMode,
+    endpoint: &str,
+    body: &str,
+) -> Result<(T, Drift), SteamError> {
+    let mut drift = Drift {
+        endpoint: endpoint.to_string(),
+        ..Drift::default()
+    };
+
+    match mode {
+        SchemaMode::Default => decode(body).map(|value| (value, drift)),
+        SchemaMode::Strict => {
+            let mut unknown = Vec::new();
+            let deserializer = &mut serde_json::Deserializer::from_str(body);
+            let value = serde_path_to_error::deserialize(serde_ignored::Deserializer::new(
+                deserializer,
+                &mut |path| unknown.push(segments(&path)),
+            ))
+            .map_err(|error| decode_error(body, error))?;
+
+            if !unknown.is_empty() {
+                let json: Value = serde_json::from_str(body).unwrap_or_default();
+                drift.extra = unknown_fields(&json, unknown);
+                return Err(SteamError::SchemaDrift(drift));
+            }
+            Ok((value, drift))
+        }
+        SchemaMode::Lenient => {
+            let Ok(mut json) = serde_json::from_str::<Value>(body) else {
+                return decode(body).map(|value| (value, drift));
+            };
+
+            for _ in 0..MAX_REPAIRS {
+                let mut unknown = Vec::new();
+                let error = match serde_path_to_error::deserialize(
+                    serde_ignored::Deserializer::new(&json, &mut |path| {
+                        unknown.push(segments(&path))
+                    }),
+                ) {
+                    Ok(value) => {
+                        drift.extra = unknown_fields(&json, unknown);
+                        return Ok((value, drift));
+                    }
+                    Err(error) => error,
+                };
+
+                if !repair::<T>(&mut json, &error, &mut drift.missing) {
+                    return Err(decode_error(body, error));
+                }
+            }
+
+            decode(body).map(|value| (value, drift))
+        }
+    }
+}
+
+/// Values tried in turn for a missing field, the first one `T` accepts being kept.
+fn candidates() -> [Value; 6] {
+    [
+        Value::Null,
+        Value::String(String::new()),
+        Value::from(0),
+        Value::Bool(false),
+        Value::Array(Vec::new()),
+        Value::Object(Default::default()),
+    ]
+}
+
+/// Fills in the field `error` reports as missing with the first of [`candidates`] that `T` accepts there. Returns
+/// `false` if `error` is about something else.
+///
+/// Elements of an array share a type, so the value is filled into the same field in every element of the arrays on
+/// its path, not just the one `error` is about.
+fn repair<T: DeserializeOwned>(
+    json: &mut Value,
+    error: &serde_path_to_error::Error<serde_json::Error>,
+    missing: &mut Vec<String>,
+) -> bool {
+    let Some(field) = missing_field(error) else {
+        return false;
+    };
+    let mut path = error_path(error);
+    path.push(Segment::Key(field.to_string()));
+    let Some(Value::Object(parent)) = lookup_mut(json, &path[..path.len() - 1]) else {
+        return false;
+    };
+    if parent.contains_key(&field) {
+        return false;
+    }
+
+    // A value is accepted if decoding gets past it. `{}` comes last and is kept even if decoding stops at it, as
+    // that is how a struct with missing fields of its own fails, and those are filled in by the next repairs.
+    let [candidates @ .., object] = candidates();
+    let mut fill = object;
+    for candidate in candidates {
+        set(json, &path, candidate.clone());
+        let accepted = match serde_path_to_error::deserialize::<_, T>(&*json) {
+            Ok(_) => true,
+            Err(error) => error_path(&error) != path,
+        };
+        if accepted {
+            fill = candidate;
+            break;
+        }
+    }
+    remove(json, &path);
+
+    for sibling in siblings(json, &path) {
+        let Some(Value::Object(parent)) = lookup_mut(json, &sibling[..sibling.len() - 1]) else {
+            continue;
+        };
+        if !parent.contains_key(&field) {
+            parent.insert(field.clone(), fill.clone());
+            missing.push(format_path(&sibling));
+        }
+    }
+    true
+}
+
+/// The field a struct reported missing, from the message of serde's `de::Error::missing_field`, which derived
+/// `Deserialize` implementations raise. Its path is that of the struct.
+fn missing_field(error: &serde_path_to_error::Error<serde_json::Error>) -> Option<String> {
+    let message = error.inner().to_string();
+    let field = message.strip_prefix("missing field `")?.split('`').next()?;
+    Some(field.to_string())
+}
+
+fn error_path(error: &serde_path_to_error::Error<serde_json::Error>) -> Vec<Segment> {
+    error
+        .path()
+        .iter()
+        .filter_map(|segment| match segment {
+            serde_path_to_error::Segment::Map { key } => Some(Segment::Key(key.clone())),
+            serde_path_to_error::Segment::Seq { index } => Some(Segment::Index(*index)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Sets the field at `path`, whose parent is an object.
+fn set(json: &mut Value, path: &[Segment], value: Value) {
+    if let (Some(Value::Object(parent)), Some(Segment::Key(field))) =
+        (lookup_mut(json, &path[..path.len() - 1]), path.last())
+    {
+        parent.insert(field.clone(), value);
+    }
+}
+
+/// Removes the field at `path`, whose parent is an object.
+fn remove(json: &mut Value, path: &[Segment]) {
+    if let (Some(Value::Object(parent)), Some(Segment::Key(field))) =
+        (lookup_mut(json, &path[..path.len() - 1]), path.last())
+    {
+        parent.remove(field);
+    }
+}
+
+/// The paths `path` leads to with each of its indices replaced by every index of that array, e.g. `a[0].b` leads to
+/// `a[0].b`, `a[1].b` and so on.
+fn siblings(json: &Value, path: &[Segment]) -> Vec<Vec<Segment>> {
+    let mut paths = vec![Vec::new()];
+    for segment in path {
+        paths = paths
+            .into_iter()
+            .flat_map(|prefix: Vec<Segment>| {
+                let count = match segment {
+                    Segment::Key(_) => 1,
+                    Segment::Index(_) => lookup(json, &prefix)
+                        .and_then(Value::as_array)
+                        .map_or(0, Vec::len),
+                };
+                (0..count).map(move |index| {
+                    let mut path = prefix.clone();
+                    path.push(match segment {
+                        Segment::Key(key) => Segment::Key(key.clone()),
+                        Segment::Index(_) => Segment::Index(index),
+                    });
+                    path
+                })
+            })
+            .collect();
+    }
+    paths
+}
+
+fn segments(path: &serde_ignored::Path) -> Vec<Segment> {
+    let (parent, segment) = match path {
+        serde_ignored::Path::Root => return Vec::new(),
+        serde_ignored::Path::Seq { parent, index } => (parent, Some(Segment::Index(*index))),
+        serde_ignored::Path::Map { parent, key } => (parent, Some(Segment::Key(key.clone()))),
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => (parent, None),
+    };
+
+    let mut segments = self::segments(parent);
+    segments.extend(segment);
+    segments
+}
+
+/// Formats a path like [`SteamError::Decode`] does, e.g. `response.players[0].steamid`.
+fn format_path(path: &[Segment]) -> String {
+    let mut formatted = String::new();
+    for segment in path {
+        match segment {
+            Segment::Key(key) if formatted.is_empty() => formatted.push_str(key),
+            Segment::Key(key) => {
+                formatted.push('.');
+                formatted.push_str(key);
+            }
+            Segment::Index(index) => formatted.push_str(&format!("[{index}]")),
+        }
+    }
+    if formatted.is_empty() {
+        formatted.push('.');
+    }
+    formatted
+}
+
+fn lookup<'a>(json: &'a Value, path: &[Segment]) -> Option<&'a Value> {
+    path.iter().try_fold(json, |value, segment| match segment {
+        Segment::Key(key) => value.get(key),
+        Segment::Index(index) => value.get(index),
+    })
+}
+
+fn lookup_mut<'a>(json: &'a mut Value, path: &[Segment]) -> Option<&'a mut Value> {
+    path.iter().try_fold(json, |value, segment| match segment {
+        Segment::Key(key) => value.get_mut(key),
+        Segment::Index(index) => value.get_mut(index),
+    })
+}
+
+fn unknown_fields(json: &Value, paths: Vec<Vec<Segment>>) -> BTreeMap<String, Value> {
+    paths
+        .into_iter()
+        .map(|path| {
+            let value = lookup(json, &path).cloned().unwrap_or_default();
+            (format_path(&path), value)
+        })
+        .collect()
+}
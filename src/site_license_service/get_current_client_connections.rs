@@ -1,13 +1,8 @@
 //! Implements the `GetCurrentClientConnections` endpoint
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
-use crate::{
-    errors::{ErrorHandle, SiteLicenseServiceError},
-    macros::do_http,
-    Steam, BASE,
-};
+use crate::{errors::SteamError, macros::do_http, query::Query, Steam};
 
 use super::{GameName, INTERFACE};
 
@@ -78,23 +73,18 @@ impl Steam {
     /// * `publisher_key` - Steamworks Web API publisher authentication key
     /// * `site_id` - Site ID to see; zero for all sites
     pub async fn get_current_client_connections(
+        &self,
         publisher_key: &str,
         site_id: u64,
-    ) -> Result<CurrentClientConnections, SiteLicenseServiceError> {
+    ) -> Result<CurrentClientConnections, SteamError> {
         let url = format!(
-            "{}/{}/{}/v{}/?key={}&siteid={}",
-            BASE, INTERFACE, ENDPOINT, VERSION, publisher_key, site_id
-        );
-        let json = do_http!(
-            url,
-            Value,
-            ErrorHandle,
-            SiteLicenseServiceError::GetCurrentClientConnections
-        );
-        let wrapper: Wrapper = ErrorHandle!(
-            serde_json::from_value(json.to_owned()),
-            SiteLicenseServiceError::GetCurrentClientConnections
+            "{}/{}/{}/v{}/",
+            self.partner_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", publisher_key)
+            .param("siteid", site_id);
+        let wrapper: Wrapper = do_http!(self, url, Wrapper, query);
         Ok(wrapper.response)
     }
 }
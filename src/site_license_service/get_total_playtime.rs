@@ -1,13 +1,8 @@
 //! Implements the `GetTotalPlaytime` endpoint
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
-use crate::{
-    errors::{ErrorHandle, SiteLicenseServiceError},
-    macros::do_http,
-    Steam, BASE,
-};
+use crate::{errors::SteamError, macros::do_http, query::Query, Steam};
 
 use super::{GameName, INTERFACE};
 
@@ -68,25 +63,22 @@ impl Steam {
     /// * `end_time` - Report activity starting before this time. RFC 3339 UTC format.
     /// * `site_id` - Site ID to see; zero for all sites
     pub async fn get_total_playtime(
+        &self,
         publisher_key: &str,
         start_time: &str,
         end_time: &str,
         site_id: u64,
-    ) -> Result<TotalPlaytime, SiteLicenseServiceError> {
+    ) -> Result<TotalPlaytime, SteamError> {
         let url = format!(
-            "{}/{}/{}/v{}/?key={}&start_time={}&end_time={}&siteid={}",
-            BASE, INTERFACE, ENDPOINT, VERSION, publisher_key, start_time, end_time, site_id
-        );
-        let json = do_http!(
-            url,
-            Value,
-            ErrorHandle,
-            SiteLicenseServiceError::GetCurrentClientConnections
-        );
-        let wrapper: Wrapper = ErrorHandle!(
-            serde_json::from_value(json.to_owned()),
-            SiteLicenseServiceError::GetCurrentClientConnections
+            "{}/{}/{}/v{}/",
+            self.partner_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", publisher_key)
+            .param("start_time", start_time)
+            .param("end_time", end_time)
+            .param("siteid", site_id);
+        let wrapper: Wrapper = do_http!(self, url, Wrapper, query);
         Ok(wrapper.response)
     }
 }
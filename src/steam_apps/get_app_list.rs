@@ -2,11 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    errors::{ErrorHandle, SteamAppsError},
-    macros::do_http,
-    Steam, BASE,
-};
+use crate::{errors::SteamError, ids::AppId, macros::do_http, query::Query, Steam};
 
 use super::INTERFACE;
 
@@ -17,7 +13,7 @@
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct App {
     /// App ID of this application.
-    pub appid: u32,
+    pub appid: AppId,
 
     /// The name of this application.
     pub name: String,
@@ -36,9 +32,10 @@ struct Wrapper {
 
 impl Steam {
     /// Gets the complete list of public apps.
-    pub async fn get_app_list() -> Result<AppList, SteamAppsError> {
-        let url = format!("{}/{}/{}/v{}/", BASE, INTERFACE, ENDPOINT, VERSION);
-        let wrapper = do_http!(url, Wrapper, ErrorHandle, SteamAppsError::GetAppList);
+    pub async fn get_app_list(&self) -> Result<AppList, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new();
+        let wrapper = do_http!(self, url, Wrapper, query);
         Ok(wrapper.applist)
     }
 }
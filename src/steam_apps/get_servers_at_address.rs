@@ -3,9 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::{ErrorHandle, SteamAppsError},
-    macros::do_http,
-    Steam, BASE,
+    errors::SteamError, ids::AppId, macros::do_http, query::Query, steam_id::SteamId, Steam,
 };
 
 use super::INTERFACE;
@@ -23,14 +21,14 @@ pub struct Server {
     #[serde(rename = "gmsindex")]
     pub gms_index: i32,
 
-    // TODO: provide documentation for this field
-    pub steamid: String,
+    /// The game server's Steam ID.
+    pub steamid: SteamId,
 
     // TODO: provide documentation for this field
     pub reject: Option<String>,
 
     /// Gives the steam game appid. Ex: 730
-    pub appid: u32,
+    pub appid: AppId,
 
     /// Tells which directory the game is from. Ex: "csgo"
     pub gamedir: String,
@@ -74,17 +72,13 @@ impl Steam {
     /// # Arguments
     ///
     /// * `address` - IP or IP:queryport to list.
-    pub async fn get_servers_at_address(address: &str) -> Result<ServersResponse, SteamAppsError> {
-        let url = format!(
-            "{}/{}/{}/v{}/?addr={}",
-            BASE, INTERFACE, ENDPOINT, VERSION, address
-        );
-        let wrapper = do_http!(
-            url,
-            Wrapper,
-            ErrorHandle,
-            SteamAppsError::GetServersAtAddress
-        );
+    pub async fn get_servers_at_address(
+        &self,
+        address: &str,
+    ) -> Result<ServersResponse, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new().param("addr", address);
+        let wrapper = do_http!(self, url, Wrapper, query);
         Ok(wrapper.response)
     }
 }
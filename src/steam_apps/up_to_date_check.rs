@@ -2,11 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    errors::{ErrorHandle, SteamAppsError},
-    macros::do_http,
-    Steam, BASE,
-};
+use crate::{errors::SteamError, ids::AppId, macros::do_http, query::Query, Steam};
 
 use super::INTERFACE;
 
@@ -44,14 +40,13 @@ impl Steam {
     /// * `appid` - AppID of game.
     /// * `version` - The installed version of the game.
     pub async fn up_to_date_check(
-        appid: u32,
+        &self,
+        appid: AppId,
         version: u32,
-    ) -> Result<UpToDateResponse, SteamAppsError> {
-        let url = format!(
-            "{}/{}/{}/v{}/?appid={}&version={}",
-            BASE, INTERFACE, ENDPOINT, VERSION, appid, version
-        );
-        let wrapper = do_http!(url, Wrapper, ErrorHandle, SteamAppsError::GetAppList);
+    ) -> Result<UpToDateResponse, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new().param("appid", appid).param("version", version);
+        let wrapper = do_http!(self, url, Wrapper, query);
         Ok(wrapper.response)
     }
 }
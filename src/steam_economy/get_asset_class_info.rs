@@ -7,9 +7,10 @@
 use serde_json::Value;
 
 use crate::{
-    errors::{ErrorHandle, SteamEconomyError},
-    macros::{do_http, gen_args, optional_argument},
-    Steam, BASE,
+    errors::SteamError,
+    ids::{AppId, ClassId, InstanceId},
+    macros::{do_http, EndPoint},
+    query::Query,
 };
 
 use super::INTERFACE;
@@ -65,7 +66,7 @@ pub struct AssetClassInfo {
     /// Application data associated with the asset class.
     pub app_data: AppData,
     /// Optional class ID of the asset class.
-    pub class_id: Option<String>,
+    pub class_id: Option<ClassId>,
 }
 
 /// Represents a description associated with an asset class.
@@ -114,65 +115,73 @@ pub struct Tag {
     pub category_name: String,
 }
 
-impl Steam {
+EndPoint!(
     /// Retrieves asset class information for a specified app.
-    ///
-    /// # Arguments
-    ///
-    /// * `appid` - The ID of the application (game) for which to retrieve asset class information. Must be a steam economy app.
-    /// * `language` - An optional parameter specifying the user's local language.
-    /// * `class_count` - Number of classes requested. Must be at least one.
-    /// * `classid0` - Class ID of the nth class.
-    /// * `instanceid0` - Instance ID of the nth class.
-    ///
-    /// Note: This endpoint gets modified to provide easier access to the data! This will not give an exact copy of the data outputed by the API.
-    pub async fn get_asset_class_info(
-        &self,
-        appid: u32,
-        language: Option<&str>,
-        class_count: u32,
-        classid0: u64,
-        instanceid0: Option<u32>,
-    ) -> Result<HashMap<String, AssetClassInfo>, SteamEconomyError> {
-        let key = &self.api_key.clone();
-        let args = gen_args!(key, appid, class_count, classid0)
-            + &optional_argument!(language, instanceid0);
-        let url = format!("{}/{}/{}/v{}/?{}", BASE, INTERFACE, ENDPOINT, VERSION, args);
-
-        let response = do_http!(
-            url,
-            UncleanAssetClassInfo,
-            ErrorHandle,
-            SteamEconomyError::GetAssetClassInfo
-        )
-        .clean()?;
+    get_asset_class_info -> GetAssetClassInfo<'a> => HashMap<String, AssetClassInfo> {
+        required {
+            /// The ID of the application (game) for which to retrieve asset class information. Must be a steam economy app.
+            appid: AppId,
+            /// Number of classes requested. Must be at least one.
+            class_count: u32,
+            /// Class ID of the nth class.
+            classid0: ClassId,
+        }
+        optional {
+            /// An optional parameter specifying the user's local language.
+            language: &'a str,
+            /// Instance ID of the nth class.
+            /// Note: This endpoint gets modified to provide easier access to the data! This will not give an exact copy of the data outputed by the API.
+            instanceid0: InstanceId,
+        }
+    }
+);
+
+impl GetAssetClassInfo<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<HashMap<String, AssetClassInfo>, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
+        );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("appid", self.appid)
+            .param("class_count", self.class_count)
+            .param("classid0", self.classid0)
+            .optional("language", self.language)
+            .optional("instanceid0", self.instanceid0);
+        let response = do_http!(self.steam, url, UncleanAssetClassInfo, query).clean()?;
 
         Ok(response)
     }
 }
 
 impl UncleanAssetClassInfo {
-    pub fn clean(mut self) -> Result<HashMap<String, AssetClassInfo>, SteamEconomyError> {
+    pub fn clean(mut self) -> Result<HashMap<String, AssetClassInfo>, SteamError> {
         // Discard success status
-        self.result.remove("success");
+        if let Some(Value::Bool(false)) = self.result.remove("success") {
+            let message = self.result.get("error").and_then(Value::as_str);
+            return Err(SteamError::Steam {
+                message: message.unwrap_or("Request failed").to_string(),
+            });
+        }
 
         // TODO: make sure that order isn't needed
-        let data = self
-            .result
+        self.result
             .par_iter()
-            .map(move |(id, data)| -> (String, AssetClassInfo) {
-                println!("{data:?}");
-                (
-                    id.clone(),
-                    serde_json::from_value::<AssetClassInfo>(data.to_owned()).unwrap(),
-                )
-            })
-            .collect::<Vec<(String, AssetClassInfo)>>();
-
-        // Convert the data into a hashmap
-        let data = data
-            .into_iter()
-            .collect::<HashMap<String, AssetClassInfo>>();
-        Ok(data)
+            .map(
+                move |(id, data)| -> Result<(String, AssetClassInfo), SteamError> {
+                    println!("{data:?}");
+                    let class_info = serde_path_to_error::deserialize(data).map_err(|error| {
+                        SteamError::Decode {
+                            path: format!("result.{id}.{}", error.path()),
+                            message: error.inner().to_string(),
+                            body: data.to_string(),
+                        }
+                    })?;
+                    Ok((id.clone(), class_info))
+                },
+            )
+            .collect()
     }
 }
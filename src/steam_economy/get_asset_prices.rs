@@ -3,12 +3,12 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
-use serde_json::{from_value, Value};
 
 use crate::{
-    errors::{ErrorHandle, SteamEconomyError},
-    macros::{do_http, gen_args, optional_argument},
-    Steam, BASE,
+    errors::SteamError,
+    ids::{AppId, ClassId},
+    macros::{do_http, EndPoint},
+    query::Query,
 };
 
 use super::INTERFACE;
@@ -51,7 +51,7 @@ pub struct Asset {
     pub class: Vec<HashMap<String, String>>,
 
     /// The class ID of the asset.
-    pub classid: String,
+    pub classid: ClassId,
 
     /// Optional tags associated with the asset.
     pub tags: Option<Vec<String>>,
@@ -65,30 +65,38 @@ struct Wrapper {
     result: Option<AssetPrices>,
 }
 
-impl Steam {
+EndPoint!(
     /// Retrieves asset prices for a specified app.
-    ///
-    /// # Arguments
-    ///
-    /// * `appid` - The ID of the application (game) for which to retrieve asset prices. Must be a Steam economy app.
-    /// * `language` - An optional parameter specifying the user's local language.
-    /// * `currency` - An optional parameter specifying the currency to filter for.
-    pub async fn get_asset_prices(
-        &self,
-        appid: u32,
-        language: Option<&str>,
-        currency: Option<&str>,
-    ) -> Result<AssetPrices, SteamEconomyError> {
-        let key = &self.api_key.clone();
-        let args = gen_args!(key, appid) + &optional_argument!(language, currency);
-        let url = format!("{}/{}/{}/v{}/?{}", BASE, INTERFACE, ENDPOINT, VERSION, args);
-
-        let json = do_http!(url, Value, ErrorHandle, SteamEconomyError::GetAssetPrices);
-        let response: Wrapper = ErrorHandle!(
-            from_value(json.to_owned()),
-            SteamEconomyError::GetAssetPrices
+    get_asset_prices -> GetAssetPrices<'a> => AssetPrices {
+        required {
+            /// The ID of the application (game) for which to retrieve asset prices. Must be a Steam economy app.
+            appid: AppId,
+        }
+        optional {
+            /// An optional parameter specifying the user's local language.
+            language: &'a str,
+            /// An optional parameter specifying the currency to filter for.
+            currency: &'a str,
+        }
+    }
+);
+
+impl GetAssetPrices<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<AssetPrices, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
-
-        Ok(response.result.unwrap())
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("appid", self.appid)
+            .optional("language", self.language)
+            .optional("currency", self.currency);
+        let response: Wrapper = do_http!(self.steam, url, Wrapper, query);
+
+        response.result.ok_or_else(|| SteamError::Steam {
+            message: "No asset prices returned".to_string(),
+        })
     }
 }
@@ -1,10 +1,18 @@
 use core::fmt;
-use std::sync::LazyLock;
+use std::{marker::PhantomData, num::ParseIntError, str::FromStr, sync::LazyLock};
 
 use num_enum::TryFromPrimitive;
 use pomsky_macro::pomsky;
 use regex::{Match, Regex};
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{
+    de::{self, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    errors::SteamError,
+    ids::{AppId, GroupId, IdVisitor},
+};
 
 const STEAM2_REGEX_STR: &str = pomsky! {
     ^
@@ -34,6 +42,12 @@
 
 static STEAM3_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(STEAM3_REGEX_STR).unwrap());
 
+/// The letters quick invite codes use in place of the hexadecimal digits of the account ID.
+const INVITE_CODE_ALPHABET: &[u8; 16] = b"bcdfghjkmnpqrtvw";
+
+/// The characters friend codes are written with, one per 5 bits.
+const FRIEND_CODE_ALPHABET: &[u8; 32] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
 /// Represents a SteamID64 type which is used to uniquely identify users on the Steam platform.
 /// SteamID64 is a 64-bit unsigned integer.
 /// For more information on SteamID and SteamID64, refer to the official Steam documentation:
@@ -47,7 +61,7 @@
 /// let steam_id = SteamId::new(76561197960287930);
 /// println!("SteamId: {}", steam_id);
 ///
-/// let smart_steam_id = SteamId::new_smart("STEAM_1:0:11101");
+/// let smart_steam_id = SteamId::new_smart("STEAM_1:0:11101").unwrap();
 /// println!("SteamId: {}", smart_steam_id);
 /// ```
 ///
@@ -73,15 +87,14 @@
 ///
 /// # Parsing
 ///
-/// You can parse a `SteamId` from a string:
+/// You can parse a `SteamId` from a string in any of the forms [`SteamId::new_smart`] accepts:
 /// ```
 /// use steam_rs::steam_id::SteamId;
 ///
-/// let steam_id = SteamId::from(
-///     "76561197960287930".to_string()
-/// );
+/// let steam_id: SteamId = "76561197960287930".parse().unwrap();
+/// assert_eq!(steam_id, SteamId::try_from("[U:1:22202]").unwrap());
 ///
-/// println!("Parsed SteamId: {}", steam_id);
+/// assert!("not a steam id".parse::<SteamId>().is_err());
 /// ```
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct SteamId(pub u64);
@@ -120,23 +133,32 @@ pub fn new(value: u64) -> Self {
     pub fn new_smart(value: &str) -> Result<Self, SteamIdError> {
         let mut steam_id = Self::new(0);
 
-        let match_to_u64 = |m: Match| m.as_str().parse::<u64>().unwrap();
+        // A captured number, or `TooBig` if it is bigger than `limit`, however many digits it has.
+        let number = |m: Option<Match>, what: &str, limit: u64| {
+            m.ok_or(SteamIdError::Invalid)?
+                .as_str()
+                .parse::<u64>()
+                .ok()
+                .filter(|number| *number <= limit)
+                .ok_or_else(|| SteamIdError::TooBig(what.to_owned(), limit))
+        };
+        let universe = |m: Option<Match>| {
+            let universe = number(m, "Account universe", 0xFF)?;
+            Universe::try_from(universe).map_err(|_| SteamIdError::Invalid)
+        };
 
         if let Some(captures) = STEAM2_REGEX.captures(value) {
-            let account_id = captures.name("id").map(match_to_u64).unwrap();
-            if account_id > u32::MAX as u64 {
-                return Err(SteamIdError::TooBig("SteamID".to_owned(), u32::MAX as u64));
-            }
+            let account_id = number(captures.name("id"), "SteamID", u32::MAX as u64)?;
 
-            let mut universe = captures.name("universe").map(match_to_u64).unwrap();
-            if universe == Universe::Invalid as u64 {
-                universe = Universe::Public as u64;
+            let mut universe = universe(captures.name("universe"))?;
+            if universe == Universe::Invalid {
+                universe = Universe::Public;
             }
 
-            let auth_server = captures.name("authServer").map(match_to_u64).unwrap();
+            let auth_server = number(captures.name("authServer"), "Auth server", 1)?;
             let account_id = (account_id << 1) | auth_server;
 
-            steam_id.set_account_universe(universe)?;
+            steam_id.set_account_universe(universe as u64)?;
             steam_id.set_account_instance(AccountInstance::Desktop as u64)?;
             steam_id.set_account_type(AccountType::Individual as u64)?;
             steam_id.set_account_id(account_id)?;
@@ -145,17 +167,17 @@ pub fn new_smart(value: &str) -> Result<Self, SteamIdError> {
         }
 
         if let Some(captures) = STEAM3_REGEX.captures(value) {
-            let account_id = captures.name("id").map(match_to_u64).unwrap();
-            if account_id > u32::MAX as u64 {
-                return Err(SteamIdError::TooBig("SteamID".to_owned(), u32::MAX as u64));
-            }
+            let account_id = number(captures.name("id"), "SteamID", u32::MAX as u64)?;
 
-            let letter_str = captures.name("type").unwrap().as_str();
-            let letter = letter_str.chars().next().unwrap();
+            let letter = captures
+                .name("type")
+                .and_then(|m| m.as_str().chars().next())
+                .ok_or(SteamIdError::Invalid)?;
             let mut instance = match (letter, captures.name("instance")) {
                 ('U', None) => AccountInstance::Desktop,
                 (_, Some(m)) => {
-                    AccountInstance::try_from(match_to_u64(m)).unwrap_or(AccountInstance::All)
+                    let instance = number(Some(m), "Account instance", 0xFFFFF)?;
+                    AccountInstance::try_from(instance).unwrap_or(AccountInstance::All)
                 }
                 _ => AccountInstance::All,
             };
@@ -172,9 +194,9 @@ pub fn new_smart(value: &str) -> Result<Self, SteamIdError> {
                 _ => steam_id.set_account_type(AccountType::from(letter) as u64)?,
             }
 
-            let universe = captures.name("universe").map(match_to_u64).unwrap();
+            let universe = universe(captures.name("universe"))?;
 
-            steam_id.set_account_universe(universe)?;
+            steam_id.set_account_universe(universe as u64)?;
             steam_id.set_account_instance(instance as u64)?;
             steam_id.set_account_id(account_id)?;
             return Ok(steam_id);
@@ -187,16 +209,42 @@ pub fn new_smart(value: &str) -> Result<Self, SteamIdError> {
     }
 
     /// Creates a new [AccountType::Individual] `SteamId` in public universe from a given account id.
+    ///
+    /// Never fails, see [`SteamId::from_individual_account_id`] for the infallible version.
     pub fn from_account_id(account_id: u32) -> Result<Self, SteamIdError> {
+        Ok(Self::from_individual_account_id(account_id))
+    }
+
+    /// Creates a new [AccountType::Individual] `SteamId` in public universe from a given account id.
+    pub const fn from_individual_account_id(account_id: u32) -> Self {
+        SteamId(
+            (Universe::Public as u64) << 56
+                | (AccountType::Individual as u64) << 52
+                | (AccountInstance::Desktop as u64) << 32
+                | account_id as u64,
+        )
+    }
+
+    /// Creates a new [AccountType::Clan] `SteamId` in public universe from a given group account id.
+    pub fn from_clan_id(account_id: u32) -> Result<Self, SteamIdError> {
         let mut steam_id = SteamId::new(0);
         steam_id.set_account_id(account_id as u64)?;
-        steam_id.set_account_instance(AccountInstance::Desktop as u64)?;
-        steam_id.set_account_type(AccountType::Individual as u64)?;
+        steam_id.set_account_type(AccountType::Clan as u64)?;
         steam_id.set_account_universe(Universe::Public as u64)?;
 
         Ok(steam_id)
     }
 
+    /// Creates a new [AccountType::Clan] `SteamId` from a group ID, which Steam gives either as the group's account
+    /// id, e.g. the `gid` of [`get_user_group_list`](crate::Steam::get_user_group_list), or as its SteamID64.
+    pub fn from_group_id(group_id: GroupId) -> Result<Self, SteamIdError> {
+        match u32::try_from(group_id.0) {
+            Ok(account_id) => Self::from_clan_id(account_id),
+            Err(_) if SteamId(group_id.0).is_clan() => Ok(SteamId(group_id.0)),
+            Err(_) => Err(SteamIdError::Invalid),
+        }
+    }
+
     /// Set account universe directly.
     pub fn set_account_universe(&mut self, value: u64) -> Result<(), SteamIdError> {
         if value > 0xFF {
@@ -259,6 +307,24 @@ pub fn get_account_id(&self) -> u32 {
         self.get(0, 0xFFFFFFFF) as u32
     }
 
+    /// Returns whether the `SteamId` belongs to a Steam group.
+    pub fn is_clan(&self) -> bool {
+        self.get_account_type() == AccountType::Clan
+    }
+
+    /// Get the group ID (the account ID) of a Steam group, or `None` if the `SteamId` is not a group.
+    pub fn get_group_id(&self) -> Option<GroupId> {
+        self.is_clan()
+            .then(|| GroupId(self.get_account_id() as u64))
+    }
+
+    /// Get the community URL of a Steam group, e.g. `https://steamcommunity.com/gid/103582791429521412`, or `None`
+    /// if the `SteamId` is not a group.
+    pub fn to_group_url(&self) -> Option<String> {
+        self.is_clan()
+            .then(|| format!("https://steamcommunity.com/gid/{}", self.0))
+    }
+
     /// Get account instance of the `SteamId`.
     ///
     /// Note: To get [AccountInstance] value use `AccountInstance::try_from(u32)`
@@ -317,6 +383,108 @@ pub fn to_id3_string(&self) -> String {
 
         string
     }
+
+    /// Get the `SteamId`'s friend code, as shown in CS:GO and Counter-Strike 2, e.g. `SUCVS-FADA`.
+    ///
+    /// Only [AccountType::Individual] accounts have one: the code is derived from the account ID alone.
+    pub fn to_friend_code(&self) -> String {
+        let account_id = self.get_account_id();
+        let hash = friend_code_hash(account_id);
+
+        // Each nibble of the account ID, lowest first, followed by one bit of the hash.
+        let mut bits = 0u64;
+        for i in 0..8 {
+            let nibble = (account_id >> (i * 4)) & 0xF;
+            bits = (bits << 4 | nibble as u64) << 1 | ((hash >> i) & 1) as u64;
+        }
+
+        // The code is the byte-swapped bits, 5 at a time. Its first four characters are always `A` and left out.
+        let mut bits = bits.swap_bytes() >> 20;
+        let mut code = String::with_capacity(10);
+        for i in 0..9 {
+            if i == 5 {
+                code.push('-');
+            }
+            code.push(FRIEND_CODE_ALPHABET[(bits & 0x1F) as usize] as char);
+            bits >>= 5;
+        }
+
+        code
+    }
+
+    /// Creates a new [AccountType::Individual] `SteamId` in public universe from a friend code, e.g. `SUCVS-FADA`.
+    ///
+    /// The code is checked against the hash it carries, so mistyped codes are rejected.
+    pub fn from_friend_code(code: &str) -> Result<Self, SteamIdError> {
+        let code = code.to_ascii_uppercase();
+        if code.len() != 10 || code.as_bytes()[5] != b'-' {
+            return Err(SteamIdError::Invalid);
+        }
+
+        let mut bits = 0u64;
+        for (i, character) in code.bytes().filter(|c| *c != b'-').enumerate() {
+            let value = FRIEND_CODE_ALPHABET
+                .iter()
+                .position(|c| *c == character)
+                .ok_or(SteamIdError::Invalid)?;
+            bits |= (value as u64) << (20 + 5 * i);
+        }
+
+        let mut bits = bits.swap_bytes();
+        let mut account_id = 0u32;
+        for _ in 0..8 {
+            bits >>= 1;
+            account_id = account_id << 4 | (bits & 0xF) as u32;
+            bits >>= 4;
+        }
+
+        let steam_id = Self::from_account_id(account_id)?;
+        if steam_id.to_friend_code() != code {
+            return Err(SteamIdError::Invalid);
+        }
+
+        Ok(steam_id)
+    }
+
+    /// Get the `SteamId`'s quick invite code, as in `https://s.team/p/<code>`, e.g. `hj-qp`.
+    ///
+    /// Only [AccountType::Individual] accounts have one: the code is derived from the account ID alone.
+    pub fn to_invite_code(&self) -> String {
+        let code: String = format!("{:x}", self.get_account_id())
+            .chars()
+            .filter_map(|digit| digit.to_digit(16))
+            .map(|digit| INVITE_CODE_ALPHABET[digit as usize] as char)
+            .collect();
+
+        match code.split_at(code.len() / 2) {
+            ("", _) => code,
+            (first, second) => format!("{first}-{second}"),
+        }
+    }
+
+    /// Creates a new [AccountType::Individual] `SteamId` in public universe from a quick invite code, e.g. `hj-qp`.
+    pub fn from_invite_code(code: &str) -> Result<Self, SteamIdError> {
+        let hex = code
+            .chars()
+            .filter(|c| *c != '-')
+            .map(|c| {
+                let digit = INVITE_CODE_ALPHABET.iter().position(|d| *d as char == c)?;
+                char::from_digit(digit as u32, 16)
+            })
+            .collect::<Option<String>>()
+            .ok_or(SteamIdError::Invalid)?;
+
+        match u32::from_str_radix(&hex, 16) {
+            Ok(0) | Err(_) => Err(SteamIdError::Invalid),
+            Ok(account_id) => Self::from_account_id(account_id),
+        }
+    }
+}
+
+/// The first 32 bits of the MD5 of `CSGO` and the account ID, as friend codes carry them.
+fn friend_code_hash(account_id: u32) -> u32 {
+    let digest = md5::compute(((0x4353_474F_u64 << 32) | account_id as u64).to_le_bytes());
+    u32::from_le_bytes([digest.0[0], digest.0[1], digest.0[2], digest.0[3]])
 }
 
 impl From<u64> for SteamId {
@@ -326,20 +494,62 @@ fn from(value: u64) -> Self {
     }
 }
 
-impl From<String> for SteamId {
-    /// Formats the `SteamId` as a `String`.
-    fn from(value: String) -> Self {
-        SteamId(value.parse::<u64>().unwrap())
+impl FromStr for SteamId {
+    type Err = SteamIdError;
+
+    /// Parses a SteamID2, SteamID3 or SteamID64 string, see [`SteamId::new_smart`].
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        SteamId::new_smart(value)
+    }
+}
+
+impl TryFrom<&str> for SteamId {
+    type Error = SteamIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        SteamId::new_smart(value)
+    }
+}
+
+impl TryFrom<String> for SteamId {
+    type Error = SteamIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        SteamId::new_smart(&value)
     }
 }
 
 impl<'de> Deserialize<'de> for SteamId {
+    /// Accepts a SteamID64 as a number, or any string [`SteamId::new_smart`] accepts. Steam sends both.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        Ok(SteamId::from(s))
+        deserializer.deserialize_any(SteamIdVisitor)
+    }
+}
+
+struct SteamIdVisitor;
+
+impl Visitor<'_> for SteamIdVisitor {
+    type Value = SteamId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a SteamID64 as a string or integer, or a SteamID2 or SteamID3 string")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(SteamId(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u64::try_from(value)
+            .map(SteamId)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        SteamId::new_smart(value).map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
     }
 }
 
@@ -352,6 +562,280 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     }
 }
 
+/// The ways a Steam ID is written, as recognised by [`SteamIdInput::parse`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SteamIdForm {
+    /// A SteamID64, e.g. `76561197960287930`.
+    SteamId64,
+    /// A SteamID2, e.g. `STEAM_1:0:11101`.
+    SteamId2,
+    /// A SteamID3, with or without brackets, e.g. `[U:1:22202]`.
+    SteamId3,
+    /// A community profile URL, e.g. `https://steamcommunity.com/profiles/76561197960287930`.
+    ProfileUrl,
+    /// A community vanity URL, e.g. `https://steamcommunity.com/id/gabelogannewell`.
+    VanityUrl,
+    /// A quick invite link, e.g. `https://s.team/p/hj-qp`.
+    InviteLink,
+    /// A community group URL, e.g. `https://steamcommunity.com/gid/103582791429521412`.
+    GroupUrl,
+    /// A `steam://` link, e.g. `steam://url/SteamIDPage/76561197960287930`.
+    SteamLink,
+    /// A friend code, e.g. `SUCVS-FADA`.
+    FriendCode,
+    /// A bare vanity name, e.g. `gabelogannewell`.
+    VanityName,
+}
+
+impl fmt::Display for SteamIdForm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::SteamId64 => "SteamID64",
+            Self::SteamId2 => "SteamID2",
+            Self::SteamId3 => "SteamID3",
+            Self::ProfileUrl => "profile URL",
+            Self::VanityUrl => "vanity URL",
+            Self::InviteLink => "quick invite link",
+            Self::GroupUrl => "group URL",
+            Self::SteamLink => "steam:// link",
+            Self::FriendCode => "friend code",
+            Self::VanityName => "vanity name",
+        })
+    }
+}
+
+/// Something a user wrote to refer to an account, e.g. pasted from a browser.
+///
+/// Use [`Steam::resolve_steam_id`](crate::Steam::resolve_steam_id) to also resolve vanity names.
+///
+/// # Examples
+///
+/// ```
+/// use steam_rs::steam_id::{SteamId, SteamIdForm, SteamIdInput};
+///
+/// let input = SteamIdInput::parse("https://steamcommunity.com/profiles/76561197960287930/").unwrap();
+/// assert_eq!(
+///     input,
+///     SteamIdInput::Id { steam_id: SteamId(76561197960287930), form: SteamIdForm::ProfileUrl }
+/// );
+///
+/// let input = SteamIdInput::parse("https://steamcommunity.com/id/gabelogannewell").unwrap();
+/// assert_eq!(
+///     input,
+///     SteamIdInput::Vanity { name: "gabelogannewell".to_string(), form: SteamIdForm::VanityUrl }
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SteamIdInput {
+    /// A Steam ID that was decoded without asking Steam.
+    Id {
+        /// The Steam ID.
+        steam_id: SteamId,
+        /// The form it was written in.
+        form: SteamIdForm,
+    },
+    /// A vanity name, which only Steam can resolve.
+    Vanity {
+        /// The vanity name.
+        name: String,
+        /// The form it was written in, [`SteamIdForm::VanityUrl`] or [`SteamIdForm::VanityName`].
+        form: SteamIdForm,
+    },
+}
+
+impl SteamIdInput {
+    /// Recognises SteamID64, SteamID2 and SteamID3 strings, community profile, vanity, quick invite and group URLs
+    /// (with or without the scheme), `steam://` links, friend codes and bare vanity names. Surrounding whitespace is ignored.
+    ///
+    /// A vanity name that happens to be a valid friend code is taken for the friend code.
+    ///
+    /// Only fails with [`ResolveSteamIdError::Unrecognized`] or [`ResolveSteamIdError::Invalid`].
+    pub fn parse(input: &str) -> Result<Self, ResolveSteamIdError> {
+        let input = input.trim();
+        let invalid = |form| ResolveSteamIdError::Invalid {
+            form,
+            input: input.to_string(),
+        };
+        let id = |parsed: Option<SteamId>, form| match parsed {
+            Some(steam_id) => Ok(SteamIdInput::Id { steam_id, form }),
+            None => Err(invalid(form)),
+        };
+
+        if let Some(link) = input.strip_prefix("steam://") {
+            if let Some(url) = link.strip_prefix("openurl/") {
+                return Self::parse(url);
+            }
+            let id64 = link
+                .strip_prefix("url/SteamIDPage/")
+                .or_else(|| link.strip_prefix("friends/add/"));
+            if let Some(id64) = id64 {
+                let id64 = id64.trim_end_matches('/');
+                return id(id64.parse().ok().map(SteamId), SteamIdForm::SteamLink);
+            }
+        } else if let Some((host, path)) = community_url(input) {
+            let path = path.split(['?', '#']).next().unwrap_or_default();
+            let mut segments = path.split('/');
+            let kind = segments.next();
+            let value = segments.next().filter(|value| !value.is_empty());
+
+            return match (host, kind, value) {
+                ("steamcommunity.com", Some("profiles"), Some(value)) => {
+                    id(SteamId::new_smart(value).ok(), SteamIdForm::ProfileUrl)
+                }
+                ("steamcommunity.com", Some("id"), Some(name)) if is_vanity_name(name) => {
+                    Ok(SteamIdInput::Vanity {
+                        name: name.to_string(),
+                        form: SteamIdForm::VanityUrl,
+                    })
+                }
+                ("steamcommunity.com", Some("user"), Some(code))
+                | ("s.team", Some("p"), Some(code)) => id(
+                    SteamId::from_invite_code(code).ok(),
+                    SteamIdForm::InviteLink,
+                ),
+                ("steamcommunity.com", Some("gid"), Some(value)) => id(
+                    SteamId::new_smart(value).ok().filter(SteamId::is_clan),
+                    SteamIdForm::GroupUrl,
+                ),
+                ("steamcommunity.com", Some("profiles"), _) => {
+                    Err(invalid(SteamIdForm::ProfileUrl))
+                }
+                ("steamcommunity.com", Some("id"), _) => Err(invalid(SteamIdForm::VanityUrl)),
+                ("steamcommunity.com", Some("gid"), _) => Err(invalid(SteamIdForm::GroupUrl)),
+                ("steamcommunity.com", Some("user"), _) | ("s.team", Some("p"), _) => {
+                    Err(invalid(SteamIdForm::InviteLink))
+                }
+                _ => Err(ResolveSteamIdError::Unrecognized {
+                    input: input.to_string(),
+                }),
+            };
+        } else if input.starts_with("STEAM_") {
+            return id(SteamId::new_smart(input).ok(), SteamIdForm::SteamId2);
+        } else if input.starts_with('[') {
+            return id(SteamId::new_smart(input).ok(), SteamIdForm::SteamId3);
+        } else if input.as_bytes().get(1) == Some(&b':')
+            && input.as_bytes()[0].is_ascii_alphabetic()
+        {
+            return id(
+                SteamId::new_smart(&format!("[{input}]")).ok(),
+                SteamIdForm::SteamId3,
+            );
+        } else if !input.is_empty() && input.bytes().all(|byte| byte.is_ascii_digit()) {
+            return id(input.parse().ok().map(SteamId), SteamIdForm::SteamId64);
+        } else if let Ok(steam_id) = SteamId::from_friend_code(input) {
+            return Ok(SteamIdInput::Id {
+                steam_id,
+                form: SteamIdForm::FriendCode,
+            });
+        } else if is_vanity_name(input) {
+            return Ok(SteamIdInput::Vanity {
+                name: input.to_string(),
+                form: SteamIdForm::VanityName,
+            });
+        }
+
+        Err(ResolveSteamIdError::Unrecognized {
+            input: input.to_string(),
+        })
+    }
+}
+
+/// Splits a Steam Community or `s.team` URL into its host and its path, without the leading `/`.
+pub(crate) fn community_url(input: &str) -> Option<(&str, &str)> {
+    let url = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .unwrap_or(input);
+    let url = url.strip_prefix("www.").unwrap_or(url);
+
+    ["steamcommunity.com", "s.team"]
+        .into_iter()
+        .find_map(|host| {
+            let path = url.strip_prefix(host)?;
+            match path.strip_prefix('/') {
+                Some(path) => Some((host, path)),
+                None if path.is_empty() => Some((host, path)),
+                None => None,
+            }
+        })
+}
+
+/// Vanity names are 2 to 32 letters, digits, `-` or `_`.
+fn is_vanity_name(name: &str) -> bool {
+    (2..=32).contains(&name.len())
+        && name
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_')
+}
+
+/// Why user input could not be resolved to a [`SteamId`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ResolveSteamIdError {
+    /// The input is not written in any [`SteamIdForm`].
+    Unrecognized {
+        /// The input, without surrounding whitespace.
+        input: String,
+    },
+
+    /// The input is written in `form`, but the ID in it is malformed or out of range.
+    Invalid {
+        /// The form that was recognised.
+        form: SteamIdForm,
+        /// The input, without surrounding whitespace.
+        input: String,
+    },
+
+    /// Steam knows no profile by the vanity name.
+    NoMatch {
+        /// The form that was recognised.
+        form: SteamIdForm,
+        /// The vanity name.
+        name: String,
+    },
+
+    /// Asking Steam to resolve the vanity name failed.
+    Request {
+        /// The form that was recognised.
+        form: SteamIdForm,
+        /// The vanity name.
+        name: String,
+        /// Why the request failed.
+        error: SteamError,
+    },
+}
+
+impl fmt::Display for ResolveSteamIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unrecognized { input } => {
+                write!(f, "`{input}` is not a Steam ID, profile URL or vanity name")
+            }
+            Self::Invalid { form, input } => {
+                write!(
+                    f,
+                    "`{input}` looks like a {form} but holds no valid Steam ID"
+                )
+            }
+            Self::NoMatch { form, name } => {
+                write!(f, "No profile has the vanity name `{name}` (from a {form})")
+            }
+            Self::Request { name, error, .. } => {
+                write!(f, "Failed to resolve the vanity name `{name}`: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResolveSteamIdError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Request { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
 /// Error type for parsing a `SteamId` from a `string`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ParseSteamIdError;
@@ -447,3 +931,174 @@ pub enum Universe {
     Internal,
     Dev,
 }
+
+/// Identifies what a user is playing: a Steam app, a mod of one, a non-Steam shortcut or a P2P game.
+///
+/// It packs an app ID (the lower 24 bits), a [`GameType`] (the next 8 bits) and a mod ID (the upper 32 bits). The mod
+/// ID of mods and shortcuts is a CRC32 with the highest bit set.
+///
+/// # Examples
+///
+/// ```
+/// use steam_rs::{
+///     ids::AppId,
+///     steam_id::{GameId, GameType},
+/// };
+///
+/// let game_id: GameId = "440".parse().unwrap();
+/// assert_eq!(game_id.get_app_id(), AppId(440));
+/// assert_eq!(game_id.get_game_type(), Some(GameType::App));
+///
+/// let shortcut = GameId::from_shortcut("\"C:\\Games\\Doom\\doom.exe\"", "Doom");
+/// assert!(shortcut.is_shortcut());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GameId(pub u64);
+
+impl GameId {
+    /// Creates a new `GameId` instance with the given 64-bit unsigned integer value.
+    pub fn new(value: u64) -> Self {
+        GameId(value)
+    }
+
+    /// Creates the `GameId` of a Steam app.
+    pub fn from_app_id(app_id: AppId) -> Self {
+        GameId(app_id.0 as u64 & 0xFFFFFF)
+    }
+
+    /// Creates the `GameId` of a mod of `app_id`, e.g. a Source mod, from the name of its directory, e.g. `"cstrike"`.
+    pub fn from_mod(app_id: AppId, mod_directory: &str) -> Self {
+        let mod_id = crc32(mod_directory.as_bytes()) | 0x80000000;
+        Self::from_parts(app_id, GameType::GameMod, mod_id)
+    }
+
+    /// Creates the `GameId` Steam gives a non-Steam game added as a shortcut.
+    ///
+    /// `exe` is the path of the executable as Steam stores it, quotes included, e.g. `"C:\Games\doom.exe"`, and
+    /// `name` the name of the shortcut.
+    pub fn from_shortcut(exe: &str, name: &str) -> Self {
+        let mod_id = crc32(format!("{exe}{name}").as_bytes()) | 0x80000000;
+        Self::from_parts(AppId(0), GameType::Shortcut, mod_id)
+    }
+
+    /// Creates a `GameId` from its parts. The app ID is truncated to 24 bits.
+    pub fn from_parts(app_id: AppId, game_type: GameType, mod_id: u32) -> Self {
+        GameId((mod_id as u64) << 32 | (game_type as u64) << 24 | (app_id.0 as u64 & 0xFFFFFF))
+    }
+
+    /// Converts the `GameId` into its underlying 64-bit unsigned integer value.
+    pub fn into_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// Get the app the game is, or is a mod of. Shortcuts have none, i.e. `AppId(0)`.
+    pub fn get_app_id(&self) -> AppId {
+        AppId((self.0 & 0xFFFFFF) as u32)
+    }
+
+    /// Get the type of the game, or `None` if it is none Steam knows of.
+    pub fn get_game_type(&self) -> Option<GameType> {
+        GameType::try_from((self.0 >> 24) & 0xFF).ok()
+    }
+
+    /// Get the mod ID of the game: a CRC32 with the highest bit set for mods and shortcuts, and 0 for apps.
+    pub fn get_mod_id(&self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+
+    /// Returns whether the game is a Steam app.
+    pub fn is_app(&self) -> bool {
+        self.get_game_type() == Some(GameType::App)
+    }
+
+    /// Returns whether the game is a mod of a Steam app.
+    pub fn is_mod(&self) -> bool {
+        self.get_game_type() == Some(GameType::GameMod)
+    }
+
+    /// Returns whether the game is a non-Steam game added as a shortcut.
+    pub fn is_shortcut(&self) -> bool {
+        self.get_game_type() == Some(GameType::Shortcut)
+    }
+
+    /// Returns whether the game is a P2P game.
+    pub fn is_p2p(&self) -> bool {
+        self.get_game_type() == Some(GameType::P2P)
+    }
+}
+
+impl fmt::Display for GameId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for GameId {
+    fn from(value: u64) -> Self {
+        GameId(value)
+    }
+}
+
+impl From<AppId> for GameId {
+    fn from(app_id: AppId) -> Self {
+        GameId::from_app_id(app_id)
+    }
+}
+
+impl FromStr for GameId {
+    type Err = ParseIntError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value.parse().map(GameId)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_any(IdVisitor::<u64>(PhantomData))
+            .map(GameId)
+    }
+}
+
+impl Serialize for GameId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&self.0)
+    }
+}
+
+/// What kind of game a [`GameId`] identifies.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, TryFromPrimitive)]
+#[repr(u64)]
+pub enum GameType {
+    /// A Steam app.
+    App,
+    /// A mod of a Steam app, e.g. a Source mod.
+    GameMod,
+    /// A non-Steam game added to Steam as a shortcut.
+    Shortcut,
+    /// A P2P game.
+    P2P,
+}
+
+/// The CRC-32 (IEEE) checksum Steam derives mod and shortcut IDs from.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB88320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
@@ -1,9 +1,10 @@
 //! Implements the `GetNewsForApp` endpoint
 
 use crate::{
-    errors::{ErrorHandle, SteamNewsError},
-    macros::{do_http, optional_argument},
-    Steam, BASE,
+    errors::SteamError,
+    ids::AppId,
+    macros::{do_http, EndPoint},
+    query::Query,
 };
 use serde::{Deserialize, Serialize};
 
@@ -24,13 +25,13 @@ pub struct NewsItem {
     pub date: u32,
     pub feedname: String,
     pub feed_type: u8,
-    pub appid: u32,
+    pub appid: AppId,
     pub tags: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AppNews {
-    pub appid: u32, // TODO: Convert to AppId
+    pub appid: AppId,
     pub newsitems: Vec<NewsItem>,
     pub count: u32,
 }
@@ -40,38 +41,40 @@ struct Response {
     appnews: AppNews,
 }
 
-impl Steam {
+EndPoint!(
     /// Get the news for the specified app.
-    ///
-    /// # Arguments
-    ///
-    /// * `appid` - The ID of the application (game) for which to retrieve news for.
-    /// * `max_length` - Maximum length for the content to return, if this is 0 the full content is returned, if it's less then a blurb is generated to fit.
-    /// * `end_date` - Retrieve posts earlier than this date (unix epoch timestamp).
-    /// * `count` - Number of posts to retrieve (default 20).
-    /// * `feeds` - Comma-seperated list of feed names to return news for.
-    pub async fn get_news_for_app(
-        appid: u32,
-        max_length: Option<u32>,
-        end_date: Option<u32>,
-        count: Option<u32>,
-        feeds: Option<Vec<&str>>,
-    ) -> Result<AppNews, SteamNewsError> {
-        // DO NOT RAYON THIS! - Rayon doesn't protect the order of data!
-        let feeds: Option<String> =
-            feeds.map(|feeds| feeds.iter().map(|&feed| feed.to_string() + ",").collect());
-
-        let optional_arguments = vec![
-            optional_argument!(max_length, "maxlength"),
-            optional_argument!(end_date, "enddate"),
-            optional_argument!(count),
-            optional_argument!(feeds),
-        ];
-
-        let query = format!("?appid={}{}", appid, optional_arguments.concat());
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
+    get_news_for_app -> GetNewsForApp<'a> => AppNews {
+        required {
+            /// The ID of the application (game) for which to retrieve news for.
+            appid: AppId,
+        }
+        optional {
+            /// Maximum length for the content to return, if this is 0 the full content is returned, if it's less then a blurb is generated to fit.
+            max_length: u32,
+            /// Retrieve posts earlier than this date (unix epoch timestamp).
+            end_date: u32,
+            /// Number of posts to retrieve (default 20).
+            count: u32,
+            /// Comma-seperated list of feed names to return news for.
+            feeds: &'a [&'a str],
+        }
+    }
+);
 
-        let response = do_http!(url, Response, ErrorHandle, SteamNewsError::GetNews);
+impl GetNewsForApp<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<AppNews, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
+        );
+        let query = Query::new()
+            .param("appid", self.appid)
+            .optional("maxlength", self.max_length)
+            .optional("enddate", self.end_date)
+            .optional("count", self.count)
+            .optional("feeds", self.feeds.map(|feeds| feeds.join(",")));
+        let response = do_http!(self.steam, url, Response, query);
 
         Ok(response.appnews)
     }
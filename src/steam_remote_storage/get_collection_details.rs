@@ -1,10 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    errors::{ErrorHandle, SteamRemoteStorageError},
-    macros::do_http,
-    Steam, BASE,
-};
+use crate::{errors::SteamError, ids::PublishedFileId, macros::do_http, query::Query, Steam};
 
 use super::INTERFACE;
 
@@ -14,7 +10,7 @@
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CollectionDetails {
     #[serde(rename = "publishedfileid")]
-    pub published_file_id: String,
+    pub published_file_id: PublishedFileId,
     pub result: u32,
 }
 
@@ -34,25 +30,14 @@ struct Wrapper {
 
 impl Steam {
     pub async fn get_collection_details(
-        published_fileids: &[u64],
-    ) -> Result<Response, SteamRemoteStorageError> {
-        let url = format!("{BASE}/{INTERFACE}/{ENDPOINT}/v{VERSION}");
-
-        let mut params = String::new();
-
-        params.push_str(&format!("collectioncount={}", published_fileids.len()));
-
-        for (index, fileid) in published_fileids.iter().enumerate() {
-            params.push_str(&format!("&publishedfileids[{}]={}", index, fileid));
-        }
-
-        let wrapper = do_http!(
-            url,
-            Wrapper,
-            ErrorHandle,
-            SteamRemoteStorageError::GetCollectionDetails,
-            params
-        );
+        &self,
+        published_fileids: &[PublishedFileId],
+    ) -> Result<Response, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new()
+            .param("collectioncount", published_fileids.len())
+            .array("publishedfileids", published_fileids);
+        let wrapper = do_http!(self, POST url, Wrapper, query);
 
         Ok(wrapper.response)
     }
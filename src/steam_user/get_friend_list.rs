@@ -3,14 +3,8 @@
 use core::fmt;
 
 use serde::Deserialize;
-use serde_json::{from_value, Value};
 
-use crate::{
-    errors::{ErrorHandle, SteamUserError},
-    macros::{do_http, optional_argument},
-    steam_id::SteamId,
-    Steam, BASE,
-};
+use crate::{errors::SteamError, macros::EndPoint, query::Query, steam_id::SteamId};
 
 use super::INTERFACE;
 
@@ -66,29 +60,44 @@ struct Wrapper {
     friends_list: Option<FriendsList>,
 }
 
-impl Steam {
+EndPoint!(
     /// Get a user's friend list.
     ///
-    /// # Arguments
-    ///
-    /// * `steam_id` - The SteamID of the user.
-    /// * `relationship` - Optional relationship type (e.g., `Relationship::Friend`).
-    pub async fn get_friend_list(
-        &self,
-        steam_id: SteamId,                  // SteamID of user
-        relationship: Option<Relationship>, // relationship type (ex: Relationship::Friend)
-    ) -> Result<Vec<Friend>, SteamUserError> {
-        let query = format!(
-            "?key={}&steamid={}{}",
-            &self.api_key,
-            steam_id,
-            optional_argument!(relationship)
+    /// Fails with [`SteamError::PrivateProfile`] if the user's friend list is not public.
+    get_friend_list -> GetFriendList<'a> => Vec<Friend> {
+        required {
+            /// The SteamID of the user.
+            steam_id: SteamId,
+        }
+        optional {
+            /// Optional relationship type (e.g., `Relationship::Friend`).
+            relationship: Relationship,
+        }
+    }
+);
+
+impl GetFriendList<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<Vec<Friend>, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-        let json = do_http!(url, Value, ErrorHandle, SteamUserError::GetFriendList);
-        let wrapper: Wrapper =
-            ErrorHandle!(from_value(json.to_owned()), SteamUserError::GetFriendList);
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("steamid", self.steam_id)
+            .optional("relationship", self.relationship);
+        // Steam answers `401 Unauthorized` when the profile's friend list is not public.
+        let wrapper: Wrapper = match self.steam.get_json(&url, query).await {
+            Err(SteamError::Unauthorized { status, .. }) if status == 401 => {
+                return Err(SteamError::PrivateProfile)
+            }
+            result => result?,
+        };
 
-        Ok(wrapper.friends_list.unwrap().friends)
+        Ok(wrapper
+            .friends_list
+            .map(|friends_list| friends_list.friends)
+            .unwrap_or_default())
     }
 }
@@ -1,13 +1,11 @@
 //! Implements the `GetPlayerBans` endpoint
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
-use serde_json::{from_value, Value};
 
 use crate::{
-    errors::{ErrorHandle, SteamUserError},
-    macros::do_http,
-    steam_id::SteamId,
-    Steam, BASE,
+    bulk::by_steam_id, errors::SteamError, macros::do_http, query::Query, steam_id::SteamId, Steam,
 };
 
 use super::INTERFACE;
@@ -24,9 +22,9 @@ struct Wrapper {
 /// Represents a player object with data about bans.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Player {
-    /// A string containing the player's 64-bit ID.
+    /// The player's 64-bit ID.
     #[serde(rename = "SteamId")]
-    pub steam_id: String, // TODO: Convert to SteamId
+    pub steam_id: SteamId,
 
     /// Boolean indicating whether or not the player is banned
     /// from the [Steam Community](http://steamcommunity.com/).
@@ -59,22 +57,47 @@ pub struct Player {
 impl Steam {
     /// Get player ban/probation status.
     ///
+    /// Any number of SteamIDs may be given: repeated IDs are sent once, and more than 100 are split into several
+    /// requests sent at once, up to
+    /// [`SteamBuilder::max_concurrent_requests`](crate::SteamBuilder::max_concurrent_requests).
+    ///
     /// # Arguments
     ///
     /// * `steam_ids` - A vector of `SteamId` objects
     pub async fn get_player_bans(
         &self,
         steam_ids: Vec<SteamId>,
-    ) -> Result<Vec<Player>, SteamUserError> {
-        let steam_ids: String = steam_ids.iter().map(|&id| id.to_string() + ",").collect();
-
-        let query = format!("?key={}&steamids={}", &self.api_key, steam_ids);
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-
-        let json = do_http!(url, Value, ErrorHandle, SteamUserError::GetPlayerBans);
-        let wrapper: Wrapper =
-            ErrorHandle!(from_value(json.to_owned()), SteamUserError::GetPlayerBans);
+    ) -> Result<Vec<Player>, SteamError> {
+        self.fan_out(steam_ids, fetch_player_bans).await
+    }
 
-        Ok(wrapper.players)
+    /// Get player ban/probation status by SteamID, like [`Steam::get_player_bans`]. Every SteamID given is a key,
+    /// mapped to `None` if Steam returned nothing for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `steam_ids` - A vector of `SteamId` objects
+    pub async fn get_player_bans_by_id(
+        &self,
+        steam_ids: Vec<SteamId>,
+    ) -> Result<HashMap<SteamId, Option<Player>>, SteamError> {
+        let players = self.get_player_bans(steam_ids.clone()).await?;
+        Ok(by_steam_id(steam_ids, players, |player| player.steam_id))
     }
 }
+
+async fn fetch_player_bans(
+    steam: Steam,
+    steam_ids: Vec<SteamId>,
+) -> Result<Vec<Player>, SteamError> {
+    let url = format!(
+        "{}/{}/{}/v{}/",
+        steam.base_url, INTERFACE, ENDPOINT, VERSION
+    );
+    let query = Query::new()
+        .param("key", &steam.api_key)
+        .list("steamids", steam_ids);
+    let wrapper: Wrapper = do_http!(steam, url, Wrapper, query);
+
+    Ok(wrapper.players)
+}
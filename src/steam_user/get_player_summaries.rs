@@ -1,13 +1,16 @@
 //! Implements the `GetPlayerSummaries` endpoint
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
-use serde_json::{from_value, Value};
 
 use crate::{
-    errors::{ErrorHandle, SteamUserError},
+    bulk::by_steam_id,
+    errors::SteamError,
     macros::do_http,
-    steam_id::SteamId,
-    Steam, BASE,
+    query::Query,
+    steam_id::{GameId, SteamId},
+    Steam,
 };
 
 use super::INTERFACE;
@@ -15,9 +18,101 @@
 const ENDPOINT: &str = "GetPlayerSummaries";
 const VERSION: &str = "0002";
 
+macro_rules! state {
+    ($(#[$meta:meta])* $name:ident { $($(#[$variant_meta:meta])* $variant:ident = $value:literal,)* }) => {
+        $(#[$meta])*
+        ///
+        /// Values this crate does not know are kept in `Unknown`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(from = "u8", into = "u8")]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// A value this crate does not know.
+            Unknown(u8),
+        }
+
+        impl From<u8> for $name {
+            fn from(value: u8) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    value => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+    };
+}
+
+state!(
+    /// A user's status.
+    PersonaState {
+        /// Offline, which is also reported for private profiles.
+        Offline = 0,
+        /// Online.
+        Online = 1,
+        /// Busy.
+        Busy = 2,
+        /// Away.
+        Away = 3,
+        /// Snooze.
+        Snooze = 4,
+        /// Looking to trade.
+        LookingToTrade = 5,
+        /// Looking to play.
+        LookingToPlay = 6,
+        /// Invisible, reported as offline to others.
+        Invisible = 7,
+    }
+);
+
+state!(
+    /// Whether a profile is visible to the caller.
+    ///
+    /// The Web API only reports `Private` (for private and friends only profiles alike) or `Public`.
+    CommunityVisibility {
+        /// The profile is not visible to the caller.
+        Private = 1,
+        /// The profile is only visible to the user's friends.
+        FriendsOnly = 2,
+        /// The profile is visible to everyone.
+        Public = 3,
+    }
+);
+
+state!(
+    /// Whether a user has set up their community profile.
+    ProfileState {
+        /// The profile has not been set up.
+        NotConfigured = 0,
+        /// The profile has been set up.
+        Configured = 1,
+    }
+);
+
+state!(
+    /// Who may comment on a profile.
+    CommentPermission {
+        /// Friends only.
+        FriendsOnly = 0,
+        /// Anyone.
+        Public = 1,
+        /// Nobody but the user.
+        Private = 2,
+    }
+);
+
 /// Represents a user profile object.
 ///
 /// Contained information varies depending on whether or not the user has their profile set to Friends only or Private.
+/// Use [`Player::into_profile`] to separate the fields every profile has from those only public profiles have.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Player {
     /// The user's 64-bit ID
@@ -47,53 +142,42 @@ pub struct Player {
     #[serde(rename = "avatarhash")]
     pub avatar_hash: String,
 
-    /// The user's status
-    /// - 0: Offline (Also set when the profile is Private)
-    /// - 1: Online
-    /// - 2: Busy
-    /// - 3: Away
-    /// - 4: Snooze
-    /// - 5: Looking to trade
-    /// - 6: Looking to play
+    /// The user's status. Always [`PersonaState::Offline`] when the profile is private.
     #[serde(rename = "personastate")]
-    pub persona_state: u8,
-
-    /// An integer that describes the access setting of the profile.
-    /// - 1: Private
-    /// - 2: Friends only
-    /// - 3: Friends of Friends
-    /// - 4: Users Only
-    /// - 5: Public
+    pub persona_state: PersonaState,
+
+    /// Whether the profile is visible to the caller.
     #[serde(rename = "communityvisibilitystate")]
-    pub community_visibility_state: u8,
+    pub community_visibility_state: CommunityVisibility,
 
-    /// If set to 1, the user has configured the profile.
+    /// Whether the user has configured the profile.
     #[serde(rename = "profilestate")]
-    pub profile_state: Option<u8>,
+    pub profile_state: Option<ProfileState>,
 
     /// A unix timestamp of when the user was last online.
     #[serde(rename = "lastlogoff")]
     pub last_logoff: Option<u64>,
 
-    /// If present the profile allows public comments.
+    /// Who may comment on the profile.
     #[serde(rename = "commentpermission")]
-    pub comment_permission: Option<u8>,
+    pub comment_permission: Option<CommentPermission>,
 
     /// The user's real name.
     #[serde(rename = "realname")]
     pub real_name: Option<String>,
 
-    /// The 64-bit ID of the user's primary group.
+    /// The clan SteamID of the user's primary group.
     #[serde(rename = "primaryclanid")]
-    pub primary_clan_id: Option<String>,
+    pub primary_clan_id: Option<SteamId>,
 
     /// A unix timestamp of the date the profile was created.
     #[serde(rename = "timecreated")]
     pub time_created: Option<u64>,
 
-    /// If the user is in game this will be set to it's app ID as a string.
+    /// If the user is in game this will be set to the game they are playing, which may be a mod or a non-Steam
+    /// shortcut rather than a Steam app.
     #[serde(rename = "gameid")]
-    pub game_id: Option<String>,
+    pub game_id: Option<GameId>,
 
     /// The server URL given as an IP address and port number separated by a colon.
     /// This will not be present or set to "0.0.0.0:0" if none is available.
@@ -117,6 +201,154 @@ pub struct Player {
     pub loc_city_id: Option<u64>,
 }
 
+impl Player {
+    /// Returns whether the profile is visible to the caller, and so has more than its summary.
+    pub fn is_public(&self) -> bool {
+        self.community_visibility_state == CommunityVisibility::Public
+    }
+
+    /// Splits the player into the fields Steam sends for every profile and, for public profiles, the fields it only
+    /// sends for those.
+    pub fn into_profile(self) -> PlayerProfile {
+        let public = self.is_public();
+        let summary = ProfileSummary {
+            steam_id: self.steam_id,
+            persona_name: self.persona_name,
+            profile_url: self.profile_url,
+            avatar: self.avatar,
+            avatar_medium: self.avatar_medium,
+            avatar_full: self.avatar_full,
+            avatar_hash: self.avatar_hash,
+            persona_state: self.persona_state,
+            community_visibility_state: self.community_visibility_state,
+            profile_state: self.profile_state,
+            last_logoff: self.last_logoff,
+            comment_permission: self.comment_permission,
+        };
+
+        if !public {
+            return PlayerProfile::Private(summary);
+        }
+
+        let details = ProfileDetails {
+            real_name: self.real_name,
+            primary_clan_id: self.primary_clan_id,
+            time_created: self.time_created,
+            game_id: self.game_id,
+            game_server_ip: self.game_server_ip,
+            game_extra_info: self.game_extra_info,
+            loc_country_code: self.loc_country_code,
+            loc_state_code: self.loc_state_code,
+            loc_city_id: self.loc_city_id,
+        };
+        PlayerProfile::Public(summary, details)
+    }
+}
+
+/// A [`Player`] split by what Steam reveals of the profile, see [`Player::into_profile`].
+#[derive(Debug, Clone)]
+pub enum PlayerProfile {
+    /// A profile visible to the caller.
+    Public(ProfileSummary, ProfileDetails),
+
+    /// A private or friends only profile, of which Steam only sends the summary.
+    Private(ProfileSummary),
+}
+
+impl PlayerProfile {
+    /// Get the fields every profile has.
+    pub fn summary(&self) -> &ProfileSummary {
+        match self {
+            PlayerProfile::Public(summary, _) | PlayerProfile::Private(summary) => summary,
+        }
+    }
+
+    /// Get the fields only public profiles have, or `None` if the profile is private.
+    pub fn details(&self) -> Option<&ProfileDetails> {
+        match self {
+            PlayerProfile::Public(_, details) => Some(details),
+            PlayerProfile::Private(_) => None,
+        }
+    }
+}
+
+impl From<Player> for PlayerProfile {
+    fn from(player: Player) -> Self {
+        player.into_profile()
+    }
+}
+
+/// The fields of a [`Player`] Steam sends for every profile, private or not.
+#[derive(Debug, Clone)]
+pub struct ProfileSummary {
+    /// The user's 64-bit ID
+    pub steam_id: SteamId,
+
+    /// User's display name.
+    pub persona_name: String,
+
+    /// The URL to the user's Steam Community profile.
+    pub profile_url: String,
+
+    /// The URL to the user's avatar as a 32x32 image
+    pub avatar: String,
+
+    /// The URL to the user's avatar as a 64x64 image
+    pub avatar_medium: String,
+
+    /// The URL to the user's avatar as a 184x184 image
+    pub avatar_full: String,
+
+    /// Hash of the user's avatar
+    pub avatar_hash: String,
+
+    /// The user's status. Always [`PersonaState::Offline`] when the profile is private.
+    pub persona_state: PersonaState,
+
+    /// Whether the profile is visible to the caller.
+    pub community_visibility_state: CommunityVisibility,
+
+    /// Whether the user has configured the profile.
+    pub profile_state: Option<ProfileState>,
+
+    /// A unix timestamp of when the user was last online.
+    pub last_logoff: Option<u64>,
+
+    /// Who may comment on the profile.
+    pub comment_permission: Option<CommentPermission>,
+}
+
+/// The fields of a [`Player`] Steam only sends for public profiles.
+#[derive(Debug, Clone)]
+pub struct ProfileDetails {
+    /// The user's real name.
+    pub real_name: Option<String>,
+
+    /// The clan SteamID of the user's primary group.
+    pub primary_clan_id: Option<SteamId>,
+
+    /// A unix timestamp of the date the profile was created.
+    pub time_created: Option<u64>,
+
+    /// If the user is in game this will be set to the game they are playing.
+    pub game_id: Option<GameId>,
+
+    /// The server URL given as an IP address and port number separated by a colon.
+    pub game_server_ip: Option<String>,
+
+    /// The title of the game that the user is playing.
+    pub game_extra_info: Option<String>,
+
+    /// ISO 3166 code of where the user is located.
+    pub loc_country_code: Option<String>,
+
+    /// Variable length code representing the state the user is located in.
+    pub loc_state_code: Option<String>,
+
+    /// An integer ID internal to Steam representing the user's city.
+    pub loc_city_id: Option<u64>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct PlayerSummary {
     /// A list of profile objects. Contained information varies depending on
@@ -132,24 +364,48 @@ struct Wrapper {
 impl Steam {
     /// Get user profile data.
     ///
+    /// Any number of SteamIDs may be given: repeated IDs are sent once, and more than 100 are split into several
+    /// requests sent at once, up to
+    /// [`SteamBuilder::max_concurrent_requests`](crate::SteamBuilder::max_concurrent_requests).
+    /// Profiles that don't exist are left out.
+    ///
     /// # Arguments
     ///
     /// * `steam_ids` - A vector of `SteamId` objects
     pub async fn get_player_summaries(
         &self,
         steam_ids: Vec<SteamId>,
-    ) -> Result<Vec<Player>, SteamUserError> {
-        let steam_ids: String = steam_ids.iter().map(|&id| id.to_string() + ",").collect();
+    ) -> Result<Vec<Player>, SteamError> {
+        self.fan_out(steam_ids, fetch_player_summaries).await
+    }
 
-        let query = format!("?key={}&steamids={}", &self.api_key, steam_ids);
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
+    /// Get user profile data by SteamID, like [`Steam::get_player_summaries`]. Every SteamID given is a key, mapped
+    /// to `None` if Steam returned nothing for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `steam_ids` - A vector of `SteamId` objects
+    pub async fn get_player_summaries_by_id(
+        &self,
+        steam_ids: Vec<SteamId>,
+    ) -> Result<HashMap<SteamId, Option<Player>>, SteamError> {
+        let players = self.get_player_summaries(steam_ids.clone()).await?;
+        Ok(by_steam_id(steam_ids, players, |player| player.steam_id))
+    }
+}
 
-        let json = do_http!(url, Value, ErrorHandle, SteamUserError::GetPlayerSummaries);
-        let wrapper: Wrapper = ErrorHandle!(
-            from_value(json.to_owned()),
-            SteamUserError::GetPlayerSummaries
-        );
+async fn fetch_player_summaries(
+    steam: Steam,
+    steam_ids: Vec<SteamId>,
+) -> Result<Vec<Player>, SteamError> {
+    let url = format!(
+        "{}/{}/{}/v{}/",
+        steam.base_url, INTERFACE, ENDPOINT, VERSION
+    );
+    let query = Query::new()
+        .param("key", &steam.api_key)
+        .list("steamids", steam_ids);
+    let wrapper: Wrapper = do_http!(steam, url, Wrapper, query);
 
-        Ok(wrapper.response.players)
-    }
+    Ok(wrapper.response.players)
 }
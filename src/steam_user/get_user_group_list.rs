@@ -1,13 +1,9 @@
 //! # Implements the `GetUserGroupList` endpoint
 
-use serde::Deserialize;
-use serde_json::{from_value, Value};
+use serde::{de, Deserialize, Deserializer};
 
 use crate::{
-    errors::{ErrorHandle, SteamUserError},
-    macros::do_http,
-    steam_id::SteamId,
-    Steam, BASE,
+    errors::SteamError, ids::GroupId, macros::do_http, query::Query, steam_id::SteamId, Steam,
 };
 
 use super::INTERFACE;
@@ -35,8 +31,17 @@ pub struct Response {
 /// Represents a Steam group
 #[derive(Deserialize, Debug, Clone)]
 pub struct Group {
-    /// The group's ID
-    pub gid: String,
+    /// The group's clan SteamID, decoded from the group ID (`gid`) Steam sends.
+    #[serde(rename = "gid", deserialize_with = "deserialize_clan")]
+    pub steam_id: SteamId,
+}
+
+fn deserialize_clan<'de, D>(deserializer: D) -> Result<SteamId, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let group_id = GroupId::deserialize(deserializer)?;
+    SteamId::from_group_id(group_id).map_err(de::Error::custom)
 }
 
 impl Steam {
@@ -45,15 +50,12 @@ impl Steam {
     /// # Arguments
     ///
     /// * `steam_id` - The SteamID of the user.
-    pub async fn get_user_group_list(&self, steam_id: SteamId) -> Result<Response, SteamUserError> {
-        let query = format!("?key={}&steamid={}", &self.api_key, steam_id);
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-        println!("{}", url);
-        let json = do_http!(url, Value, ErrorHandle, SteamUserError::GetUserGroupList);
-        let wrapper: Wrapper = ErrorHandle!(
-            from_value(json.to_owned()),
-            SteamUserError::GetUserGroupList
-        );
+    pub async fn get_user_group_list(&self, steam_id: SteamId) -> Result<Response, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new()
+            .param("key", &self.api_key)
+            .param("steamid", steam_id);
+        let wrapper: Wrapper = do_http!(self, url, Wrapper, query);
 
         Ok(wrapper.response)
     }
@@ -20,4 +20,5 @@
 pub mod get_player_bans;
 pub mod get_player_summaries;
 pub mod get_user_group_list;
+mod resolve_steam_id;
 pub mod resolve_vanity_url;
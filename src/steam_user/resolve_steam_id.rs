@@ -0,0 +1,31 @@
+//! Resolves what users write to refer to an account, falling back to the `ResolveVanityURL` endpoint
+
+use crate::{
+    steam_id::{ResolveSteamIdError, SteamId, SteamIdInput},
+    Steam,
+};
+
+impl Steam {
+    /// Resolves a Steam ID written in any form a user might paste: a SteamID64, SteamID2 or SteamID3, a community
+    /// profile, vanity or quick invite URL, a `steam://` link or a bare vanity name.
+    ///
+    /// Everything but vanity names is decoded without a request, see [`SteamIdInput::parse`]. Vanity names are
+    /// resolved with [`Steam::resolve_vanity_url`].
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - What the user wrote, e.g. `https://steamcommunity.com/id/gabelogannewell/`
+    pub async fn resolve_steam_id(&self, input: &str) -> Result<SteamId, ResolveSteamIdError> {
+        let (name, form) = match SteamIdInput::parse(input)? {
+            SteamIdInput::Id { steam_id, .. } => return Ok(steam_id),
+            SteamIdInput::Vanity { name, form } => (name, form),
+        };
+
+        match self.resolve_vanity_url(&name).send().await {
+            Ok(response) => response
+                .steamid
+                .ok_or(ResolveSteamIdError::NoMatch { form, name }),
+            Err(error) => Err(ResolveSteamIdError::Request { form, name, error }),
+        }
+    }
+}
@@ -1,12 +1,12 @@
 //! Implements the `ResolveVanityURL` endpoint
 
 use serde::{Deserialize, Serialize};
-use serde_json::{from_value, Value};
 
 use crate::{
-    errors::{ErrorHandle, SteamUserError},
-    macros::{do_http, optional_argument},
-    Steam, BASE,
+    errors::SteamError,
+    macros::{do_http, EndPoint},
+    query::Query,
+    steam_id::SteamId,
 };
 
 use super::INTERFACE;
@@ -25,47 +25,41 @@ pub struct Response {
     pub message: Option<String>,
 
     /// The 64 bit Steam ID the vanity URL resolves to. Not returned on resolution failures.
-    pub steamid: Option<String>,
+    pub steamid: Option<SteamId>,
 
     /// The status of the request. 1 if successful, 42 if there was no match.
     pub success: u8,
 }
 
-impl Steam {
+EndPoint!(
     /// Resolve vanity URL parts to a 64-bit ID.
-    ///
-    /// # Arguments
-    ///
-    /// * `vanity_url` - The user's vanity URL that you would like to retrieve a steam ID for, e.g. <http://steamcommunity.com/id/gabelogannewell> would use "gabelogannewell"
-    /// * `url_type` - The type of vanity URL.
-    ///     * 1 (default): Individual profile
-    ///     * 2: Group,
-    ///     * 3: Official game group
-    pub async fn resolve_vanity_url(
-        &self,
-        vanity_url: &str,
-        url_type: Option<i32>,
-    ) -> Result<Response, SteamUserError> {
-        let query = vec![
-            format!("?key={}", &self.api_key),
-            format!("&vanityurl={}", vanity_url),
-            optional_argument!(url_type),
-        ];
+    resolve_vanity_url -> ResolveVanityUrl<'a> => Response {
+        required {
+            /// The user's vanity URL that you would like to retrieve a steam ID for, e.g. <http://steamcommunity.com/id/gabelogannewell> would use "gabelogannewell"
+            vanity_url: &'a str,
+        }
+        optional {
+            /// The type of vanity URL.
+            ///    * 1 (default): Individual profile
+            ///    * 2: Group,
+            ///    * 3: Official game group
+            url_type: i32,
+        }
+    }
+);
 
+impl ResolveVanityUrl<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<Response, SteamError> {
         let url = format!(
-            "{}/{}/{}/v{}/{}",
-            BASE,
-            INTERFACE,
-            ENDPOINT,
-            VERSION,
-            query.concat()
-        );
-
-        let json = do_http!(url, Value, ErrorHandle, SteamUserError::ResolveVanityURL);
-        let wrapper: Wrapper = ErrorHandle!(
-            from_value(json.to_owned()),
-            SteamUserError::ResolveVanityURL
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("vanityurl", self.vanity_url)
+            .optional("url_type", self.url_type);
+        let wrapper: Wrapper = do_http!(self.steam, url, Wrapper, query);
 
         Ok(wrapper.response)
     }
@@ -5,9 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::{ErrorHandle, SteamUserAuthError},
-    macros::do_http,
-    Steam, BASE,
+    errors::SteamError, ids::AppId, macros::do_http, query::Query, steam_id::SteamId, Steam,
 };
 
 use super::INTERFACE;
@@ -19,9 +17,9 @@
 pub struct TicketAuthResponse {
     pub result: String,
     #[serde(rename = "steamid")]
-    pub steam_id: String,
+    pub steam_id: SteamId,
     #[serde(rename = "ownersteamid")]
-    pub owner_steam_id: String,
+    pub owner_steam_id: SteamId,
     #[serde(rename = "vacbanned")]
     pub vac_banned: bool,
     #[serde(rename = "publisherbanned")]
@@ -41,19 +39,15 @@ struct Wrapper {
 impl Steam {
     pub async fn authenticate_user_ticket(
         &self,
-        app_id: u32,
+        app_id: AppId,
         ticket: &str,
-    ) -> Result<TicketAuthResponse, SteamUserAuthError> {
-        let key = &self.api_key.clone();
-        let url = format!(
-            "{BASE}/{INTERFACE}/{ENDPOINT}/v{VERSION}/?key={key}&appid={app_id}&ticket={ticket}"
-        );
-        let wrapper = do_http!(
-            url,
-            Wrapper,
-            ErrorHandle,
-            SteamUserAuthError::AuthenticateUserTicket
-        );
+    ) -> Result<TicketAuthResponse, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new()
+            .param("key", &self.api_key)
+            .param("appid", app_id)
+            .param("ticket", ticket);
+        let wrapper = do_http!(self, url, Wrapper, query);
         Ok(wrapper.response.params)
     }
 }
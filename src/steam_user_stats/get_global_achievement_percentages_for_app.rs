@@ -3,11 +3,7 @@
 use serde::{Deserialize, Serialize};
 use serde_this_or_that::as_f64;
 
-use crate::{
-    errors::{ErrorHandle, SteamUserStatsError},
-    macros::do_http,
-    Steam, BASE,
-};
+use crate::{errors::SteamError, ids::AppId, macros::do_http, query::Query, Steam};
 
 use super::INTERFACE;
 
@@ -36,19 +32,14 @@ impl Steam {
     ///
     /// # Arguments
     ///
-    /// * `game_id` - GameID to retrieve the achievement percentages for.
+    /// * `game_id` - App ID of the game to retrieve the achievement percentages for.
     pub async fn get_global_achievement_percentages_for_app(
         &self,
-        game_id: u32,
-    ) -> Result<AchievementPercentages, SteamUserStatsError> {
-        let query = format!("?gameid={}", game_id);
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-        let response = do_http!(
-            url,
-            Response,
-            ErrorHandle,
-            SteamUserStatsError::GetGlobalAchievements
-        );
+        game_id: AppId,
+    ) -> Result<AchievementPercentages, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new().param("gameid", game_id);
+        let response = do_http!(self, url, Response, query);
         Ok(response.achievementpercentages)
     }
 }
@@ -7,7 +7,10 @@
 use serde_json::Value;
 
 use crate::{
-    errors::{ErrorHandle, SteamUserStatsError}, gen_args, macros::{do_http, optional_argument, EndPoint}, Steam, BASE
+    errors::SteamError,
+    ids::AppId,
+    query::Query,
+    Steam,
 };
 
 use super::INTERFACE;
@@ -44,50 +47,24 @@ impl Steam {
     /// * `names` - The names of the stats to return.
     /// 
     /// The `count` option being provided to the consumer needs to be reviewed as it could be automatically generated (`names.len()+1`)
-    pub async fn get_global_stats_for_game(&self, appid: u32, count: u32, names: Vec<String>) -> Result<GlobalStats, SteamUserStatsError> {
-        let url = format!("{}/{}/{}/v{}/", BASE, INTERFACE, ENDPOINT, VERSION);
-
-        let mut args = gen_args!( appid, count);
-        for (i, name) in names.iter().enumerate() {
-            args.push_str(&format!("&name[{i}]={}", name));
-        }
-
-        let url = format!("{url}?{args}");
-        // Most a carbon copy of the macro
-        if let Ok(response) = reqwest::get(url).await {
-
-            let status = response.status();
-
-            match status {
-                reqwest::StatusCode::OK => {
-                    let txt = ErrorHandle!(response.text().await, SteamUserStatsError::GetGlobalStatsForGame);
-                    let data = ErrorHandle!(serde_json::from_str::<Value>(&txt), SteamUserStatsError::GetGlobalStatsForGame);
-                    if let Ok(data) = serde_json::from_value::<GlobalStatsResponse>(data.clone()) {
-                        return Ok(data.response);
-                    } else {
-                        return Err(SteamUserStatsError::GetGlobalStatsForGame(format!("{}", data["response"]["error"].as_str().unwrap())));
-                    }
-
-                },
-                _ => {
-                    let data = response.text().await;
-                    let remark;
-                    if data.is_ok() {
-                        remark = format!(", alongside data: '{}'", data.unwrap());
-                    } else {
-                        remark = String::new();
-                    }
-
-                    return Err(SteamUserStatsError::GetGlobalStatsForGame(format!(
-                        "Expected 200 Status, got {}{}",
-                        status, remark
-                    )));
-                }
-            }
-            
-        } else {
-            return Err(SteamUserStatsError::GetGlobalStatsForGame("HTTPS Error".to_string()));
+    pub async fn get_global_stats_for_game(&self, appid: AppId, count: u32, names: Vec<String>) -> Result<GlobalStats, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new()
+            .param("appid", appid)
+            .param("count", count)
+            .array("name", names);
+
+        let body = self.get(&url, query).await?;
+
+        // Steam reports failures here as `{"response":{"result":8,"error":"..."}}`, without a `success` field.
+        let data: Value = self.decode(&url, &body)?;
+        if let Some(message) = data["response"]["error"].as_str() {
+            return Err(SteamError::Steam {
+                message: message.to_string(),
+            });
         }
 
+        let response: GlobalStatsResponse = self.decode(&url, &body)?;
+        Ok(response.response)
     }
 }
@@ -2,11 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    errors::{ErrorHandle, SteamUserStatsError},
-    macros::{do_http, gen_args},
-    Steam, BASE,
-};
+use crate::{errors::SteamError, ids::AppId, macros::do_http, query::Query, Steam};
 
 use super::INTERFACE;
 
@@ -30,26 +26,18 @@ impl Steam {
     /// # Arguments
     ///
     /// * `appid` - The ID of the application (game) for which to retrieve the number of current players.
-    pub async fn get_number_of_current_players(
-        &self,
-        appid: u32,
-    ) -> Result<u64, SteamUserStatsError> {
-        let key = &self.api_key.clone();
-        let args = gen_args!(key, appid);
-        let url = format!("{BASE}/{INTERFACE}/{ENDPOINT}/v{VERSION}/?{args}");
-
-        let wrapper = do_http!(
-            url,
-            Wrapper,
-            ErrorHandle,
-            SteamUserStatsError::GetNumberOfCurrentPlayers
-        );
+    pub async fn get_number_of_current_players(&self, appid: AppId) -> Result<u64, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new()
+            .param("key", &self.api_key)
+            .param("appid", appid);
+        let wrapper = do_http!(self, url, Wrapper, query);
 
         match wrapper.response.result {
             1 => Ok(wrapper.response.player_count.unwrap()),
-            _ => Err(SteamUserStatsError::GetNumberOfCurrentPlayers(
-                "App not found.".to_string(),
-            )),
+            _ => Err(SteamError::NotFound {
+                body: "App not found.".to_string(),
+            }),
         }
     }
 }
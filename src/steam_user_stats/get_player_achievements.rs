@@ -3,10 +3,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::{ErrorHandle, SteamUserStatsError},
-    macros::{do_http, gen_args, optional_argument},
+    errors::SteamError,
+    ids::AppId,
+    macros::{do_http, EndPoint},
+    query::Query,
     steam_id::SteamId,
-    Steam, BASE,
 };
 
 use super::INTERFACE;
@@ -24,8 +25,7 @@ pub struct PlayerStats {
     pub error: Option<String>,
     pub success: bool,
     #[serde(rename = "steamID")]
-    // TODO: Make this SteamId
-    pub steam_id: Option<String>,
+    pub steam_id: Option<SteamId>,
     #[serde(rename = "gameName")]
     pub game_name: Option<String>,
     pub achievements: Option<Vec<Achievement>>,
@@ -39,29 +39,35 @@ pub struct Achievement {
     pub unlocktime: Option<u64>,
 }
 
-impl Steam {
+EndPoint!(
     /// Gets the list of achievements the specified user has unlocked in an app.
-    ///
-    /// # Arguments
-    /// * `steamid` - The user's SteamID.
-    /// * `appid` - The ID of the application (game) to get achievements for.
-    /// * `language` - Localized language to return (english, french, etc.).
-    pub async fn get_player_achievements(
-        &self,
-        steamid: SteamId,
-        appid: u32,
-        language: Option<&str>,
-    ) -> Result<PlayerStats, SteamUserStatsError> {
-        let key = &self.api_key.clone();
-        let steamid = steamid.into_u64();
-        let args = gen_args!(key, appid, steamid) + &optional_argument!(language, "l");
-        let url = format!("{BASE}/{INTERFACE}/{ENDPOINT}/v{VERSION}/?{args}");
-        let wrapper = do_http!(
-            url,
-            Wrapper,
-            ErrorHandle,
-            SteamUserStatsError::GetPlayerAchievements
+    get_player_achievements -> GetPlayerAchievements<'a> => PlayerStats {
+        required {
+            /// The user's SteamID.
+            steamid: SteamId,
+            /// The ID of the application (game) to get achievements for.
+            appid: AppId,
+        }
+        optional {
+            /// Localized language to return (english, french, etc.).
+            language: &'a str,
+        }
+    }
+);
+
+impl GetPlayerAchievements<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<PlayerStats, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("appid", self.appid)
+            .param("steamid", self.steamid)
+            .optional("l", self.language);
+        let wrapper = do_http!(self.steam, url, Wrapper, query);
         Ok(wrapper.playerstats)
     }
 }
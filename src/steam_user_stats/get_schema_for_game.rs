@@ -3,9 +3,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::{ErrorHandle, SteamUserStatsError},
-    macros::{do_http, gen_args, optional_argument},
-    Steam, BASE,
+    errors::SteamError,
+    ids::AppId,
+    macros::{do_http, EndPoint},
+    query::Query,
 };
 
 use super::INTERFACE;
@@ -57,32 +58,32 @@ pub struct Achievement {
     pub icon_gray: String,
 }
 
-impl Steam {
+EndPoint!(
     /// Gets the complete list of stats and achievements for the specified game.
-    ///
-    /// # Arguments
-    ///
-    /// * `appid` - The ID of the application (game) for which to retrieve the number of current players.
-    /// * `language` - Localized language to return (english, french, etc.).
-    pub async fn get_schema_for_game(
-        &self,
-        appid: u32,
-        language: Option<&str>,
-    ) -> Result<Game, SteamUserStatsError> {
-        let key = &self.api_key;
-        let query = format!(
-            "?key={}{}{}",
-            key,
-            gen_args!(appid),
-            optional_argument!(language, "l")
-        );
-        let url = format!("{}/{}/{}/v{}/{}", BASE, INTERFACE, ENDPOINT, VERSION, query);
-        let response = do_http!(
-            url,
-            Response,
-            ErrorHandle,
-            SteamUserStatsError::GetSchemaForGame
+    get_schema_for_game -> GetSchemaForGame<'a> => Game {
+        required {
+            /// The ID of the application (game) for which to retrieve the number of current players.
+            appid: AppId,
+        }
+        optional {
+            /// Localized language to return (english, french, etc.).
+            language: &'a str,
+        }
+    }
+);
+
+impl GetSchemaForGame<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<Game, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
         );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .param("appid", self.appid)
+            .optional("l", self.language);
+        let response = do_http!(self.steam, url, Response, query);
         Ok(response.game)
     }
 }
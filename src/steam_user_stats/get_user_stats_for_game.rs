@@ -3,21 +3,20 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::{ErrorHandle, SteamUserStatsError},
-    macros::{do_http, gen_args},
-    steam_id::SteamId,
-    Steam,
+    errors::SteamError, ids::AppId, macros::do_http, query::Query, steam_id::SteamId, Steam,
 };
 
-const END_POINT: &str = "https://api.steampowered.com/ISteamUserStats/GetUserStatsForGame/v1";
+use super::INTERFACE;
+
+const ENDPOINT: &str = "GetUserStatsForGame";
+const VERSION: &str = "1";
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum UserGameStats {
     #[serde(rename = "playerstats")]
     PlayerStats {
         #[serde(rename = "steamID")]
-        /// TODO: Make this SteamId
-        steam_id: String,
+        steam_id: SteamId,
         #[serde(rename = "gameName")]
         game_name: String,
         achievements: HashMap<String, Achievement>,
@@ -44,17 +43,13 @@ impl Steam {
     pub async fn get_user_stats_for_game(
         &self,
         steamid: SteamId,
-        appid: u32,
-    ) -> Result<UserGameStats, SteamUserStatsError> {
-        let key = &self.api_key.clone();
-        let steamid = steamid.into_u64();
-        let args = gen_args!(key, appid, steamid);
-        let url = format!("{END_POINT}?{args}");
-        Ok(do_http!(
-            url,
-            UserGameStats,
-            ErrorHandle,
-            SteamUserStatsError::GetUserStatsForGame
-        ))
+        appid: AppId,
+    ) -> Result<UserGameStats, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new()
+            .param("key", &self.api_key)
+            .param("appid", appid)
+            .param("steamid", steamid);
+        Ok(do_http!(self, url, UserGameStats, query))
     }
 }
@@ -1,13 +1,8 @@
 //! # Implements the `GetServerInfo` endpoint
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
-use crate::{
-    errors::{ErrorHandle, SteamWebAPIUtilError},
-    macros::do_http,
-    Steam, BASE,
-};
+use crate::{errors::SteamError, macros::do_http, query::Query, Steam};
 
 use super::INTERFACE;
 
@@ -27,13 +22,10 @@ pub struct ServerInfo {
 
 impl Steam {
     /// Returns WebAPI server time & checks server status.
-    pub async fn get_server_info() -> Result<ServerInfo, SteamWebAPIUtilError> {
-        let url = format!("{}/{}/{}/v{}/", BASE, INTERFACE, ENDPOINT, VERSION);
-        let json = do_http!(url, Value, ErrorHandle, SteamWebAPIUtilError::GetServerInfo);
-        let server_info: ServerInfo = ErrorHandle!(
-            serde_json::from_value(json.to_owned()),
-            SteamWebAPIUtilError::GetServerInfo
-        );
+    pub async fn get_server_info(&self) -> Result<ServerInfo, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new();
+        let server_info: ServerInfo = do_http!(self, url, ServerInfo, query);
         Ok(server_info)
     }
 }
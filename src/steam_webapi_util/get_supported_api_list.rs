@@ -1,12 +1,12 @@
 //! # Implements the `GetSupportedAPIList` endpoint
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
 use crate::{
-    errors::{ErrorHandle, SteamWebAPIUtilError},
+    errors::{decode, SteamError},
     macros::do_http,
-    Steam, BASE,
+    query::Query,
+    Steam,
 };
 
 use super::INTERFACE;
@@ -64,6 +64,23 @@ pub struct APIList {
     pub interfaces: Vec<Interface>,
 }
 
+impl APIList {
+    /// Parses a list saved as JSON, either serialized from an [`APIList`] or the raw response of `GetSupportedAPIList`.
+    pub fn from_json(json: &str) -> Result<APIList, SteamError> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Saved {
+            Response(Wrapper),
+            List(APIList),
+        }
+
+        Ok(match decode(json)? {
+            Saved::Response(wrapper) => wrapper.apilist,
+            Saved::List(api_list) => api_list,
+        })
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 struct Wrapper {
     apilist: APIList,
@@ -71,16 +88,10 @@ struct Wrapper {
 
 impl Steam {
     /// Lists all supported API calls.
-    pub async fn get_supported_api_list(&self) -> Result<APIList, SteamWebAPIUtilError> {
-        let url = format!(
-            "{}/{}/{}/v{}/?key={}",
-            BASE, INTERFACE, ENDPOINT, VERSION, &self.api_key
-        );
-        let json = do_http!(url, Value, ErrorHandle, SteamWebAPIUtilError::GetServerInfo);
-        let wrapper: Wrapper = ErrorHandle!(
-            serde_json::from_value(json.to_owned()),
-            SteamWebAPIUtilError::GetServerInfo
-        );
+    pub async fn get_supported_api_list(&self) -> Result<APIList, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new().param("key", &self.api_key);
+        let wrapper: Wrapper = do_http!(self, url, Wrapper, query);
         Ok(wrapper.apilist)
     }
 }
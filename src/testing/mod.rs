@@ -0,0 +1,355 @@
+//! A local stand-in for the Steam Web API, to run integration tests without an API key or network access.
+//!
+//! A [`Simulator`] is an HTTP server on `127.0.0.1` answering from an in-memory [`World`] of users, friends, owned
+//! games, bans, workshop files and trade offers. Its responses are shaped like Steam's, including how Steam treats
+//! private profiles, missing keys and unknown methods, so a [`Steam`] client pointed at it behaves as it would
+//! against the real API. [`Fault`]s can be injected to exercise rate limiting and server errors.
+//!
+//! This module is only available with the `testing` feature.
+//!
+//! The simulator implements these methods; every other one answers `404 Not Found`:
+//!
+//! - `ISteamUser`: `GetPlayerSummaries`, `GetFriendList`, `GetPlayerBans`, `ResolveVanityURL`, `GetUserGroupList`
+//! - `IPlayerService`: `GetOwnedGames`, `GetRecentlyPlayedGames`, `GetSteamLevel`, `GetBadges`,
+//!   `GetCommunityBadgeProgress`
+//! - `IPublishedFileService`: `QueryFiles`
+//! - `ISteamRemoteStorage`: `GetPublishedFileDetails`, `GetCollectionDetails`
+//! - `IEconService`: `GetTradeOffers`, `GetTradeOffer`, `GetTradeOffersSummary`, `GetTradeHistory`
+//! - `ISteamApps`: `GetAppList`, listing the apps of owned games and workshop files
+//! - `ISteamWebAPIUtil`: `GetServerInfo`, `GetSupportedAPIList`, listing the methods above
+//!
+//! A [`World`] has no achievements, stats, news, item classes or prices, so `ISteamUserStats`, `ISteamNews`,
+//! `ISteamEconomy` and the rest of `ISteamApps` are not simulated. Badges are limited to the XP of the user's level,
+//! with no badges or quests.
+//!
+//! # Examples
+//!
+//! ```
+//! use steam_rs::{
+//!     errors::SteamError,
+//!     retry::RetryPolicy,
+//!     testing::{seed, Fault, Simulator, World},
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let simulator = Simulator::start(World::seeded()).await;
+//! let steam = simulator.client();
+//!
+//! let level = steam.get_steam_level(seed::GABE).await.unwrap();
+//! assert_eq!(level, 50);
+//!
+//! let friends = steam.get_friend_list(seed::PRIVATE).send().await;
+//! assert!(matches!(friends, Err(SteamError::PrivateProfile)));
+//!
+//! // Without retries, an injected fault reaches the caller.
+//! let steam = simulator.builder().retry_policy(RetryPolicy::none()).build();
+//! simulator.inject(Fault::RateLimited { retry_after: Some(30) });
+//! let level = steam.get_steam_level(seed::GABE).await;
+//! assert!(matches!(level, Err(SteamError::RateLimited { .. })));
+//! # }
+//! ```
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use reqwest::StatusCode;
+use serde_json::Value;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+use crate::{query::Query, Steam, SteamBuilder};
+
+mod routes;
+pub mod seed;
+mod world;
+
+pub use world::{Bans, Game, Item, TradeOffer, User, WorkshopFile, World};
+
+use routes::Reply;
+
+/// An error to answer a request with instead of its regular response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// `429 Too Many Requests`, with a `Retry-After` header in seconds if one is given.
+    RateLimited {
+        /// The value of the `Retry-After` header.
+        retry_after: Option<u64>,
+    },
+
+    /// `500 Internal Server Error`.
+    ServerError,
+
+    /// Any other status, with an empty body.
+    Status(u16),
+}
+
+/// A request received by a [`Simulator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// The HTTP method, e.g. `"GET"`.
+    pub method: String,
+
+    /// The path, e.g. `"/ISteamUser/GetPlayerSummaries/v0002/"`.
+    pub path: String,
+
+    /// The decoded parameters from the query string and the form body, in the order they were sent.
+    pub params: Vec<(String, String)>,
+}
+
+impl Request {
+    /// The value of a parameter, if it was sent.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    world: World,
+    faults: VecDeque<(Option<String>, Fault)>,
+    requests: Vec<Request>,
+}
+
+/// A local Steam Web API server answering from a [`World`]. It shuts down when dropped.
+#[derive(Debug)]
+pub struct Simulator {
+    url: String,
+    state: Arc<Mutex<State>>,
+    server: JoinHandle<()>,
+}
+
+impl Simulator {
+    /// The API key the simulator accepts. Requests with another key, or none, are answered `403 Forbidden`.
+    pub const API_KEY: &'static str = "0123456789ABCDEF0123456789ABCDEF";
+
+    /// Starts a simulator on a free local port.
+    ///
+    /// # Panics
+    ///
+    /// If no local port can be bound, or when called outside of a Tokio runtime.
+    pub async fn start(world: World) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind the simulator to a local port");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State {
+            world,
+            faults: VecDeque::new(),
+            requests: Vec::new(),
+        }));
+
+        let shared = state.clone();
+        let server = tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                tokio::spawn(serve(socket, shared.clone()));
+            }
+        });
+
+        Simulator { url, state, server }
+    }
+
+    /// The base URL of the simulator, e.g. `http://127.0.0.1:49152`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// A [`SteamBuilder`] pointed at the simulator with its [`API_KEY`](Self::API_KEY), to configure further.
+    pub fn builder(&self) -> SteamBuilder {
+        Steam::builder()
+            .api_key(Self::API_KEY)
+            .base_url(&self.url)
+            .partner_url(&self.url)
+    }
+
+    /// A [`Steam`] client pointed at the simulator with its [`API_KEY`](Self::API_KEY).
+    pub fn client(&self) -> Steam {
+        self.builder().build()
+    }
+
+    /// Answers the next request with `fault`. Faults queue up, each answering one request.
+    pub fn inject(&self, fault: Fault) {
+        self.lock().faults.push_back((None, fault));
+    }
+
+    /// Answers the next request to `method`, e.g. `"GetFriendList"`, with `fault`.
+    pub fn inject_on(&self, method: &str, fault: Fault) {
+        self.lock()
+            .faults
+            .push_back((Some(method.to_string()), fault));
+    }
+
+    /// Changes the world the simulator answers from.
+    pub fn update(&self, change: impl FnOnce(&mut World)) {
+        change(&mut self.lock().world);
+    }
+
+    /// All requests received so far, including the ones answered with a fault.
+    pub fn requests(&self) -> Vec<Request> {
+        self.lock().requests.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for Simulator {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+/// Answers a single request on `socket`, then closes it.
+async fn serve(mut socket: TcpStream, state: Arc<Mutex<State>>) {
+    let Some(request) = read_request(&mut socket).await else {
+        return;
+    };
+
+    let reply = {
+        let mut state = state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.requests.push(request.clone());
+
+        let method = request.path.trim_matches('/').split('/').nth(1);
+        let fault = state
+            .faults
+            .iter()
+            .position(|(only, _)| only.is_none() || only.as_deref() == method);
+        match fault.and_then(|index| state.faults.remove(index)) {
+            Some((_, fault)) => fault_reply(fault),
+            None => {
+                let request = Request {
+                    params: merge_input_json(request.params),
+                    ..request
+                };
+                routes::handle(&state.world, Simulator::API_KEY, &request)
+            }
+        }
+    };
+
+    let reason = StatusCode::from_u16(reply.status)
+        .ok()
+        .and_then(|status| status.canonical_reason())
+        .unwrap_or("Unknown");
+    let mut head = format!(
+        "HTTP/1.1 {} {reason}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        reply.status,
+        reply.body.len()
+    );
+    for (name, value) in &reply.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+
+    let _ = socket.write_all(head.as_bytes()).await;
+    let _ = socket.write_all(reply.body.as_bytes()).await;
+    let _ = socket.shutdown().await;
+}
+
+fn fault_reply(fault: Fault) -> Reply {
+    match fault {
+        Fault::RateLimited { retry_after } => {
+            let mut reply = Reply::html(429, "");
+            if let Some(retry_after) = retry_after {
+                reply.headers.push(("Retry-After", retry_after.to_string()));
+            }
+            reply
+        }
+        Fault::ServerError => Reply::html(500, ""),
+        Fault::Status(status) => Reply::html(status, ""),
+    }
+}
+
+async fn read_request(socket: &mut TcpStream) -> Option<Request> {
+    let mut data = Vec::new();
+    let mut buffer = [0; 4096];
+
+    let head_end = loop {
+        let read = socket.read(&mut buffer).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        data.extend_from_slice(&buffer[..read]);
+        if let Some(index) = data.windows(4).position(|window| window == b"\r\n\r\n") {
+            break index + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&data[..head_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_string();
+    let target = request_line.next()?;
+
+    let content_length = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+
+    while data.len() < head_end + content_length {
+        let read = socket.read(&mut buffer).await.ok()?;
+        if read == 0 {
+            break;
+        }
+        data.extend_from_slice(&buffer[..read]);
+    }
+
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let mut params: Vec<(String, String)> = Query::parse(query).into();
+    params.extend(Vec::from(Query::parse(&String::from_utf8_lossy(
+        &data[head_end..],
+    ))));
+
+    Some(Request {
+        method,
+        path: path.to_string(),
+        params,
+    })
+}
+
+/// Replaces parameters with the ones in `input_json`, which `I*Service` interfaces read their arguments from.
+fn merge_input_json(mut params: Vec<(String, String)>) -> Vec<(String, String)> {
+    let Some(Value::Object(input)) = params
+        .iter()
+        .find(|(name, _)| name == "input_json")
+        .and_then(|(_, json)| serde_json::from_str(json).ok())
+    else {
+        return params;
+    };
+
+    for (name, value) in input {
+        params.retain(|(param, _)| param != &name && !param.starts_with(&format!("{name}[")));
+        match value {
+            Value::Array(values) => params.extend(
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| (format!("{name}[{index}]"), scalar(value))),
+            ),
+            value => params.push((name, scalar(&value))),
+        }
+    }
+
+    params
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Bool(value) => u8::from(*value).to_string(),
+        value => value.to_string(),
+    }
+}
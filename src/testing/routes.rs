@@ -0,0 +1,899 @@
+//! Answers requests from the [`World`], shaped like Steam's own responses.
+
+use std::{
+    cmp::Reverse,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    ids::{AppId, PublishedFileId, TradeOfferId},
+    steam_id::SteamId,
+    steam_user::get_player_summaries::{
+        CommentPermission, CommunityVisibility, PersonaState, ProfileState,
+    },
+};
+
+use super::{
+    world::{Item, TradeOffer, User, WorkshopFile, World},
+    Request,
+};
+
+const FORBIDDEN: &str = "<html><head><title>Forbidden</title></head><body><h1>Forbidden</h1>Access is denied. Retrying will not help. Please verify your <pre>key=</pre> parameter.</body></html>";
+const UNAUTHORIZED: &str = "<html><head><title>Unauthorized</title></head><body><h1>Unauthorized</h1>Access is denied. Retrying will not help. Please verify your <pre>key=</pre> parameter.</body></html>";
+const NOT_FOUND: &str =
+    "<html><head><title>Not Found</title></head><body><h1>Not Found</h1></body></html>";
+const METHOD_NOT_ALLOWED: &str = "<html><head><title>Method Not Allowed</title></head><body><h1>Method Not Allowed</h1>This API must be called with a HTTP POST request</body></html>";
+
+/// The methods the simulator implements, as `(interface, method, version, HTTP method)`.
+const METHODS: &[(&str, &str, u8, &str)] = &[
+    ("ISteamUser", "GetPlayerSummaries", 2, "GET"),
+    ("ISteamUser", "GetFriendList", 1, "GET"),
+    ("ISteamUser", "GetPlayerBans", 1, "GET"),
+    ("ISteamUser", "ResolveVanityURL", 1, "GET"),
+    ("ISteamUser", "GetUserGroupList", 1, "GET"),
+    ("IPlayerService", "GetOwnedGames", 1, "GET"),
+    ("IPlayerService", "GetRecentlyPlayedGames", 1, "GET"),
+    ("IPlayerService", "GetSteamLevel", 1, "GET"),
+    ("IPlayerService", "GetBadges", 1, "GET"),
+    ("IPlayerService", "GetCommunityBadgeProgress", 1, "GET"),
+    ("IPublishedFileService", "QueryFiles", 1, "GET"),
+    ("ISteamRemoteStorage", "GetPublishedFileDetails", 1, "POST"),
+    ("ISteamRemoteStorage", "GetCollectionDetails", 1, "POST"),
+    ("IEconService", "GetTradeOffers", 1, "GET"),
+    ("IEconService", "GetTradeOffer", 1, "GET"),
+    ("IEconService", "GetTradeOffersSummary", 1, "GET"),
+    ("IEconService", "GetTradeHistory", 1, "GET"),
+    ("ISteamApps", "GetAppList", 2, "GET"),
+    ("ISteamWebAPIUtil", "GetServerInfo", 1, "GET"),
+    ("ISteamWebAPIUtil", "GetSupportedAPIList", 1, "GET"),
+];
+
+/// Steam answers for the first 100 SteamIDs of bulk calls and silently drops the rest.
+const MAX_STEAM_IDS: usize = 100;
+
+/// A response to send back.
+pub(super) struct Reply {
+    pub status: u16,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: String,
+}
+
+impl Reply {
+    fn json(body: Value) -> Self {
+        Reply {
+            status: 200,
+            headers: vec![(
+                "Content-Type",
+                "application/json; charset=UTF-8".to_string(),
+            )],
+            body: body.to_string(),
+        }
+    }
+
+    pub fn html(status: u16, body: &str) -> Self {
+        Reply {
+            status,
+            headers: vec![("Content-Type", "text/html".to_string())],
+            body: body.to_string(),
+        }
+    }
+}
+
+/// Answers `request` from `world`, checking the key against `api_key`.
+pub(super) fn handle(world: &World, api_key: &str, request: &Request) -> Reply {
+    let mut segments = request.path.trim_matches('/').split('/');
+    let (Some(interface), Some(method), Some(version), None) = (
+        segments.next(),
+        segments.next(),
+        segments.next(),
+        segments.next(),
+    ) else {
+        return Reply::html(404, NOT_FOUND);
+    };
+    let Some(version) = version
+        .strip_prefix('v')
+        .and_then(|version| version.parse::<u32>().ok())
+    else {
+        return Reply::html(404, NOT_FOUND);
+    };
+
+    let params = Params(&request.params);
+    let post = request.method == "POST";
+
+    // Like Steam, the simulator serves `ISteamRemoteStorage`, the app list and the server time without a key.
+    let keyless = matches!(
+        (interface, method),
+        ("ISteamRemoteStorage", _)
+            | ("ISteamApps", "GetAppList")
+            | ("ISteamWebAPIUtil", "GetServerInfo")
+    );
+    if !keyless && params.get("key") != Some(api_key) {
+        return Reply::html(403, FORBIDDEN);
+    }
+
+    match (interface, method, version) {
+        ("ISteamUser", "GetPlayerSummaries", 2) => player_summaries(world, &params),
+        ("ISteamUser", "GetFriendList", 1) => friend_list(world, &params),
+        ("ISteamUser", "GetPlayerBans", 1) => player_bans(world, &params),
+        ("ISteamUser", "ResolveVanityURL", 1) => resolve_vanity_url(world, &params),
+        ("ISteamUser", "GetUserGroupList", 1) => user_group_list(world, &params),
+        ("IPlayerService", "GetOwnedGames", 1) => owned_games(world, &params),
+        ("IPlayerService", "GetRecentlyPlayedGames", 1) => recently_played_games(world, &params),
+        ("IPlayerService", "GetSteamLevel", 1) => steam_level(world, &params),
+        ("IPlayerService", "GetBadges", 1) => badges(world, &params),
+        ("IPlayerService", "GetCommunityBadgeProgress", 1) => {
+            community_badge_progress(world, &params)
+        }
+        ("IPublishedFileService", "QueryFiles", 1) => query_files(world, &params),
+        ("ISteamRemoteStorage", "GetPublishedFileDetails", 1) if post => {
+            published_file_details(world, &params)
+        }
+        ("ISteamRemoteStorage", "GetCollectionDetails", 1) if post => {
+            collection_details(world, &params)
+        }
+        ("ISteamRemoteStorage", "GetPublishedFileDetails" | "GetCollectionDetails", 1) => {
+            Reply::html(405, METHOD_NOT_ALLOWED)
+        }
+        ("IEconService", "GetTradeOffers", 1) => trade_offers(world, &params),
+        ("IEconService", "GetTradeOffer", 1) => trade_offer(world, &params),
+        ("IEconService", "GetTradeOffersSummary", 1) => trade_offers_summary(world),
+        ("IEconService", "GetTradeHistory", 1) => trade_history(world, &params),
+        ("ISteamApps", "GetAppList", 2) => app_list(world),
+        ("ISteamWebAPIUtil", "GetServerInfo", 1) => server_info(),
+        ("ISteamWebAPIUtil", "GetSupportedAPIList", 1) => supported_api_list(),
+        _ => Reply::html(404, NOT_FOUND),
+    }
+}
+
+/// Request parameters, with `input_json` already merged in.
+struct Params<'a>(&'a [(String, String)]);
+
+impl Params<'_> {
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn number<T: std::str::FromStr>(&self, name: &str) -> Option<T> {
+        self.get(name)?.parse().ok()
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        matches!(self.get(name), Some("1" | "true"))
+    }
+
+    fn steam_id(&self, name: &str) -> Option<SteamId> {
+        self.number(name).map(SteamId)
+    }
+
+    /// A comma-separated list, e.g. `steamids`.
+    fn list(&self, name: &str) -> Vec<&str> {
+        self.get(name)
+            .map(|value| value.split(',').map(str::trim).collect())
+            .unwrap_or_default()
+    }
+
+    /// An array sent as `name[0]`, `name[1]`, ….
+    fn array(&self, name: &str) -> Vec<&str> {
+        (0..)
+            .map_while(|index| self.get(&format!("{name}[{index}]")))
+            .collect()
+    }
+}
+
+fn player_summaries(world: &World, params: &Params) -> Reply {
+    let players: Vec<Value> = params
+        .list("steamids")
+        .into_iter()
+        .take(MAX_STEAM_IDS)
+        .filter_map(|steam_id| steam_id.parse().ok())
+        .filter_map(|steam_id| world.find_user(SteamId(steam_id)))
+        .map(player_summary)
+        .collect();
+
+    Reply::json(json!({ "response": { "players": players } }))
+}
+
+fn player_summary(user: &User) -> Value {
+    let mut player = json!({
+        "steamid": user.steam_id.to_string(),
+        "communityvisibilitystate": if user.public { CommunityVisibility::Public } else { CommunityVisibility::Private },
+        "profilestate": ProfileState::Configured,
+        "personaname": user.persona_name,
+        "profileurl": user.profile_url(),
+        "avatar": "https://avatars.steamstatic.com/fef49e7fa7e1997310d705b2a6158ff8dc1cdfeb.jpg",
+        "avatarmedium": "https://avatars.steamstatic.com/fef49e7fa7e1997310d705b2a6158ff8dc1cdfeb_medium.jpg",
+        "avatarfull": "https://avatars.steamstatic.com/fef49e7fa7e1997310d705b2a6158ff8dc1cdfeb_full.jpg",
+        "avatarhash": "fef49e7fa7e1997310d705b2a6158ff8dc1cdfeb",
+        "personastate": if user.public { user.persona_state } else { PersonaState::Offline },
+    });
+
+    // Private profiles only reveal the fields above.
+    if user.public {
+        let fields = player.as_object_mut().unwrap();
+        fields.insert(
+            "commentpermission".to_string(),
+            json!(CommentPermission::Public),
+        );
+        fields.insert("lastlogoff".to_string(), json!(1_700_000_000));
+        fields.insert("timecreated".to_string(), json!(user.time_created));
+        let primary_clan = user
+            .groups
+            .first()
+            .and_then(|group_id| SteamId::from_group_id(*group_id).ok())
+            .map_or(103582791429521408, |steam_id| steam_id.0);
+        fields.insert("primaryclanid".to_string(), json!(primary_clan.to_string()));
+        fields.insert("personastateflags".to_string(), json!(0));
+        if let Some(real_name) = &user.real_name {
+            fields.insert("realname".to_string(), json!(real_name));
+        }
+        if let Some(country_code) = &user.country_code {
+            fields.insert("loccountrycode".to_string(), json!(country_code));
+        }
+    }
+
+    player
+}
+
+fn friend_list(world: &World, params: &Params) -> Reply {
+    let Some(user) = params
+        .steam_id("steamid")
+        .and_then(|steam_id| world.find_user(steam_id))
+    else {
+        return Reply::json(json!({}));
+    };
+
+    // Steam refuses to list the friends of private profiles.
+    if !user.public {
+        return Reply::html(401, UNAUTHORIZED);
+    }
+
+    let friends: Vec<Value> = user
+        .friends
+        .iter()
+        .map(|(steam_id, since)| {
+            json!({
+                "steamid": steam_id.to_string(),
+                "relationship": "friend",
+                "friend_since": since,
+            })
+        })
+        .collect();
+
+    Reply::json(json!({ "friendslist": { "friends": friends } }))
+}
+
+fn player_bans(world: &World, params: &Params) -> Reply {
+    let players: Vec<Value> = params
+        .list("steamids")
+        .into_iter()
+        .take(MAX_STEAM_IDS)
+        .filter_map(|steam_id| steam_id.parse().ok())
+        .filter_map(|steam_id| world.find_user(SteamId(steam_id)))
+        .map(|user| {
+            json!({
+                "SteamId": user.steam_id.to_string(),
+                "CommunityBanned": user.bans.community_banned,
+                "VACBanned": user.bans.vac_bans > 0,
+                "NumberOfVACBans": user.bans.vac_bans,
+                "DaysSinceLastBan": user.bans.days_since_last_ban,
+                "NumberOfGameBans": user.bans.game_bans,
+                "EconomyBan": user.bans.economy_ban,
+            })
+        })
+        .collect();
+
+    Reply::json(json!({ "players": players }))
+}
+
+fn resolve_vanity_url(world: &World, params: &Params) -> Reply {
+    let vanity_url = params.get("vanityurl").unwrap_or_default();
+    let user = world
+        .users
+        .iter()
+        .find(|user| user.vanity_url.as_deref() == Some(vanity_url));
+
+    match (params.number::<u8>("url_type").unwrap_or(1), user) {
+        (1, Some(user)) => Reply::json(json!({
+            "response": { "steamid": user.steam_id.to_string(), "success": 1 }
+        })),
+        _ => Reply::json(json!({ "response": { "success": 42, "message": "No match" } })),
+    }
+}
+
+fn user_group_list(world: &World, params: &Params) -> Reply {
+    let user = params
+        .steam_id("steamid")
+        .and_then(|steam_id| world.find_user(steam_id))
+        .filter(|user| user.public);
+
+    match user {
+        Some(user) => {
+            let groups: Vec<Value> = user
+                .groups
+                .iter()
+                .map(|group_id| json!({ "gid": group_id }))
+                .collect();
+            Reply::json(json!({ "response": { "success": true, "groups": groups } }))
+        }
+        None => Reply::json(json!({
+            "response": { "success": false, "error": "Failed to get group list" }
+        })),
+    }
+}
+
+/// The user named by `steamid`, if their profile is public.
+///
+/// Steam answers service methods about private profiles with an empty `response` object.
+fn public_user<'a>(world: &'a World, params: &Params) -> Result<&'a User, Reply> {
+    params
+        .steam_id("steamid")
+        .and_then(|steam_id| world.find_user(steam_id))
+        .filter(|user| user.public)
+        .ok_or_else(|| Reply::json(json!({ "response": {} })))
+}
+
+fn owned_games(world: &World, params: &Params) -> Reply {
+    let user = match public_user(world, params) {
+        Ok(user) => user,
+        Err(reply) => return reply,
+    };
+    let include_appinfo = params.flag("include_appinfo");
+    let filter: Vec<AppId> = params
+        .array("appids_filter")
+        .into_iter()
+        .filter_map(|appid| appid.parse().ok())
+        .collect();
+
+    let games: Vec<Value> = user
+        .games
+        .iter()
+        .filter(|game| filter.is_empty() || filter.contains(&game.appid))
+        .map(|game| {
+            let mut value = json!({
+                "appid": game.appid,
+                "playtime_forever": game.playtime_forever,
+                "rtime_last_played": game.rtime_last_played,
+            });
+            let fields = value.as_object_mut().unwrap();
+            if game.playtime_2weeks > 0 {
+                fields.insert("playtime_2weeks".to_string(), json!(game.playtime_2weeks));
+            }
+            if include_appinfo {
+                fields.insert("name".to_string(), json!(game.name));
+                fields.insert("img_icon_url".to_string(), json!(icon_hash(game.appid)));
+                fields.insert("has_community_visible_stats".to_string(), json!(true));
+            }
+            value
+        })
+        .collect();
+
+    Reply::json(json!({ "response": { "game_count": games.len(), "games": games } }))
+}
+
+fn recently_played_games(world: &World, params: &Params) -> Reply {
+    let user = match public_user(world, params) {
+        Ok(user) => user,
+        Err(reply) => return reply,
+    };
+
+    let mut games: Vec<_> = user
+        .games
+        .iter()
+        .filter(|game| game.playtime_2weeks > 0)
+        .collect();
+    games.sort_by_key(|game| Reverse(game.playtime_2weeks));
+    let total_count = games.len();
+    if let Some(count) = params.number::<usize>("count").filter(|count| *count > 0) {
+        games.truncate(count);
+    }
+
+    let games: Vec<Value> = games
+        .into_iter()
+        .map(|game| {
+            json!({
+                "appid": game.appid,
+                "name": game.name,
+                "playtime_2weeks": game.playtime_2weeks,
+                "playtime_forever": game.playtime_forever,
+                "img_icon_url": icon_hash(game.appid),
+            })
+        })
+        .collect();
+
+    Reply::json(json!({ "response": { "total_count": total_count, "games": games } }))
+}
+
+fn steam_level(world: &World, params: &Params) -> Reply {
+    match public_user(world, params) {
+        Ok(user) => Reply::json(json!({ "response": { "player_level": user.level } })),
+        Err(reply) => reply,
+    }
+}
+
+fn badges(world: &World, params: &Params) -> Reply {
+    let user = match public_user(world, params) {
+        Ok(user) => user,
+        Err(reply) => return reply,
+    };
+    let level = u32::from(user.level);
+    let xp = xp_for_level(level);
+
+    Reply::json(json!({
+        "response": {
+            "badges": [],
+            "player_xp": xp,
+            "player_level": level,
+            "player_xp_needed_to_level_up": xp_for_level(level + 1) - xp,
+            "player_xp_needed_current_level": xp,
+        }
+    }))
+}
+
+/// The XP needed to reach `level`. Each level costs 100 XP more than the ten before it.
+fn xp_for_level(level: u32) -> u32 {
+    (0..level).map(|level| (level / 10 + 1) * 100).sum()
+}
+
+fn community_badge_progress(world: &World, params: &Params) -> Reply {
+    match public_user(world, params) {
+        Ok(_) => Reply::json(json!({ "response": { "quests": [] } })),
+        Err(reply) => reply,
+    }
+}
+
+/// A stand-in for the hash Steam names app icons by.
+fn icon_hash(appid: AppId) -> String {
+    format!("{:040x}", appid.0)
+}
+
+fn query_files(world: &World, params: &Params) -> Reply {
+    let app_id = params
+        .number::<AppId>("appid")
+        .filter(|app_id| app_id.0 != 0);
+    let search_text = params.get("search_text").map(str::to_lowercase);
+    let required_tags = params.array("requiredtags");
+    let excluded_tags = params.array("excludedtags");
+    let match_all_tags = params.flag("match_all_tags");
+
+    let mut files: Vec<&WorkshopFile> = world
+        .files
+        .iter()
+        .filter(|file| app_id.is_none_or(|app_id| file.app_id == app_id))
+        .filter(|file| {
+            search_text.as_ref().is_none_or(|text| {
+                file.title.to_lowercase().contains(text)
+                    || file.description.to_lowercase().contains(text)
+            })
+        })
+        .filter(|file| {
+            let has_tag = |tag: &&str| file.tags.iter().any(|t| t == tag);
+            required_tags.is_empty()
+                || if match_all_tags {
+                    required_tags.iter().all(has_tag)
+                } else {
+                    required_tags.iter().any(has_tag)
+                }
+        })
+        .filter(|file| {
+            !excluded_tags
+                .iter()
+                .any(|tag| file.tags.iter().any(|t| t == tag))
+        })
+        .collect();
+
+    match params.number::<u32>("query_type") {
+        Some(1) => files.sort_by_key(|file| Reverse(file.time_created)),
+        Some(9) => files.sort_by_key(|file| Reverse(file.subscriptions)),
+        _ => {}
+    }
+
+    let total = files.len();
+    if params.flag("totalonly") {
+        return Reply::json(json!({ "response": { "total": total } }));
+    }
+
+    let per_page = params.number::<usize>("numperpage").unwrap_or(1).max(1);
+    let page = params.number::<usize>("page").unwrap_or(1).max(1);
+    let details: Vec<Value> = files
+        .into_iter()
+        .skip((page - 1) * per_page)
+        .take(per_page)
+        .map(|file| {
+            if params.flag("ids_only") {
+                json!({ "result": 1, "publishedfileid": file.id.to_string() })
+            } else {
+                file_details(file)
+            }
+        })
+        .collect();
+
+    Reply::json(json!({ "response": { "total": total, "publishedfiledetails": details } }))
+}
+
+fn file_details(file: &WorkshopFile) -> Value {
+    let tags: Vec<Value> = file
+        .tags
+        .iter()
+        .map(|tag| json!({ "tag": tag, "display_name": tag }))
+        .collect();
+
+    let mut details = json!({
+        "result": 1,
+        "publishedfileid": file.id.to_string(),
+        "creator": file.creator.to_string(),
+        "creator_appid": file.app_id,
+        "consumer_appid": file.app_id,
+        "consumer_shortcutid": 0,
+        "filename": "",
+        "file_size": "1024",
+        "preview_file_size": "2048",
+        "preview_url": format!("https://steamuserimages-a.akamaihd.net/ugc/{}/", file.id),
+        "url": "",
+        "hcontent_file": file.id.to_string(),
+        "hcontent_preview": file.id.to_string(),
+        "title": file.title,
+        "short_description": file.description,
+        "file_description": file.description,
+        "time_created": file.time_created,
+        "time_updated": file.time_created,
+        "visibility": 0,
+        "flags": 0,
+        "workshop_file": false,
+        "workshop_accepted": false,
+        "show_subscribe_all": false,
+        "num_comments_public": 0,
+        "banned": false,
+        "ban_reason": "",
+        "banner": "0",
+    });
+    // Split in two to stay within the recursion limit of `json!`.
+    let statistics = json!({
+        "can_be_deleted": true,
+        "app_name": file.app_name,
+        "file_type": if file.children.is_empty() { 0 } else { 2 },
+        "can_subscribe": true,
+        "subscriptions": file.subscriptions,
+        "favorited": 0,
+        "followers": 0,
+        "lifetime_subscriptions": file.subscriptions,
+        "lifetime_favorited": 0,
+        "lifetime_followers": 0,
+        "lifetime_playtime": "0",
+        "lifetime_playtime_sessions": "0",
+        "views": 0,
+        "num_children": file.children.len(),
+        "num_reports": 0,
+        "previews": [],
+        "tags": tags,
+        "vote_data": { "score": 0.5, "votes_up": 0, "votes_down": 0 },
+        "playtime_stats": { "playtime_seconds": "0", "num_sessions": "0" },
+        "language": 0,
+        "maybe_inappropriate_sex": false,
+        "maybe_inappropriate_violence": false,
+        "revision_change_number": "1",
+        "revision": 1,
+        "available_revisions": [1],
+        "ban_text_check_result": 5,
+    });
+
+    if let (Value::Object(details), Value::Object(statistics)) = (&mut details, statistics) {
+        details.extend(statistics);
+    }
+    details
+}
+
+/// The IDs sent as `publishedfileids[0]`, `publishedfileids[1]`, ….
+fn published_file_ids(params: &Params) -> Vec<(String, Option<PublishedFileId>)> {
+    params
+        .array("publishedfileids")
+        .into_iter()
+        .map(|id| (id.to_string(), id.parse().ok()))
+        .collect()
+}
+
+fn published_file_details(world: &World, params: &Params) -> Reply {
+    let details: Vec<Value> = published_file_ids(params)
+        .into_iter()
+        .map(
+            |(id, parsed)| match parsed.and_then(|id| world.find_file(id)) {
+                Some(file) => {
+                    let mut details = file_details(file);
+                    let fields = details.as_object_mut().unwrap();
+                    fields.insert("description".to_string(), json!(file.description));
+                    fields.insert("consumer_app_id".to_string(), json!(file.app_id));
+                    details
+                }
+                // Result 9 is `k_EResultFileNotFound`.
+                None => json!({ "publishedfileid": id, "result": 9 }),
+            },
+        )
+        .collect();
+
+    Reply::json(json!({
+        "response": { "result": 1, "resultcount": details.len(), "publishedfiledetails": details }
+    }))
+}
+
+fn collection_details(world: &World, params: &Params) -> Reply {
+    let details: Vec<Value> = published_file_ids(params)
+        .into_iter()
+        .map(|(id, parsed)| match parsed.and_then(|id| world.find_file(id)) {
+            Some(file) if !file.children.is_empty() => {
+                let children: Vec<Value> = file
+                    .children
+                    .iter()
+                    .enumerate()
+                    .map(|(index, child)| {
+                        json!({ "publishedfileid": child.to_string(), "sortorder": index, "filetype": 0 })
+                    })
+                    .collect();
+                json!({ "publishedfileid": id, "result": 1, "children": children })
+            }
+            _ => json!({ "publishedfileid": id, "result": 9 }),
+        })
+        .collect();
+
+    Reply::json(json!({
+        "response": { "result": 1, "resultcount": details.len(), "collectiondetails": details }
+    }))
+}
+
+fn trade_offers(world: &World, params: &Params) -> Reply {
+    let active_only = params.flag("active_only");
+    let historical_only = params.flag("historical_only");
+    let offers = |sent: bool| -> Vec<Value> {
+        world
+            .trade_offers
+            .iter()
+            .filter(|offer| offer.sent == sent)
+            // 2 is `k_ETradeOfferStateActive`.
+            .filter(|offer| !active_only || offer.state == 2)
+            .filter(|offer| !historical_only || offer.state != 2)
+            .map(trade_offer_json)
+            .collect()
+    };
+
+    let mut response = Map::new();
+    if params.flag("get_sent_offers") {
+        response.insert("trade_offers_sent".to_string(), json!(offers(true)));
+    }
+    if params.flag("get_received_offers") {
+        response.insert("trade_offers_received".to_string(), json!(offers(false)));
+    }
+    response.insert("next_cursor".to_string(), json!(0));
+
+    Reply::json(json!({ "response": response }))
+}
+
+fn trade_offer(world: &World, params: &Params) -> Reply {
+    let offer = params
+        .number::<TradeOfferId>("tradeofferid")
+        .and_then(|id| world.trade_offers.iter().find(|offer| offer.id == id));
+
+    match offer {
+        Some(offer) => Reply::json(json!({ "response": { "offer": trade_offer_json(offer) } })),
+        None => Reply::json(json!({ "response": {} })),
+    }
+}
+
+fn trade_offer_json(offer: &TradeOffer) -> Value {
+    let mut value = json!({
+        "tradeofferid": offer.id.to_string(),
+        "accountid_other": offer.partner.get_account_id(),
+        "message": offer.message,
+        "expiration_time": offer.time_created + 14 * 24 * 60 * 60,
+        "trade_offer_state": offer.state,
+        "is_our_offer": offer.sent,
+        "time_created": offer.time_created,
+        "time_updated": offer.time_created,
+        "from_real_time_trade": false,
+        "escrow_end_date": 0,
+        "confirmation_method": 0,
+        "eresult": 1,
+    });
+
+    // Steam leaves out empty item lists.
+    let fields = value.as_object_mut().unwrap();
+    for (name, items) in [
+        ("items_to_give", &offer.items_to_give),
+        ("items_to_receive", &offer.items_to_receive),
+    ] {
+        if !items.is_empty() {
+            fields.insert(name.to_string(), items.iter().map(item_json).collect());
+        }
+    }
+
+    value
+}
+
+fn item_json(item: &Item) -> Value {
+    json!({
+        "appid": item.appid,
+        "contextid": item.contextid.to_string(),
+        "assetid": item.assetid.to_string(),
+        "classid": item.classid.to_string(),
+        "instanceid": "0",
+        "amount": item.amount.to_string(),
+        "missing": false,
+        "est_usd": "0",
+    })
+}
+
+fn trade_offers_summary(world: &World) -> Reply {
+    let count = |sent: bool, active: bool| {
+        world
+            .trade_offers
+            .iter()
+            .filter(|offer| offer.sent == sent && (offer.state == 2) == active)
+            .count()
+    };
+
+    Reply::json(json!({
+        "response": {
+            "pending_received_count": count(false, true),
+            "new_received_count": count(false, true),
+            "updated_received_count": 0,
+            "historical_received_count": count(false, false),
+            "pending_sent_count": count(true, true),
+            "newly_accepted_sent_count": 0,
+            "updated_sent_count": 0,
+            "historical_sent_count": count(true, false),
+            "escrow_received_count": 0,
+            "escrow_sent_count": 0,
+        }
+    }))
+}
+
+fn trade_history(world: &World, params: &Params) -> Reply {
+    let start_after_time = params.number::<u32>("start_after_time");
+    let mut trades: Vec<&TradeOffer> = world
+        .trade_offers
+        .iter()
+        // 3 is `k_ETradeOfferStateAccepted`.
+        .filter(|offer| offer.state == 3)
+        .filter(|offer| start_after_time.is_none_or(|time| offer.time_created < time))
+        .collect();
+    trades.sort_by_key(|offer| Reverse(offer.time_created));
+
+    let total = trades.len();
+    let max_trades = params.number::<usize>("max_trades").unwrap_or_default();
+    let trades: Vec<Value> = trades
+        .into_iter()
+        .take(max_trades)
+        .map(|offer| {
+            let mut value = json!({
+                "tradeid": offer.id.to_string(),
+                "steamid_other": offer.partner.to_string(),
+                "time_init": offer.time_created,
+                // 3 is `k_ETradeStatus_Complete`.
+                "status": 3,
+            });
+
+            // Steam leaves out empty asset lists.
+            let fields = value.as_object_mut().unwrap();
+            for (name, items) in [
+                ("assets_given", &offer.items_to_give),
+                ("assets_received", &offer.items_to_receive),
+            ] {
+                if !items.is_empty() {
+                    fields.insert(
+                        name.to_string(),
+                        items.iter().map(traded_asset_json).collect(),
+                    );
+                }
+            }
+            value
+        })
+        .collect();
+
+    let mut response = Map::new();
+    if params.flag("include_total") {
+        response.insert("total_trades".to_string(), json!(total));
+    }
+    response.insert("more".to_string(), json!(total > trades.len()));
+    response.insert("trades".to_string(), json!(trades));
+
+    Reply::json(json!({ "response": response }))
+}
+
+fn traded_asset_json(item: &Item) -> Value {
+    // A traded item gets a new asset ID in its new owner's inventory.
+    json!({
+        "appid": item.appid,
+        "contextid": item.contextid.to_string(),
+        "assetid": item.assetid.to_string(),
+        "amount": item.amount.to_string(),
+        "classid": item.classid.to_string(),
+        "instanceid": "0",
+        "new_assetid": (item.assetid.0 + 1_000_000).to_string(),
+        "new_contextid": item.contextid.to_string(),
+    })
+}
+
+/// Lists the apps the world knows of, from owned games and workshop files.
+fn app_list(world: &World) -> Reply {
+    let mut apps: Vec<(AppId, &str)> = world
+        .users
+        .iter()
+        .flat_map(|user| &user.games)
+        .map(|game| (game.appid, game.name.as_str()))
+        .chain(
+            world
+                .files
+                .iter()
+                .filter(|file| !file.app_name.is_empty())
+                .map(|file| (file.app_id, file.app_name.as_str())),
+        )
+        .collect();
+    apps.sort();
+    apps.dedup_by_key(|(appid, _)| *appid);
+
+    let apps: Vec<Value> = apps
+        .into_iter()
+        .map(|(appid, name)| json!({ "appid": appid, "name": name }))
+        .collect();
+
+    Reply::json(json!({ "applist": { "apps": apps } }))
+}
+
+fn server_info() -> Reply {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    Reply::json(json!({ "servertime": now, "servertimestring": ctime(now) }))
+}
+
+/// Formats a Unix timestamp like C's `ctime`, e.g. `Thu Jan  1 00:00:00 1970`, which is how Steam shows its time.
+fn ctime(timestamp: u64) -> String {
+    const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // Howard Hinnant's `civil_from_days`, for days since 1970-01-01.
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = (shifted_month + 2) % 12;
+    let year = year_of_era + era * 400 + u64::from(month < 2);
+
+    format!(
+        "{} {} {day:>2} {:02}:{:02}:{:02} {year}",
+        WEEKDAYS[(days % 7) as usize],
+        MONTHS[month as usize],
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60,
+    )
+}
+
+/// Lists the methods in [`METHODS`], without their parameters.
+fn supported_api_list() -> Reply {
+    let mut interfaces: Vec<Value> = Vec::new();
+    for (interface, method, version, http_method) in METHODS {
+        let method = json!({ "name": method, "version": version, "httpmethod": http_method });
+        match interfaces.last_mut() {
+            Some(last) if last["name"] == *interface => {
+                last["methods"].as_array_mut().unwrap().push(method)
+            }
+            _ => interfaces.push(json!({ "name": interface, "methods": [method] })),
+        }
+    }
+
+    Reply::json(json!({ "apilist": { "interfaces": interfaces } }))
+}
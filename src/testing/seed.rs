@@ -0,0 +1,118 @@
+//! The users, files and trade offers of [`World::seeded`].
+
+use crate::{
+    ids::{AppId, AssetId, ClassId, GroupId, PublishedFileId, TradeOfferId},
+    steam_id::SteamId,
+    steam_user::get_player_summaries::PersonaState,
+};
+
+use super::world::{Bans, Game, Item, TradeOffer, User, WorkshopFile, World};
+
+/// A public profile with games, a friend, a group and the vanity URL `gabelogannewell`.
+pub const GABE: SteamId = SteamId(76561197960287930);
+
+/// A public profile, friends with [`GABE`].
+pub const ROBIN: SteamId = SteamId(76561197960435530);
+
+/// A private profile.
+pub const PRIVATE: SteamId = SteamId(76561198000000001);
+
+/// A public profile with VAC and game bans.
+pub const BANNED: SteamId = SteamId(76561198000000002);
+
+/// The Valve group, which [`GABE`] is a member of.
+pub const VALVE: GroupId = GroupId(4);
+
+/// A workshop file for Team Fortress 2, published by [`ROBIN`].
+pub const HAT: PublishedFileId = PublishedFileId(1_000_001);
+
+/// A workshop file for Team Fortress 2, published by [`GABE`].
+pub const MAP: PublishedFileId = PublishedFileId(1_000_002);
+
+/// A collection of [`HAT`] and [`MAP`].
+pub const COLLECTION: PublishedFileId = PublishedFileId(1_000_003);
+
+/// An active trade offer the key owner received from [`ROBIN`].
+pub const RECEIVED_OFFER: TradeOfferId = TradeOfferId(6_000_000_001);
+
+/// An active trade offer the key owner sent to [`BANNED`].
+pub const SENT_OFFER: TradeOfferId = TradeOfferId(6_000_000_002);
+
+impl World {
+    /// A small world to run tests against; see the constants in [`seed`](super::seed) for what it contains.
+    pub fn seeded() -> Self {
+        World::new()
+            .user(
+                User::new(GABE, "Rabscuttle")
+                    .real_name("Gabe Newell")
+                    .vanity_url("gabelogannewell")
+                    .persona_state(PersonaState::Online)
+                    .country_code("US")
+                    .level(50)
+                    .group(VALVE)
+                    .game(
+                        Game::new(AppId(440), "Team Fortress 2")
+                            .playtime(12_000, 90)
+                            .last_played(1_700_000_000),
+                    )
+                    .game(
+                        Game::new(AppId(570), "Dota 2")
+                            .playtime(3_000, 0)
+                            .last_played(1_650_000_000),
+                    )
+                    .game(Game::new(AppId(220), "Half-Life 2").playtime(900, 30)),
+            )
+            .user(
+                User::new(ROBIN, "Robin")
+                    .real_name("Robin Walker")
+                    .level(30)
+                    .game(Game::new(AppId(440), "Team Fortress 2").playtime(50_000, 600)),
+            )
+            .user(
+                User::new(PRIVATE, "Hidden")
+                    .private()
+                    .level(10)
+                    .game(Game::new(AppId(440), "Team Fortress 2").playtime(100, 0)),
+            )
+            .user(User::new(BANNED, "Cheater").level(1).bans(Bans {
+                vac_bans: 2,
+                game_bans: 1,
+                days_since_last_ban: 42,
+                ..Bans::default()
+            }))
+            .friends(GABE, ROBIN, 1_200_000_000)
+            .file(
+                WorkshopFile::new(HAT, ROBIN, AppId(440), "Towering Pillar of Hats")
+                    .app_name("Team Fortress 2")
+                    .description("A very tall hat.")
+                    .tag("Hat")
+                    .subscriptions(5_000)
+                    .time_created(1_300_000_000),
+            )
+            .file(
+                WorkshopFile::new(MAP, GABE, AppId(440), "cp_granary_remix")
+                    .app_name("Team Fortress 2")
+                    .description("Granary, with more hats.")
+                    .tag("Map")
+                    .subscriptions(1_200)
+                    .time_created(1_400_000_000),
+            )
+            .file(
+                WorkshopFile::new(COLLECTION, GABE, AppId(440), "Hats and maps")
+                    .app_name("Team Fortress 2")
+                    .children(&[HAT, MAP])
+                    .time_created(1_450_000_000),
+            )
+            .trade_offer(
+                TradeOffer::received(RECEIVED_OFFER, ROBIN)
+                    .message("Hat for a key?")
+                    .give(Item::new(AppId(440), AssetId(10_001), ClassId(5_002)))
+                    .receive(Item::new(AppId(440), AssetId(20_001), ClassId(101_785_959))),
+            )
+            .trade_offer(
+                TradeOffer::sent(SENT_OFFER, BANNED)
+                    .give(Item::new(AppId(440), AssetId(10_002), ClassId(5_021)))
+                    .receive(Item::new(AppId(440), AssetId(20_002), ClassId(5_002))),
+            )
+    }
+}
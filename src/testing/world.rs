@@ -0,0 +1,473 @@
+//! The in-memory data a [`Simulator`](super::Simulator) serves.
+
+use crate::{
+    ids::{AppId, AssetId, ClassId, GroupId, PublishedFileId, TradeOfferId},
+    steam_id::SteamId,
+    steam_user::get_player_summaries::PersonaState,
+};
+
+/// Everything the simulator knows about: users, workshop files and the trade offers of the API key's owner.
+#[derive(Debug, Clone, Default)]
+pub struct World {
+    /// Registered users.
+    pub users: Vec<User>,
+
+    /// Workshop files and collections.
+    pub files: Vec<WorkshopFile>,
+
+    /// Trade offers sent or received by the owner of the API key.
+    pub trade_offers: Vec<TradeOffer>,
+}
+
+impl World {
+    /// Creates an empty world.
+    pub fn new() -> Self {
+        World::default()
+    }
+
+    /// Adds a user.
+    pub fn user(mut self, user: User) -> Self {
+        self.users.push(user);
+        self
+    }
+
+    /// Makes two users friends of each other, since the given Unix timestamp.
+    ///
+    /// # Panics
+    ///
+    /// If either user has not been added yet.
+    pub fn friends(mut self, a: SteamId, b: SteamId, since: u32) -> Self {
+        for (user, friend) in [(a, b), (b, a)] {
+            self.user_mut(user)
+                .unwrap_or_else(|| panic!("unknown user {user}"))
+                .friends
+                .push((friend, since));
+        }
+        self
+    }
+
+    /// Adds a workshop file.
+    pub fn file(mut self, file: WorkshopFile) -> Self {
+        self.files.push(file);
+        self
+    }
+
+    /// Adds a trade offer.
+    pub fn trade_offer(mut self, trade_offer: TradeOffer) -> Self {
+        self.trade_offers.push(trade_offer);
+        self
+    }
+
+    /// Looks up a user.
+    pub fn find_user(&self, steam_id: SteamId) -> Option<&User> {
+        self.users.iter().find(|user| user.steam_id == steam_id)
+    }
+
+    /// Looks up a user to change them.
+    pub fn user_mut(&mut self, steam_id: SteamId) -> Option<&mut User> {
+        self.users.iter_mut().find(|user| user.steam_id == steam_id)
+    }
+
+    /// Looks up a workshop file.
+    pub fn find_file(&self, id: PublishedFileId) -> Option<&WorkshopFile> {
+        self.files.iter().find(|file| file.id == id)
+    }
+}
+
+/// A Steam user.
+#[derive(Debug, Clone)]
+pub struct User {
+    /// The user's 64-bit ID.
+    pub steam_id: SteamId,
+
+    /// The user's display name.
+    pub persona_name: String,
+
+    /// The user's real name, if they shared it.
+    pub real_name: Option<String>,
+
+    /// The custom part of the profile URL, e.g. `gabelogannewell`.
+    pub vanity_url: Option<String>,
+
+    /// The user's status.
+    pub persona_state: PersonaState,
+
+    /// Whether the profile is public. Private profiles hide their friends, games and level.
+    pub public: bool,
+
+    /// A Unix timestamp of when the account was created.
+    pub time_created: u64,
+
+    /// ISO 3166 code of where the user is located.
+    pub country_code: Option<String>,
+
+    /// The user's Steam level.
+    pub level: u16,
+
+    /// The games the user owns.
+    pub games: Vec<Game>,
+
+    /// The user's friends, with the Unix timestamp of when they became friends.
+    pub friends: Vec<(SteamId, u32)>,
+
+    /// The user's bans.
+    pub bans: Bans,
+
+    /// The groups the user is a member of. The first one is their primary group.
+    pub groups: Vec<GroupId>,
+}
+
+impl User {
+    /// Creates a public user without games, friends or bans.
+    pub fn new(steam_id: SteamId, persona_name: &str) -> Self {
+        User {
+            steam_id,
+            persona_name: persona_name.to_string(),
+            real_name: None,
+            vanity_url: None,
+            persona_state: PersonaState::Offline,
+            public: true,
+            time_created: 1_063_407_589,
+            country_code: None,
+            level: 0,
+            games: Vec::new(),
+            friends: Vec::new(),
+            bans: Bans::default(),
+            groups: Vec::new(),
+        }
+    }
+
+    /// Sets the user's real name.
+    pub fn real_name(mut self, real_name: &str) -> Self {
+        self.real_name = Some(real_name.to_string());
+        self
+    }
+
+    /// Sets the custom part of the profile URL.
+    pub fn vanity_url(mut self, vanity_url: &str) -> Self {
+        self.vanity_url = Some(vanity_url.to_string());
+        self
+    }
+
+    /// Sets the user's status.
+    pub fn persona_state(mut self, persona_state: PersonaState) -> Self {
+        self.persona_state = persona_state;
+        self
+    }
+
+    /// Makes the profile private.
+    pub fn private(mut self) -> Self {
+        self.public = false;
+        self
+    }
+
+    /// Sets the ISO 3166 code of where the user is located.
+    pub fn country_code(mut self, country_code: &str) -> Self {
+        self.country_code = Some(country_code.to_string());
+        self
+    }
+
+    /// Sets the user's Steam level.
+    pub fn level(mut self, level: u16) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Adds an owned game.
+    pub fn game(mut self, game: Game) -> Self {
+        self.games.push(game);
+        self
+    }
+
+    /// Sets the user's bans.
+    pub fn bans(mut self, bans: Bans) -> Self {
+        self.bans = bans;
+        self
+    }
+
+    /// Adds a group the user is a member of.
+    pub fn group(mut self, group_id: GroupId) -> Self {
+        self.groups.push(group_id);
+        self
+    }
+
+    /// The URL of the user's community profile.
+    pub fn profile_url(&self) -> String {
+        match &self.vanity_url {
+            Some(vanity_url) => format!("https://steamcommunity.com/id/{vanity_url}/"),
+            None => format!("https://steamcommunity.com/profiles/{}/", self.steam_id),
+        }
+    }
+}
+
+/// A game owned by a [`User`].
+#[derive(Debug, Clone)]
+pub struct Game {
+    /// The app ID.
+    pub appid: AppId,
+
+    /// The name of the game.
+    pub name: String,
+
+    /// Minutes played in total.
+    pub playtime_forever: u32,
+
+    /// Minutes played in the last two weeks.
+    pub playtime_2weeks: u32,
+
+    /// A Unix timestamp of when the game was last played.
+    pub rtime_last_played: u64,
+}
+
+impl Game {
+    /// Creates a game that was never played.
+    pub fn new(appid: AppId, name: &str) -> Self {
+        Game {
+            appid,
+            name: name.to_string(),
+            playtime_forever: 0,
+            playtime_2weeks: 0,
+            rtime_last_played: 0,
+        }
+    }
+
+    /// Sets the minutes played in total and in the last two weeks.
+    pub fn playtime(mut self, forever: u32, last_two_weeks: u32) -> Self {
+        self.playtime_forever = forever;
+        self.playtime_2weeks = last_two_weeks;
+        self
+    }
+
+    /// Sets when the game was last played.
+    pub fn last_played(mut self, rtime_last_played: u64) -> Self {
+        self.rtime_last_played = rtime_last_played;
+        self
+    }
+}
+
+/// The bans of a [`User`].
+#[derive(Debug, Clone)]
+pub struct Bans {
+    /// Whether the user is banned from the Steam Community.
+    pub community_banned: bool,
+
+    /// Number of VAC bans.
+    pub vac_bans: u32,
+
+    /// Number of bans in games.
+    pub game_bans: u32,
+
+    /// Days since the last ban.
+    pub days_since_last_ban: u32,
+
+    /// Ban status in the economy: `"none"`, `"probation"` or `"banned"`.
+    pub economy_ban: String,
+}
+
+impl Default for Bans {
+    fn default() -> Self {
+        Bans {
+            community_banned: false,
+            vac_bans: 0,
+            game_bans: 0,
+            days_since_last_ban: 0,
+            economy_ban: "none".to_string(),
+        }
+    }
+}
+
+/// A workshop file, or a collection of them.
+#[derive(Debug, Clone)]
+pub struct WorkshopFile {
+    /// The published file ID.
+    pub id: PublishedFileId,
+
+    /// The user who published the file.
+    pub creator: SteamId,
+
+    /// The app the file is for.
+    pub app_id: AppId,
+
+    /// The name of the app the file is for.
+    pub app_name: String,
+
+    /// The title of the file.
+    pub title: String,
+
+    /// The description of the file.
+    pub description: String,
+
+    /// Tags on the file.
+    pub tags: Vec<String>,
+
+    /// The number of current subscriptions.
+    pub subscriptions: u64,
+
+    /// A Unix timestamp of when the file was published.
+    pub time_created: u32,
+
+    /// The files in this collection. Empty for regular files.
+    pub children: Vec<PublishedFileId>,
+}
+
+impl WorkshopFile {
+    /// Creates an untagged file nobody subscribed to.
+    pub fn new(id: PublishedFileId, creator: SteamId, app_id: AppId, title: &str) -> Self {
+        WorkshopFile {
+            id,
+            creator,
+            app_id,
+            app_name: String::new(),
+            title: title.to_string(),
+            description: String::new(),
+            tags: Vec::new(),
+            subscriptions: 0,
+            time_created: 1_500_000_000,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the app the file is for.
+    pub fn app_name(mut self, app_name: &str) -> Self {
+        self.app_name = app_name.to_string();
+        self
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Adds a tag.
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Sets the number of current subscriptions.
+    pub fn subscriptions(mut self, subscriptions: u64) -> Self {
+        self.subscriptions = subscriptions;
+        self
+    }
+
+    /// Sets when the file was published.
+    pub fn time_created(mut self, time_created: u32) -> Self {
+        self.time_created = time_created;
+        self
+    }
+
+    /// Makes the file a collection of other files.
+    pub fn children(mut self, children: &[PublishedFileId]) -> Self {
+        self.children = children.to_vec();
+        self
+    }
+}
+
+/// A trade offer, seen from the owner of the API key.
+#[derive(Debug, Clone)]
+pub struct TradeOffer {
+    /// The trade offer ID.
+    pub id: TradeOfferId,
+
+    /// The other party of the trade.
+    pub partner: SteamId,
+
+    /// Whether the key owner sent the offer, as opposed to receiving it.
+    pub sent: bool,
+
+    /// The message included with the offer.
+    pub message: String,
+
+    /// The state of the offer, e.g. 2 for active or 3 for accepted.
+    pub state: u32,
+
+    /// Items the key owner gives.
+    pub items_to_give: Vec<Item>,
+
+    /// Items the key owner receives.
+    pub items_to_receive: Vec<Item>,
+
+    /// A Unix timestamp of when the offer was created.
+    pub time_created: u32,
+}
+
+impl TradeOffer {
+    /// Creates an active offer the key owner received from `partner`.
+    pub fn received(id: TradeOfferId, partner: SteamId) -> Self {
+        TradeOffer {
+            id,
+            partner,
+            sent: false,
+            message: String::new(),
+            state: 2,
+            items_to_give: Vec::new(),
+            items_to_receive: Vec::new(),
+            time_created: 1_700_000_000,
+        }
+    }
+
+    /// Creates an active offer the key owner sent to `partner`.
+    pub fn sent(id: TradeOfferId, partner: SteamId) -> Self {
+        TradeOffer {
+            sent: true,
+            ..TradeOffer::received(id, partner)
+        }
+    }
+
+    /// Sets the message.
+    pub fn message(mut self, message: &str) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// Sets the state.
+    pub fn state(mut self, state: u32) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Adds an item the key owner gives.
+    pub fn give(mut self, item: Item) -> Self {
+        self.items_to_give.push(item);
+        self
+    }
+
+    /// Adds an item the key owner receives.
+    pub fn receive(mut self, item: Item) -> Self {
+        self.items_to_receive.push(item);
+        self
+    }
+}
+
+/// An item in a [`TradeOffer`].
+#[derive(Debug, Clone)]
+pub struct Item {
+    /// The app the item belongs to.
+    pub appid: AppId,
+
+    /// The inventory context, 2 for most games.
+    pub contextid: u64,
+
+    /// The asset ID.
+    pub assetid: AssetId,
+
+    /// The class ID.
+    pub classid: ClassId,
+
+    /// How many of the item are traded.
+    pub amount: u32,
+}
+
+impl Item {
+    /// Creates a single item in context 2.
+    pub fn new(appid: AppId, assetid: AssetId, classid: ClassId) -> Self {
+        Item {
+            appid,
+            contextid: 2,
+            assetid,
+            classid,
+            amount: 1,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! The HTTP transport every request is sent through.
+//!
+//! [`Steam`](crate::Steam) describes each request as an [`HttpRequest`] and hands it to a [`Transport`], which
+//! returns the status, headers and body. Retries, rate limiting and quota accounting happen before the transport
+//! is called, so an implementation only has to perform a single exchange.
+//!
+//! The default is [`ReqwestTransport`]. A custom transport can serve canned responses in tests, or wrap another
+//! transport to add caching or metrics:
+//!
+//! ```
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use steam_rs::{
+//!     transport::{BoxFuture, HttpRequest, HttpResponse, ReqwestTransport, Transport, TransportError},
+//!     Steam,
+//! };
+//!
+//! #[derive(Debug, Default)]
+//! struct Counting {
+//!     inner: ReqwestTransport,
+//!     requests: AtomicUsize,
+//! }
+//!
+//! impl Transport for Counting {
+//!     fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, TransportError>> {
+//!         self.requests.fetch_add(1, Ordering::Relaxed);
+//!         self.inner.send(request)
+//!     }
+//! }
+//!
+//! let steam = Steam::builder().transport(Counting::default()).build();
+//! ```
+
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+use reqwest::{header::HeaderMap, Method, StatusCode};
+
+/// A boxed future returned by [`Transport::send`].
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// An error raised by a [`Transport`] when no response could be obtained.
+pub type TransportError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A request to be sent by a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// The HTTP method.
+    pub method: Method,
+
+    /// The URL, which may already contain a query string.
+    pub url: String,
+
+    /// Additional query parameters, to be percent-encoded and appended to `url`.
+    pub query: Vec<(String, String)>,
+
+    /// Request headers.
+    pub headers: HeaderMap,
+
+    /// The request body, if any.
+    pub body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+    /// Creates a request without query parameters, headers or body.
+    pub fn new(method: Method, url: &str) -> Self {
+        HttpRequest {
+            method,
+            url: url.to_string(),
+            query: Vec::new(),
+            headers: HeaderMap::new(),
+            body: None,
+        }
+    }
+}
+
+/// A response returned by a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// The HTTP status.
+    pub status: StatusCode,
+
+    /// Response headers.
+    pub headers: HeaderMap,
+
+    /// The response body.
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Creates a response without headers.
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        HttpResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+}
+
+/// Performs a single HTTP exchange.
+pub trait Transport: Debug + Send + Sync {
+    /// Sends `request` and returns the response, whatever its status.
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, TransportError>>;
+}
+
+/// The default [`Transport`], backed by a [`reqwest::Client`].
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport sending requests through `client`.
+    pub fn new(client: reqwest::Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, TransportError>> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, &request.url)
+                .headers(request.headers);
+            if !request.query.is_empty() {
+                builder = builder.query(&request.query);
+            }
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
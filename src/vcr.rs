@@ -0,0 +1,364 @@
+//! Recording responses to fixture files, and replaying them without network access.
+//!
+//! A [`Recorder`] is a [`Transport`] that sends requests through another transport and writes every exchange to a
+//! [`Cassette`] file, with the API key redacted. A [`Replayer`] answers requests from such a file and never touches
+//! the network; a request that was not recorded fails with a [`NoRecording`] error naming it.
+//!
+//! Requests are matched by HTTP method, interface, method, version and parameters. The base URL and the API key are
+//! ignored, so a cassette recorded against Steam replays for any client. When the same request was recorded several
+//! times, e.g. a `429` followed by a retry, the recordings are replayed in order and the last one is repeated.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use steam_rs::{
+//!     vcr::{Recorder, Replayer},
+//!     Steam,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // Once, against Steam:
+//! let steam = Steam::builder()
+//!     .api_key(&std::env::var("STEAM_API_KEY")?)
+//!     .transport(Recorder::new("tests/fixtures/server_info.json"))
+//!     .build();
+//! steam.get_server_info().await?;
+//!
+//! // From then on, offline:
+//! let steam = Steam::builder()
+//!     .transport(Replayer::open("tests/fixtures/server_info.json")?)
+//!     .build();
+//! steam.get_server_info().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    query::Query,
+    transport::{
+        BoxFuture, HttpRequest, HttpResponse, ReqwestTransport, Transport, TransportError,
+    },
+};
+
+/// What the API key is replaced with in recordings.
+pub const REDACTED: &str = "REDACTED";
+
+/// Recorded exchanges, as stored in a fixture file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// The exchanges in the order they happened.
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Reads a cassette from a JSON file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        serde_json::from_str(&json)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Writes the cassette to a JSON file, creating its directory if needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    /// Writes the cassette like [`Cassette::save`], without blocking the runtime.
+    async fn save_async(&self, path: &Path) -> io::Result<()> {
+        if let Some(directory) = path.parent() {
+            tokio::fs::create_dir_all(directory).await?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        tokio::fs::write(path, json + "\n").await
+    }
+}
+
+/// A request and the response it got.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request.
+    pub request: RecordedRequest,
+
+    /// The response.
+    pub response: RecordedResponse,
+}
+
+/// A request, reduced to what replaying matches on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// The HTTP method, e.g. `"GET"`.
+    pub method: String,
+
+    /// The path without the base URL, e.g. `"/ISteamUser/GetFriendList/v1/"`.
+    pub path: String,
+
+    /// The parameters from the query string and the form body, with the API key redacted.
+    pub params: BTreeMap<String, String>,
+}
+
+impl RecordedRequest {
+    /// Describes `request` the way it is recorded.
+    pub fn new(request: &HttpRequest) -> Self {
+        let (url, query) = request.url.split_once('?').unwrap_or((&request.url, ""));
+        // Everything up to the first `/` after the scheme is the base URL.
+        let path = match url.split_once("://") {
+            Some((_, rest)) => rest.find('/').map_or("/", |start| &rest[start..]),
+            None => url,
+        };
+
+        let mut params: Vec<(String, String)> = Query::parse(query).into();
+        params.extend(request.query.iter().cloned());
+        if let Some(body) = &request.body {
+            params.extend(Vec::from(Query::parse(&String::from_utf8_lossy(body))));
+        }
+
+        RecordedRequest {
+            method: request.method.to_string(),
+            path: path.to_string(),
+            params: params
+                .into_iter()
+                .map(|(name, value)| match name.as_str() {
+                    "key" => (name, REDACTED.to_string()),
+                    _ => (name, value),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for RecordedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.path)?;
+        for (index, (name, value)) in self.params.iter().enumerate() {
+            let separator = if index == 0 { '?' } else { '&' };
+            write!(f, "{separator}{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// The HTTP status code.
+    pub status: u16,
+
+    /// Response headers, except cookies.
+    pub headers: BTreeMap<String, String>,
+
+    /// The response body.
+    pub body: Body,
+}
+
+impl RecordedResponse {
+    fn new(response: &HttpResponse) -> Self {
+        RecordedResponse {
+            status: response.status.as_u16(),
+            headers: response
+                .headers
+                .iter()
+                .filter(|(name, _)| *name != reqwest::header::SET_COOKIE)
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect(),
+            body: match String::from_utf8(response.body.clone()) {
+                Ok(text) => Body::Text(text),
+                Err(error) => Body::Bytes(error.into_bytes()),
+            },
+        }
+    }
+
+    fn to_response(&self) -> Result<HttpResponse, TransportError> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes())?,
+                HeaderValue::from_str(value)?,
+            );
+        }
+
+        Ok(HttpResponse {
+            status: StatusCode::from_u16(self.status)?,
+            headers,
+            body: match &self.body {
+                Body::Text(text) => text.clone().into_bytes(),
+                Body::Bytes(bytes) => bytes.clone(),
+            },
+        })
+    }
+}
+
+/// A response body: text when it is valid UTF-8, e.g. JSON, and bytes otherwise, e.g. protobuf.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Body {
+    /// A UTF-8 body.
+    Text(String),
+
+    /// Any other body.
+    Bytes(Vec<u8>),
+}
+
+/// A [`Transport`] that sends requests through another transport and records every exchange to a file.
+///
+/// The file is rewritten after each exchange, so it is complete even if the program stops early. Exchanges that
+/// fail without a response, e.g. on a connection error, are not recorded.
+#[derive(Debug)]
+pub struct Recorder<T = ReqwestTransport> {
+    inner: T,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+    /// Held while the file is written, so writes of concurrent exchanges do not interleave.
+    writing: tokio::sync::Mutex<()>,
+}
+
+impl Recorder {
+    /// Records exchanges made through a default [`ReqwestTransport`] to `path`, replacing any previous recording.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Recorder::with_transport(ReqwestTransport::default(), path)
+    }
+}
+
+impl<T: Transport> Recorder<T> {
+    /// Records exchanges made through `inner` to `path`, replacing any previous recording.
+    pub fn with_transport(inner: T, path: impl Into<PathBuf>) -> Self {
+        Recorder {
+            inner,
+            path: path.into(),
+            cassette: Mutex::new(Cassette::default()),
+            writing: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// The exchanges recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        self.cassette.lock().unwrap().clone()
+    }
+}
+
+impl<T: Transport> Transport for Recorder<T> {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, TransportError>> {
+        Box::pin(async move {
+            let recorded = RecordedRequest::new(&request);
+            let response = self.inner.send(request).await?;
+
+            let interaction = Interaction {
+                request: recorded,
+                response: RecordedResponse::new(&response),
+            };
+            self.cassette.lock().unwrap().interactions.push(interaction);
+
+            // Each write takes the latest cassette, so the last one to finish includes every exchange.
+            let _writing = self.writing.lock().await;
+            self.cassette().save_async(&self.path).await?;
+
+            Ok(response)
+        })
+    }
+}
+
+/// A [`Transport`] that answers requests from a [`Cassette`] without network access.
+#[derive(Debug)]
+pub struct Replayer {
+    cassette: Cassette,
+    source: Option<PathBuf>,
+    replayed: Mutex<Vec<bool>>,
+}
+
+impl Replayer {
+    /// Replays the cassette stored at `path`.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let mut replayer = Replayer::new(Cassette::load(path)?);
+        replayer.source = Some(path.to_path_buf());
+        Ok(replayer)
+    }
+
+    /// Replays `cassette`.
+    pub fn new(cassette: Cassette) -> Self {
+        let replayed = vec![false; cassette.interactions.len()];
+        Replayer {
+            cassette,
+            source: None,
+            replayed: Mutex::new(replayed),
+        }
+    }
+
+    fn find(&self, request: &RecordedRequest) -> Option<&RecordedResponse> {
+        let mut replayed = self.replayed.lock().unwrap();
+        let matching: Vec<usize> = self
+            .cassette
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| &interaction.request == request)
+            .map(|(index, _)| index)
+            .collect();
+
+        let index = matching
+            .iter()
+            .copied()
+            .find(|index| !replayed[*index])
+            .or_else(|| matching.last().copied())?;
+        replayed[index] = true;
+        Some(&self.cassette.interactions[index].response)
+    }
+}
+
+impl Transport for Replayer {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, TransportError>> {
+        let request = RecordedRequest::new(&request);
+        let result = match self.find(&request) {
+            Some(response) => response.to_response(),
+            None => Err(NoRecording {
+                request,
+                cassette: self.source.clone(),
+            }
+            .into()),
+        };
+        Box::pin(async move { result })
+    }
+}
+
+/// The error a [`Replayer`] fails with when a request was not recorded.
+///
+/// It reaches callers as a [`SteamError::Network`](crate::errors::SteamError::Network), which can be downcast to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoRecording {
+    /// The request that was not recorded.
+    pub request: RecordedRequest,
+
+    /// The file the cassette was read from, if any.
+    pub cassette: Option<PathBuf>,
+}
+
+impl fmt::Display for NoRecording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No recorded response for {}", self.request)?;
+        if let Some(cassette) = &self.cassette {
+            write!(f, " in {}", cassette.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for NoRecording {}
@@ -0,0 +1,123 @@
+use steam_rs::{
+    ids::AppId, published_file_service::query_files::PublishedFileQueryType, steam_id::SteamId,
+    Steam,
+};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+const EXAMPLE_STEAM_ID: SteamId = SteamId(76561197960287930); // Gabe Newell
+const OWNED_GAMES: &str = r#"{"response":{"game_count":0,"games":[]}}"#;
+
+#[tokio::test]
+pub async fn unset_options_are_left_out() {
+    let server = MockServer::start(vec![MockResponse::ok(r#"{"response":{}}"#)]).await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    let _ = steam.get_trade_history(10).await;
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/IEconService/GetTradeHistory/v1/?key=key&max_trades=10"
+    );
+}
+
+#[tokio::test]
+pub async fn set_options_are_sent() {
+    let server = MockServer::start(vec![MockResponse::ok(r#"{"response":{}}"#)]).await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    let _ = steam
+        .get_trade_history(10)
+        .get_descriptions(true)
+        .language("english")
+        .send()
+        .await;
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/IEconService/GetTradeHistory/v1/?key=key&max_trades=10&get_descriptions=true&language=english"
+    );
+}
+
+#[tokio::test]
+pub async fn tag_options_are_sent_as_arrays() {
+    let server = MockServer::start(vec![MockResponse::ok(
+        r#"{"response":{"total":0,"publishedfiledetails":[]}}"#,
+    )])
+    .await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    let _ = steam
+        .query_files(PublishedFileQueryType::RankedByVote)
+        .required_tags(&["Hat", "Map"])
+        .excluded_tags(&["Taunt"])
+        .send()
+        .await;
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/IPublishedFileService/QueryFiles/v1/?key=key&query_type=0&requiredtags%5B0%5D=Hat&requiredtags%5B1%5D=Map&excludedtags%5B0%5D=Taunt"
+    );
+}
+
+#[tokio::test]
+pub async fn service_options_are_sent_as_input_json() {
+    let server = MockServer::start(vec![
+        MockResponse::ok(OWNED_GAMES),
+        MockResponse::ok(OWNED_GAMES),
+    ])
+    .await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    steam.get_owned_games(EXAMPLE_STEAM_ID).await.unwrap();
+    steam
+        .get_owned_games(EXAMPLE_STEAM_ID)
+        .include_appinfo(true)
+        .appids_filter(&[AppId(440), AppId(570)])
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/IPlayerService/GetOwnedGames/v1/?key=key&input_json=%7B%22steamid%22%3A%2276561197960287930%22%7D"
+    );
+    assert_eq!(
+        requests[1].target,
+        "/IPlayerService/GetOwnedGames/v1/?key=key&input_json=%7B%22steamid%22%3A%2276561197960287930%22%2C%22include_appinfo%22%3Atrue%2C%22appids_filter%22%3A%5B440%2C570%5D%7D"
+    );
+}
+
+#[tokio::test]
+pub async fn endpoints_without_required_arguments() {
+    let server = MockServer::start(vec![MockResponse::ok(r#"{"response":{}}"#)]).await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    let _ = steam.get_trade_offers_summary().time_last_visit(0).await;
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/IEconService/GetTradeOffersSummary/v1/?key=key&time_last_visit=0"
+    );
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "codegen")]
+
+use std::path::Path;
+
+use steam_rs::{
+    codegen::{self, Coverage},
+    steam_webapi_util::get_supported_api_list::APIList,
+};
+
+const API_LIST: &str = r#"{"apilist":{"interfaces":[
+    {"name":"ISteamUser","methods":[
+        {"name":"GetFriendList","version":1,"httpmethod":"GET","parameters":[]}
+    ]},
+    {"name":"IStoreService","methods":[
+        {"name":"GetAppList","version":1,"httpmethod":"GET","parameters":[]},
+        {"name":"GetAppList","version":2,"httpmethod":"GET","parameters":[
+            {"name":"key","type":"string","optional":false},
+            {"name":"if_modified_since","type":"uint32","optional":true,"description":"Return only items modified since this date."}
+        ]},
+        {"name":"SetTags","version":1,"httpmethod":"POST","parameters":[
+            {"name":"steamid","type":"uint64","optional":false},
+            {"name":"tags[0]","type":"string","optional":false},
+            {"name":"type","type":"{enum}","optional":false}
+        ]}
+    ]}
+]}}"#;
+
+#[test]
+pub fn names_follow_the_crate() {
+    assert_eq!(codegen::module_name("IPlayerService"), "player_service");
+    assert_eq!(codegen::module_name("ISteamUserStats"), "steam_user_stats");
+    assert_eq!(
+        codegen::function_name("GetSupportedAPIList"),
+        "get_supported_api_list"
+    );
+    assert_eq!(
+        codegen::function_name("GetServerSteamIDsByIP"),
+        "get_server_steam_ids_by_ip"
+    );
+}
+
+#[test]
+pub fn scans_the_implemented_methods() {
+    let coverage = Coverage::scan(Path::new("src")).unwrap();
+
+    assert!(coverage.contains("ISteamUser", "GetFriendList"));
+    assert!(coverage.contains("ISteamWebAPIUtil", "GetSupportedAPIList"));
+    assert!(!coverage.contains("ISteamUser", "GetPlayerBanz"));
+}
+
+#[test]
+pub fn reports_missing_methods() {
+    let api_list = APIList::from_json(API_LIST).unwrap();
+    let mut coverage = Coverage::default();
+    coverage.insert("ISteamUser", "GetFriendList");
+    coverage.reserve("get_app_list");
+
+    let missing = codegen::missing(&api_list, &coverage);
+    let missing: Vec<(&str, u8, &str)> = missing
+        .iter()
+        .map(|found| {
+            (
+                found.method.name.as_str(),
+                found.method.version,
+                found.function.as_str(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        missing,
+        [
+            ("GetAppList", 2, "store_service_get_app_list"),
+            ("SetTags", 1, "set_tags")
+        ]
+    );
+}
+
+#[test]
+pub fn generates_builders_for_optional_parameters() {
+    let api_list = APIList::from_json(API_LIST).unwrap();
+    let missing = codegen::missing(&api_list, &Coverage::default());
+    let source = codegen::generate(&missing[1]);
+
+    assert!(source.contains("const ENDPOINT: &str = \"GetAppList\";"));
+    assert!(source.contains("const VERSION: &str = \"2\";"));
+    assert!(source.contains("get_app_list -> GetAppList<'a> => GetAppListResponse {"));
+    assert!(source.contains(
+        "/// Return only items modified since this date.\n            if_modified_since: u32,"
+    ));
+    assert!(source.contains(".param(\"key\", &self.steam.api_key)"));
+    assert!(source.contains(".optional(\"if_modified_since\", self.if_modified_since)"));
+}
+
+#[test]
+pub fn generates_methods_for_required_parameters() {
+    let api_list = APIList::from_json(API_LIST).unwrap();
+    let missing = codegen::missing(&api_list, &Coverage::default());
+    let source = codegen::generate(&missing[2]);
+
+    assert!(source.contains(
+        "pub async fn set_tags(&self, steamid: SteamId, tags: &[&str], r#type: &str) -> Result<SetTagsResponse, SteamError>"
+    ));
+    assert!(source.contains(".array(\"tags\", tags)"));
+    assert!(source.contains("do_http!(self, POST url, SetTagsResponse, query)"));
+    assert!(!source.contains("\"key\""));
+}
+
+// The fixture is compiled with the crate's tests, so this checks that generated modules build.
+#[test]
+pub fn generated_modules_match_the_compiled_fixture() {
+    let api_list = APIList::from_json(API_LIST).unwrap();
+    let coverage = Coverage::scan(Path::new("src")).unwrap();
+    let missing = codegen::missing(&api_list, &coverage);
+
+    assert_eq!(
+        codegen::generate(&missing[0]),
+        include_str!("fixtures/codegen/store_service/store_service_get_app_list.rs")
+    );
+    assert_eq!(
+        codegen::generate(&missing[1]),
+        include_str!("fixtures/codegen/store_service/set_tags.rs")
+    );
+    assert_eq!(
+        codegen::generate_interface(
+            "IStoreService",
+            &[
+                "store_service_get_app_list".to_string(),
+                "set_tags".to_string()
+            ]
+        ),
+        include_str!("fixtures/codegen/store_service/mod.rs")
+    );
+}
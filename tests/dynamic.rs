@@ -0,0 +1,212 @@
+use steam_rs::{
+    dynamic::{CallError, DynamicClient},
+    errors::SteamError,
+    query::Query,
+    Steam,
+};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+const API_LIST: &str = r#"{"apilist":{"interfaces":[
+    {"name":"ISteamUser","methods":[
+        {"name":"GetPlayerBans","version":1,"httpmethod":"GET","parameters":[
+            {"name":"key","type":"string","optional":false,"description":"access key"},
+            {"name":"steamids","type":"string","optional":false,"description":"Comma-delimited list of SteamIDs"}
+        ]},
+        {"name":"GetFriendList","version":1,"httpmethod":"GET","parameters":[
+            {"name":"key","type":"string","optional":false},
+            {"name":"steamid","type":"uint64","optional":false},
+            {"name":"relationship","type":"string","optional":true}
+        ]}
+    ]},
+    {"name":"IPublishedFileService","methods":[
+        {"name":"GetDetails","version":1,"httpmethod":"POST","parameters":[
+            {"name":"publishedfileids[0]","type":"fixed64","optional":false},
+            {"name":"includetags","type":"bool","optional":true}
+        ]}
+    ]}
+]}}"#;
+
+fn client(steam: Steam) -> DynamicClient {
+    DynamicClient::from_snapshot(steam, API_LIST).unwrap()
+}
+
+fn validate(interface: &str, method: &str, version: u32, params: Query) -> CallError {
+    let client = client(Steam::new("key"));
+    client
+        .validate(interface, method, version, &params)
+        .unwrap_err()
+}
+
+#[test]
+pub fn suggests_interfaces_and_methods() {
+    let error = validate("ISteamUsr", "GetPlayerBans", 1, Query::new());
+    assert_eq!(
+        error.to_string(),
+        "Unknown interface `ISteamUsr`, did you mean `ISteamUser`?"
+    );
+
+    let error = validate("ISteamUser", "GetPlayerBan", 1, Query::new());
+    assert_eq!(
+        error,
+        CallError::UnknownMethod {
+            interface: "ISteamUser".to_string(),
+            method: "GetPlayerBan".to_string(),
+            suggestion: Some("GetPlayerBans".to_string()),
+        }
+    );
+
+    let error = validate("ISteamUser", "Unrelated", 1, Query::new());
+    assert!(matches!(
+        error,
+        CallError::UnknownMethod {
+            suggestion: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+pub fn checks_versions() {
+    let error = validate("ISteamUser", "GetPlayerBans", 2, Query::new());
+    assert_eq!(
+        error.to_string(),
+        "`ISteamUser/GetPlayerBans` has no version 2, available: v1"
+    );
+}
+
+#[test]
+pub fn checks_parameters() {
+    let error = validate("ISteamUser", "GetFriendList", 1, Query::new());
+    assert_eq!(
+        error,
+        CallError::MissingParameter {
+            name: "steamid".to_string()
+        }
+    );
+
+    let error = validate(
+        "ISteamUser",
+        "GetFriendList",
+        1,
+        Query::new().param("steamid", 1).param("relationshp", "all"),
+    );
+    assert_eq!(
+        error.to_string(),
+        "Unknown parameter `relationshp`, did you mean `relationship`?"
+    );
+
+    let error = validate(
+        "ISteamUser",
+        "GetFriendList",
+        1,
+        Query::new().param("steamid", "gaben"),
+    );
+    assert!(matches!(error, CallError::InvalidParameter { expected, .. } if expected == "uint64"));
+}
+
+#[test]
+pub fn accepts_array_parameters() {
+    let client = client(Steam::default());
+    let params = Query::new()
+        .array("publishedfileids", [1u64, 2])
+        .param("includetags", true);
+
+    assert!(client
+        .validate("IPublishedFileService", "GetDetails", 1, &params)
+        .is_ok());
+}
+
+#[test]
+pub fn accepts_format() {
+    let client = client(Steam::new("key"));
+    let params = Query::new().param("steamid", 1).param("format", "json");
+
+    assert!(client
+        .validate("ISteamUser", "GetFriendList", 1, &params)
+        .is_ok());
+}
+
+#[test]
+pub fn accepts_input_json() {
+    let client = client(Steam::new("key"));
+    // Required parameters may be given in `input_json` instead.
+    let params = Query::new().param("input_json", r#"{"steamid":1}"#);
+
+    assert!(client
+        .validate("ISteamUser", "GetFriendList", 1, &params)
+        .is_ok());
+}
+
+#[test]
+pub fn requires_a_key_when_the_client_has_none() {
+    let client = client(Steam::default());
+    let error = client
+        .validate(
+            "ISteamUser",
+            "GetPlayerBans",
+            1,
+            &Query::new().param("steamids", 1),
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        CallError::MissingParameter {
+            name: "key".to_string()
+        }
+    );
+}
+
+#[test]
+pub fn snapshots_round_trip() {
+    let client = client(Steam::default());
+    let restored = DynamicClient::from_snapshot(Steam::default(), &client.snapshot()).unwrap();
+
+    assert_eq!(restored.api_list().interfaces.len(), 2);
+}
+
+#[tokio::test]
+pub async fn sends_valid_calls_with_the_listed_http_method() {
+    let server = MockServer::start(vec![MockResponse::ok(r#"{"response":{}}"#)]).await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    client(steam)
+        .call_raw(
+            "IPublishedFileService",
+            "GetDetails",
+            1,
+            Query::new().array("publishedfileids", [1u64]),
+        )
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.target, "/IPublishedFileService/GetDetails/v1/");
+}
+
+#[tokio::test]
+pub async fn invalid_calls_are_not_sent() {
+    let server = MockServer::start(vec![]).await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    let error = client(steam)
+        .call_raw("ISteamUser", "GetFriendList", 1, Query::new())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        SteamError::InvalidCall(CallError::MissingParameter { .. })
+    ));
+    assert!(server.requests().is_empty());
+}
@@ -1,11 +1,21 @@
-use steam_rs::Steam;
+use steam_rs::{
+    ids::{TradeId, TradeOfferId},
+    Steam,
+};
 mod common;
 
 #[tokio::test]
 pub async fn get_trade_history() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
     steam
-        .get_trade_history(10, 0, 0, false, true, "english", true, true)
+        .get_trade_history(10)
+        .start_after_time(0)
+        .start_after_trade_id(TradeId(0))
+        .navigating_back(false)
+        .get_descriptions(true)
+        .language("english")
+        .include_failed(true)
+        .include_total(true)
         .await
         .unwrap();
 }
@@ -14,7 +24,14 @@ pub async fn get_trade_history() {
 pub async fn get_trade_offers() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
     steam
-        .get_trade_offers(true, true, true, "english", false, false, 10000)
+        .get_trade_offers()
+        .get_sent_offers(true)
+        .get_received_offers(true)
+        .get_descriptions(true)
+        .language("english")
+        .active_only(false)
+        .historical_only(false)
+        .time_historical_cutoff(10000)
         .await
         .unwrap();
 }
@@ -25,11 +42,19 @@ pub async fn get_trade_offers() {
 #[ignore = "Test is flawed. See: https://help.steampowered.com/en/faqs/view/1115-91C5-050C-1D60"]
 pub async fn get_trade_offer() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
-    steam.get_trade_offer(6271878669, "english").await.unwrap();
+    steam
+        .get_trade_offer(TradeOfferId(6271878669))
+        .language("english")
+        .await
+        .unwrap();
 }
 
 #[tokio::test]
 pub async fn get_trade_offers_summary() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
-    steam.get_trade_offers_summary(0).await.unwrap();
+    steam
+        .get_trade_offers_summary()
+        .time_last_visit(0)
+        .await
+        .unwrap();
 }
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use steam_rs::{
+    errors::{SteamError, SteamUserError},
+    ids::AppId,
+    retry::RetryPolicy,
+    steam_id::SteamId,
+    Steam,
+};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+const EXAMPLE_STEAM_ID: SteamId = SteamId(76561197960434622);
+
+async fn steam_with(response: MockResponse) -> (Steam, MockServer) {
+    let server = MockServer::start(vec![response]).await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(RetryPolicy::none())
+        .build();
+    (steam, server)
+}
+
+#[tokio::test]
+pub async fn unauthorized() {
+    let (steam, _server) = steam_with(MockResponse::status(403, "<html>Forbidden</html>")).await;
+
+    let error = steam.get_server_info().await.unwrap_err();
+    assert!(matches!(
+        error,
+        SteamError::Unauthorized {
+            status: StatusCode::FORBIDDEN,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+pub async fn rate_limited() {
+    let (steam, _server) =
+        steam_with(MockResponse::status(429, "").header("Retry-After", "30")).await;
+
+    let error = steam.get_server_info().await.unwrap_err();
+    assert!(matches!(
+        error,
+        SteamError::RateLimited { retry_after: Some(delay) } if delay == Duration::from_secs(30)
+    ));
+}
+
+#[tokio::test]
+pub async fn not_found() {
+    let (steam, _server) = steam_with(MockResponse::status(404, "")).await;
+
+    let error = steam.get_server_info().await.unwrap_err();
+    assert!(matches!(error, SteamError::NotFound { .. }));
+}
+
+#[tokio::test]
+pub async fn unexpected_status() {
+    let (steam, _server) = steam_with(MockResponse::status(500, "oops")).await;
+
+    let error = steam.get_server_info().await.unwrap_err();
+    match error {
+        SteamError::Status { status, body } => {
+            assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+            assert_eq!(body, "oops");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+pub async fn decode_error_reports_path_and_body() {
+    let body = r#"{"servertime":"soon","servertimestring":""}"#;
+    let (steam, _server) = steam_with(MockResponse::ok(body)).await;
+
+    let error = steam.get_server_info().await.unwrap_err();
+    match error {
+        SteamError::Decode {
+            path,
+            body: received,
+            ..
+        } => {
+            assert_eq!(path, "servertime");
+            assert_eq!(received, body);
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+pub async fn steam_reported_error() {
+    let (steam, _server) = steam_with(MockResponse::status(
+        400,
+        r#"{"playerstats":{"error":"Requested app has no stats","success":false}}"#,
+    ))
+    .await;
+
+    let error = steam
+        .get_player_achievements(EXAMPLE_STEAM_ID, AppId(1))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        SteamError::Steam { message } if message == "Requested app has no stats"
+    ));
+}
+
+#[tokio::test]
+pub async fn private_profile() {
+    let (steam, _server) = steam_with(MockResponse::status(
+        403,
+        r#"{"playerstats":{"error":"Profile is not public","success":false}}"#,
+    ))
+    .await;
+
+    let error = steam
+        .get_player_achievements(EXAMPLE_STEAM_ID, AppId(440))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, SteamError::PrivateProfile));
+
+    let (steam, _server) = steam_with(MockResponse::status(401, "<html>Unauthorized</html>")).await;
+
+    let error = steam.get_friend_list(EXAMPLE_STEAM_ID).await.unwrap_err();
+    assert!(matches!(error, SteamError::PrivateProfile));
+}
+
+#[tokio::test]
+pub async fn network_error() {
+    let steam = Steam::builder()
+        .base_url("http://127.0.0.1:1")
+        .retry_policy(RetryPolicy::none())
+        .build();
+
+    let error = steam.get_server_info().await.unwrap_err();
+    assert!(matches!(error, SteamError::Network(_)));
+}
+
+#[test]
+pub fn legacy_errors_convert() {
+    let error: SteamError = SteamUserError::GetFriendList("failed".to_string()).into();
+    assert!(matches!(error, SteamError::Other(message) if message == "failed"));
+}
@@ -0,0 +1,6 @@
+//! # Implements the `IStoreService` interface
+
+const INTERFACE: &str = "IStoreService";
+
+pub mod store_service_get_app_list;
+pub mod set_tags;
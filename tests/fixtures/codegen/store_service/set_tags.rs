@@ -0,0 +1,46 @@
+//! Implements the `SetTags` endpoint
+//!
+//! Generated from `GetSupportedAPIList`, replace the fields of [`SetTagsResponse`] with typed ones.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    errors::SteamError,
+    macros::do_http,
+    query::Query,
+    steam_id::SteamId,
+    Steam,
+};
+
+use super::INTERFACE;
+
+const ENDPOINT: &str = "SetTags";
+const VERSION: &str = "1";
+
+/// The response of `SetTags`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SetTagsResponse {
+    /// The fields returned by Steam.
+    #[serde(flatten)]
+    pub fields: Map<String, Value>,
+}
+
+impl Steam {
+    /// Calls the `SetTags` method of `IStoreService`.
+    ///
+    /// # Arguments
+    ///
+    /// * `steamid` - 
+    /// * `tags` - 
+    /// * `r#type` - 
+    pub async fn set_tags(&self, steamid: SteamId, tags: &[&str], r#type: &str) -> Result<SetTagsResponse, SteamError> {
+        let url = format!("{}/{}/{}/v{}/", self.base_url, INTERFACE, ENDPOINT, VERSION);
+        let query = Query::new()
+            .param("steamid", steamid)
+            .array("tags", tags)
+            .param("type", r#type);
+        let response = do_http!(self, POST url, SetTagsResponse, query);
+        Ok(response)
+    }
+}
@@ -0,0 +1,52 @@
+//! Implements the `GetAppList` endpoint
+//!
+//! Generated from `GetSupportedAPIList`, replace the fields of [`StoreServiceGetAppListResponse`] with typed ones.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    errors::SteamError,
+    macros::{do_http, EndPoint},
+    query::Query,
+};
+
+use super::INTERFACE;
+
+const ENDPOINT: &str = "GetAppList";
+const VERSION: &str = "2";
+
+/// The response of `GetAppList`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StoreServiceGetAppListResponse {
+    /// The fields returned by Steam.
+    #[serde(flatten)]
+    pub fields: Map<String, Value>,
+}
+
+EndPoint!(
+    /// Calls the `GetAppList` method of `IStoreService`.
+    store_service_get_app_list -> StoreServiceGetAppList<'a> => StoreServiceGetAppListResponse {
+        required {
+        }
+        optional {
+            /// Return only items modified since this date.
+            if_modified_since: u32,
+        }
+    }
+);
+
+impl StoreServiceGetAppList<'_> {
+    /// Sends the request.
+    pub async fn send(self) -> Result<StoreServiceGetAppListResponse, SteamError> {
+        let url = format!(
+            "{}/{}/{}/v{}/",
+            self.steam.base_url, INTERFACE, ENDPOINT, VERSION
+        );
+        let query = Query::new()
+            .param("key", &self.steam.api_key)
+            .optional("if_modified_since", self.if_modified_since);
+        let response = do_http!(self.steam, url, StoreServiceGetAppListResponse, query);
+        Ok(response)
+    }
+}
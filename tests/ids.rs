@@ -0,0 +1,62 @@
+use steam_rs::{
+    ids::{AppId, AssetId, PublishedFileId, TradeOfferId},
+    Steam,
+};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+#[test]
+pub fn deserializes_numbers_and_strings() {
+    let apps: Vec<AppId> = serde_json::from_str(r#"[440, "440"]"#).unwrap();
+    assert_eq!(apps, [AppId(440), AppId(440)]);
+
+    let assets: Vec<AssetId> = serde_json::from_str(r#"[12345678901, "12345678901"]"#).unwrap();
+    assert_eq!(assets, [AssetId(12345678901), AssetId(12345678901)]);
+}
+
+#[test]
+pub fn serializes_like_steam() {
+    assert_eq!(serde_json::to_string(&AppId(440)).unwrap(), "440");
+    assert_eq!(
+        serde_json::to_string(&TradeOfferId(6271878669)).unwrap(),
+        r#""6271878669""#
+    );
+}
+
+#[test]
+pub fn rejects_malformed_ids() {
+    for json in [r#""tf2""#, "-1", "4294967296", "null"] {
+        assert!(
+            serde_json::from_str::<AppId>(json).is_err(),
+            "{json} was accepted"
+        );
+    }
+    assert_eq!("2007484441".parse(), Ok(PublishedFileId(2007484441)));
+    assert!("".parse::<PublishedFileId>().is_err());
+}
+
+#[tokio::test]
+pub async fn responses_use_ids() {
+    let server = MockServer::start(vec![MockResponse::ok(
+        r#"{"response":{"result":1,"resultcount":2,"publishedfiledetails":[{"publishedfileid":"2007484441","result":1},{"publishedfileid":404,"result":9}]}}"#,
+    )])
+    .await;
+    let steam = Steam::builder().base_url(&server.url).build();
+
+    let response = steam
+        .get_published_file(&[PublishedFileId(2007484441), PublishedFileId(404)])
+        .await
+        .unwrap();
+
+    let ids: Vec<PublishedFileId> = response
+        .published_file_details
+        .iter()
+        .map(|details| details.published_file_id)
+        .collect();
+    assert_eq!(ids, [PublishedFileId(2007484441), PublishedFileId(404)]);
+    assert!(server.requests()[0]
+        .body
+        .contains("publishedfileids%5B0%5D=2007484441"));
+}
@@ -1,4 +1,4 @@
-use steam_rs::{steam_id::SteamId, Steam};
+use steam_rs::{ids::AppId, steam_id::SteamId, Steam};
 mod common;
 
 const EXAMPLE_STEAM_ID: SteamId = SteamId(76561197960434622); // Al Farnsworth
@@ -17,7 +17,7 @@ pub async fn get_community_badge_progress() {
     println!(
         "{:?}",
         steam
-            .get_community_badge_progress(EXAMPLE_STEAM_ID, None)
+            .get_community_badge_progress(EXAMPLE_STEAM_ID)
             .await
             .unwrap()
     );
@@ -29,16 +29,13 @@ pub async fn get_owned_games() {
     println!(
         "{:?}",
         steam
-            .get_owned_games(
-                EXAMPLE_STEAM_ID,
-                true,
-                true,
-                440,
-                true,
-                None,
-                "english",
-                true
-            )
+            .get_owned_games(EXAMPLE_STEAM_ID)
+            .include_appinfo(true)
+            .include_played_free_games(true)
+            .appids_filter(&[AppId(440)])
+            .include_free_sub(true)
+            .language("english")
+            .include_extended_appinfo(true)
             .await
             .unwrap()
     );
@@ -50,7 +47,8 @@ pub async fn get_recently_played_games() {
     println!(
         "{:?}",
         steam
-            .get_recently_played_games(EXAMPLE_STEAM_ID, Some(2))
+            .get_recently_played_games(EXAMPLE_STEAM_ID)
+            .count(2)
             .await
             .unwrap()
     );
@@ -62,7 +60,7 @@ pub async fn get_player_achievements() {
     println!(
         "{:?}",
         steam
-            .get_player_achievements(EXAMPLE_STEAM_ID, 440, None)
+            .get_player_achievements(EXAMPLE_STEAM_ID, AppId(440))
             .await
             .unwrap()
     );
@@ -74,7 +72,7 @@ pub async fn get_user_stats_for_game() {
     println!(
         "{:?}",
         steam
-            .get_user_stats_for_game(EXAMPLE_STEAM_ID, 440)
+            .get_user_stats_for_game(EXAMPLE_STEAM_ID, AppId(440))
             .await
             .unwrap()
     );
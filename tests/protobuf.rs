@@ -0,0 +1,105 @@
+#![cfg(feature = "protobuf")]
+
+use prost::Message;
+use steam_rs::{
+    errors::SteamError,
+    protobuf::{
+        player_service::{GetOwnedGamesRequest, GetSteamLevelRequest},
+        published_file_service::{PublishedFileDetails, QueryFilesRequest, QueryFilesResponse},
+    },
+    Steam,
+};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+#[tokio::test]
+pub async fn sends_and_decodes_protobuf() {
+    // `player_level: 42`
+    let server = MockServer::start(vec![MockResponse::ok("\u{8}*")]).await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    let response = steam
+        .call_protobuf(&GetSteamLevelRequest { steamid: Some(1) })
+        .await
+        .unwrap();
+
+    assert_eq!(response.player_level, Some(42));
+    assert_eq!(
+        server.requests()[0].target,
+        "/IPlayerService/GetSteamLevel/v1/?key=key&format=protobuf_raw&input_protobuf_encoded=CAE%3D"
+    );
+}
+
+#[tokio::test]
+pub async fn encodes_repeated_fields() {
+    let server = MockServer::start(vec![MockResponse::ok("")]).await;
+    let steam = Steam::builder().base_url(&server.url).build();
+
+    let request = GetOwnedGamesRequest {
+        steamid: Some(1),
+        appids_filter: vec![1, 2],
+        ..Default::default()
+    };
+    let response = steam.call_protobuf(&request).await.unwrap();
+
+    assert!(response.games.is_empty());
+    // steamid = 1, appids_filter = 1, appids_filter = 2
+    assert_eq!(
+        server.requests()[0].target,
+        "/IPlayerService/GetOwnedGames/v1/?format=protobuf_raw&input_protobuf_encoded=CAEgASAC"
+    );
+}
+
+#[tokio::test]
+pub async fn reports_invalid_messages() {
+    let server = MockServer::start(vec![MockResponse::ok("\u{8}")]).await;
+    let steam = Steam::builder().base_url(&server.url).build();
+
+    let error = steam
+        .call_protobuf(&GetSteamLevelRequest { steamid: Some(1) })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SteamError::Decode { .. }));
+}
+
+#[tokio::test]
+pub async fn round_trips_query_files() {
+    let expected = QueryFilesResponse {
+        total: Some(1),
+        publishedfiledetails: vec![PublishedFileDetails {
+            result: Some(1),
+            publishedfileid: Some(5),
+            consumer_appid: Some(40),
+            filename: Some("hats.vpk".to_string()),
+            file_url: Some("https://example.com/hats.vpk".to_string()),
+            ..Default::default()
+        }],
+        next_cursor: Some("AoJ".to_string()),
+    };
+    // The mock server only serves text, so the message keeps to fields below 16 and values below 128.
+    let body = String::from_utf8(expected.encode_to_vec()).unwrap();
+    let server = MockServer::start(vec![MockResponse::ok(&body)]).await;
+    let steam = Steam::builder().base_url(&server.url).build();
+
+    let request = QueryFilesRequest {
+        query_type: Some(0),
+        appid: Some(40),
+        requiredtags: vec!["Hat".to_string(), "Map".to_string()],
+        cursor: Some("*".to_string()),
+        ..Default::default()
+    };
+    let response = steam.call_protobuf(&request).await.unwrap();
+
+    assert_eq!(response, expected);
+    // query_type = 0, appid = 40, requiredtags = "Hat", requiredtags = "Map", cursor = "*"
+    assert_eq!(
+        server.requests()[0].target,
+        "/IPublishedFileService/QueryFiles/v1/?format=protobuf_raw&input_protobuf_encoded=CAAoKDIDSGF0MgNNYXC6AgEq"
+    );
+}
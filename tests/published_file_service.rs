@@ -1,4 +1,5 @@
 use steam_rs::{
+    ids::AppId,
     published_file_service::query_files::{
         PublishedFileInfoMatchingFileType, PublishedFileQueryType,
     },
@@ -7,44 +8,32 @@
 
 mod common;
 
-const EXAMPLE_APP_ID: u32 = 440; // Team Fortress 2
+const EXAMPLE_APP_ID: AppId = AppId(440); // Team Fortress 2
 
 #[tokio::test]
 pub async fn query_files() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
     let query = steam
-        .query_files(
-            PublishedFileQueryType::RankedByVote,
-            0,
-            "*",
-            Some(5),
-            EXAMPLE_APP_ID,
-            EXAMPLE_APP_ID,
-            "",
-            "",
-            None,
-            "",
-            "",
-            "",
-            PublishedFileInfoMatchingFileType::Items,
-            0,
-            7,
-            false,
-            None,
-            None,
-            "",
-            false,
-            false,
-            true,
-            true,
-            true,
-            true,
-            true,
-            true,
-            true,
-            Some(true),
-            10,
-        )
+        .query_files(PublishedFileQueryType::RankedByVote)
+        .page(0)
+        .cursor("*")
+        .numperpage(5)
+        .creator_app_id(EXAMPLE_APP_ID)
+        .app_id(EXAMPLE_APP_ID)
+        .file_type(PublishedFileInfoMatchingFileType::Items)
+        .days(7)
+        .include_recent_votes_only(false)
+        .total_only(false)
+        .ids_only(false)
+        .return_vote_data(true)
+        .return_tags(true)
+        .return_kv_tags(true)
+        .return_previews(true)
+        .return_children(true)
+        .return_short_description(true)
+        .return_for_sale_data(true)
+        .return_metadata(true)
+        .return_playtime_stats(10)
         .await
         .unwrap();
     println!("{:?}", query);
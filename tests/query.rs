@@ -0,0 +1,97 @@
+use steam_rs::{ids::AppId, query::Query, steam_id::SteamId, Steam};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+#[test]
+pub fn encodes_reserved_characters() {
+    let query = Query::new()
+        .param("vanityurl", "a b&c#d=e")
+        .param("l", "français");
+
+    assert_eq!(query.encode(), "vanityurl=a+b%26c%23d%3De&l=fran%C3%A7ais");
+}
+
+#[test]
+pub fn parses_encoded_queries() {
+    let query = Query::new()
+        .param("vanityurl", "a b&c#d=e")
+        .param("l", "français")
+        .array("ids", [1, 2]);
+
+    assert_eq!(Query::parse(&query.encode()), query);
+}
+
+#[test]
+pub fn skips_missing_optionals() {
+    let query = Query::new()
+        .optional("count", Some(3))
+        .optional::<u32>("maxlength", None);
+
+    assert_eq!(query.pairs(), [("count".to_string(), "3".to_string())]);
+}
+
+#[test]
+pub fn builds_arrays_and_lists() {
+    let query = Query::new()
+        .array("publishedfileids", [1u64, 2])
+        .list("steamids", [SteamId(1), SteamId(2)]);
+
+    assert_eq!(
+        query.encode(),
+        "publishedfileids%5B0%5D=1&publishedfileids%5B1%5D=2&steamids=1%2C2"
+    );
+}
+
+#[test]
+pub fn encodes_input_json() {
+    let query = Query::new()
+        .param("key", "key")
+        .input_json(serde_json::json!({ "steamid": "1", "appids_filter": [440] }));
+
+    assert_eq!(
+        query.encode(),
+        "key=key&input_json=%7B%22appids_filter%22%3A%5B440%5D%2C%22steamid%22%3A%221%22%7D"
+    );
+}
+
+#[tokio::test]
+pub async fn requests_encode_values() {
+    let server = MockServer::start(vec![MockResponse::ok(
+        r#"{"response":{"success":42,"message":"No match"}}"#,
+    )])
+    .await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    let _ = steam
+        .resolve_vanity_url("gabe newell&x#y")
+        .url_type(1)
+        .await;
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/ISteamUser/ResolveVanityURL/v1/?key=key&vanityurl=gabe+newell%26x%23y&url_type=1"
+    );
+}
+
+#[tokio::test]
+pub async fn optional_strings_are_not_quoted() {
+    let server = MockServer::start(vec![MockResponse::ok(r#"{"result":{}}"#)]).await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    let _ = steam.get_asset_prices(AppId(440)).language("english").await;
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/ISteamEconomy/GetAssetPrices/v1/?key=key&appid=440&language=english"
+    );
+}
@@ -0,0 +1,126 @@
+use std::time::{Duration, Instant};
+
+use steam_rs::{
+    errors::SteamError,
+    rate_limit::{Quota, QuotaExceeded, QuotaSnapshot, RateLimit, RateLimiter},
+    retry::RetryPolicy,
+    Steam,
+};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+const SERVER_INFO: &str =
+    r#"{"servertime":1700000000,"servertimestring":"Tue Nov 14 22:13:20 2023"}"#;
+
+#[tokio::test]
+pub async fn quota_stops_requests_before_sending() {
+    let server = MockServer::start(vec![MockResponse::ok(SERVER_INFO)]).await;
+    let quota = Quota::daily(2);
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .quota(quota.clone())
+        .build();
+
+    assert!(steam.get_server_info().await.is_ok());
+    assert!(steam.get_server_info().await.is_ok());
+    assert!(matches!(
+        steam.get_server_info().await.unwrap_err(),
+        SteamError::QuotaExceeded(QuotaExceeded { limit: 2 })
+    ));
+
+    assert_eq!(server.requests().len(), 2);
+    assert_eq!(quota.used(), 2);
+    assert_eq!(quota.remaining(), 0);
+    assert_eq!(steam.quota().unwrap().used(), 2);
+}
+
+#[tokio::test]
+pub async fn quota_counts_retries() {
+    let server = MockServer::start(vec![
+        MockResponse::status(503, ""),
+        MockResponse::ok(SERVER_INFO),
+    ])
+    .await;
+    let quota = Quota::daily(10);
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(
+            RetryPolicy::default()
+                .base_delay(Duration::from_millis(1))
+                .jitter(false),
+        )
+        .quota(quota.clone())
+        .build();
+
+    assert!(steam.get_server_info().await.is_ok());
+    assert_eq!(quota.used(), 2);
+}
+
+#[tokio::test]
+pub async fn quota_snapshot_round_trips() {
+    let quota = Quota::daily(100);
+    let today = quota.snapshot().day;
+    let quota = Quota::restore(
+        100,
+        QuotaSnapshot {
+            day: today,
+            used: 40,
+        },
+    );
+    assert_eq!(quota.remaining(), 60);
+
+    let json = serde_json::to_string(&quota.snapshot()).unwrap();
+    let snapshot: QuotaSnapshot = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        snapshot,
+        QuotaSnapshot {
+            day: today,
+            used: 40
+        }
+    );
+
+    // Counts from an earlier day no longer apply.
+    let quota = Quota::restore(
+        100,
+        QuotaSnapshot {
+            day: today - 1,
+            used: 100,
+        },
+    );
+    assert_eq!(quota.used(), 0);
+}
+
+#[tokio::test]
+pub async fn rate_limiter_spaces_out_requests() {
+    let server = MockServer::start(vec![MockResponse::ok(SERVER_INFO)]).await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .rate_limiter(RateLimiter::new(RateLimit::new(
+            1,
+            Duration::from_millis(200),
+        )))
+        .build();
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        steam.get_server_info().await.unwrap();
+    }
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
+
+#[tokio::test]
+pub async fn rate_limiter_applies_per_interface() {
+    let server = MockServer::start(vec![MockResponse::ok(SERVER_INFO)]).await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .rate_limiter(RateLimiter::unlimited().interface("ISteamUser", RateLimit::per_minute(1)))
+        .build();
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        steam.get_server_info().await.unwrap();
+    }
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
@@ -0,0 +1,114 @@
+use reqwest::Method;
+use serde::Deserialize;
+use steam_rs::{errors::SteamError, query::Query, Steam};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+#[tokio::test]
+pub async fn call_raw_adds_the_key() {
+    let server = MockServer::start(vec![MockResponse::ok(
+        r#"{"players":[{"SteamId":"76561197960287930","VACBanned":false}]}"#,
+    )])
+    .await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    let bans = steam
+        .call_raw(
+            "ISteamUser",
+            "GetPlayerBans",
+            1,
+            Method::GET,
+            Query::new().param("steamids", 76561197960287930u64),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(bans["players"][0]["VACBanned"], false);
+    assert_eq!(
+        server.requests()[0].target,
+        "/ISteamUser/GetPlayerBans/v1/?key=key&steamids=76561197960287930"
+    );
+}
+
+#[tokio::test]
+pub async fn call_decodes_into_a_type() {
+    #[derive(Deserialize)]
+    struct Response {
+        response: Players,
+    }
+
+    #[derive(Deserialize)]
+    struct Players {
+        player_count: u32,
+    }
+
+    let server = MockServer::start(vec![MockResponse::ok(
+        r#"{"response":{"player_count":42,"result":1}}"#,
+    )])
+    .await;
+    let steam = Steam::builder().base_url(&server.url).build();
+
+    let response: Response = steam
+        .call(
+            "ISteamUserStats",
+            "GetNumberOfCurrentPlayers",
+            1,
+            Method::GET,
+            Query::new().param("appid", 440),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.response.player_count, 42);
+    assert_eq!(
+        server.requests()[0].target,
+        "/ISteamUserStats/GetNumberOfCurrentPlayers/v1/?appid=440"
+    );
+}
+
+#[tokio::test]
+pub async fn call_raw_posts_params_in_the_body() {
+    let server = MockServer::start(vec![MockResponse::ok(r#"{"response":{}}"#)]).await;
+    let steam = Steam::builder()
+        .api_key("key")
+        .base_url(&server.url)
+        .build();
+
+    steam
+        .call_raw(
+            "IPlayerService",
+            "RecordOfflinePlaytime",
+            1,
+            Method::POST,
+            Query::new().param("steamid", 1),
+        )
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.target, "/IPlayerService/RecordOfflinePlaytime/v1/");
+    assert_eq!(request.body, "key=key&steamid=1");
+    assert!(request.headers.contains(&(
+        "content-type".to_string(),
+        "application/x-www-form-urlencoded".to_string()
+    )));
+}
+
+#[tokio::test]
+pub async fn call_raw_reports_errors() {
+    let server = MockServer::start(vec![MockResponse::status(404, "Not Found")]).await;
+    let steam = Steam::builder().base_url(&server.url).build();
+
+    let error = steam
+        .call_raw("ISteamNew", "Missing", 1, Method::GET, Query::new())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SteamError::NotFound { .. }));
+}
@@ -0,0 +1,170 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use steam_rs::{ids::PublishedFileId, retry::RetryPolicy, Steam};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+const SERVER_INFO: &str =
+    r#"{"servertime":1700000000,"servertimestring":"Tue Nov 14 22:13:20 2023"}"#;
+const COLLECTION_DETAILS: &str =
+    r#"{"response":{"result":1,"resultcount":0,"collectiondetails":[]}}"#;
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy::default()
+        .base_delay(Duration::from_millis(1))
+        .jitter(false)
+}
+
+#[tokio::test]
+pub async fn retries_server_errors() {
+    let server = MockServer::start(vec![
+        MockResponse::status(503, ""),
+        MockResponse::status(500, ""),
+        MockResponse::ok(SERVER_INFO),
+    ])
+    .await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(fast_policy())
+        .build();
+
+    assert!(steam.get_server_info().await.is_ok());
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+pub async fn honours_retry_after() {
+    let server = MockServer::start(vec![
+        MockResponse::status(429, "").header("Retry-After", "1"),
+        MockResponse::ok(SERVER_INFO),
+    ])
+    .await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(fast_policy())
+        .build();
+
+    let start = std::time::Instant::now();
+    assert!(steam.get_server_info().await.is_ok());
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+pub async fn honours_retry_after_dates() {
+    // A date far ahead waits for `max_delay` instead of the 1ms backoff.
+    let server = MockServer::start(vec![
+        MockResponse::status(429, "").header("Retry-After", "Fri, 31 Dec 9999 23:59:59 GMT"),
+        MockResponse::ok(SERVER_INFO),
+    ])
+    .await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(fast_policy().max_delay(Duration::from_secs(1)))
+        .build();
+
+    let start = std::time::Instant::now();
+    assert!(steam.get_server_info().await.is_ok());
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    // A date in the past retries right away instead of after the 10s backoff.
+    let server = MockServer::start(vec![
+        MockResponse::status(503, "").header("Retry-After", "Sunday, 06-Nov-94 08:49:37 GMT"),
+        MockResponse::ok(SERVER_INFO),
+    ])
+    .await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(fast_policy().base_delay(Duration::from_secs(10)))
+        .build();
+
+    let start = std::time::Instant::now();
+    assert!(steam.get_server_info().await.is_ok());
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    // A two-digit year less than 50 years ahead is in the future.
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let year = 1970 + now.as_secs() / 31_556_952 + 10;
+    let date = format!("Friday, 01-Jan-{:02} 00:00:00 GMT", year % 100);
+    let server = MockServer::start(vec![
+        MockResponse::status(429, "").header("Retry-After", &date),
+        MockResponse::ok(SERVER_INFO),
+    ])
+    .await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(fast_policy().max_delay(Duration::from_secs(1)))
+        .build();
+
+    let start = std::time::Instant::now();
+    assert!(steam.get_server_info().await.is_ok());
+    assert!(start.elapsed() >= Duration::from_secs(1), "{date}");
+}
+
+#[tokio::test]
+pub async fn stops_after_max_attempts() {
+    let server = MockServer::start(vec![MockResponse::status(503, "")]).await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(fast_policy().max_attempts(2))
+        .build();
+
+    assert!(steam.get_server_info().await.is_err());
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+pub async fn does_not_retry_client_errors() {
+    let server = MockServer::start(vec![MockResponse::status(403, "")]).await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(fast_policy())
+        .build();
+
+    assert!(steam.get_server_info().await.is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+pub async fn does_not_retry_when_disabled() {
+    let server = MockServer::start(vec![MockResponse::status(503, "")]).await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(RetryPolicy::none())
+        .build();
+
+    assert!(steam.get_server_info().await.is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+pub async fn post_retries_are_opt_in() {
+    let server = MockServer::start(vec![
+        MockResponse::status(503, ""),
+        MockResponse::ok(COLLECTION_DETAILS),
+    ])
+    .await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(fast_policy())
+        .build();
+
+    assert!(steam
+        .get_collection_details(&[PublishedFileId(1)])
+        .await
+        .is_err());
+    assert_eq!(server.requests().len(), 1);
+
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(fast_policy().retry_post(true))
+        .build();
+
+    assert!(steam
+        .get_collection_details(&[PublishedFileId(1)])
+        .await
+        .is_ok());
+    assert_eq!(server.requests().len(), 2);
+}
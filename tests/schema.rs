@@ -0,0 +1,197 @@
+use std::sync::{Arc, Mutex};
+
+use serde_json::json;
+use steam_rs::{
+    errors::SteamError,
+    ids::AppId,
+    schema::{Drift, SchemaMode},
+    steam_id::SteamId,
+    Steam,
+};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+const EXAMPLE_STEAM_ID: SteamId = SteamId(76561197960434622);
+
+// `EconomyBan` is missing, `Foo` is not modelled.
+const DRIFTED_BANS: &str = r#"{"players":[{"SteamId":"76561197960434622","CommunityBanned":false,"VACBanned":false,"NumberOfVACBans":0,"DaysSinceLastBan":0,"NumberOfGameBans":0,"Foo":{"bar":1}}]}"#;
+
+async fn steam_with(mode: SchemaMode, body: &str) -> (Steam, MockServer, Arc<Mutex<Vec<Drift>>>) {
+    let server = MockServer::start(vec![MockResponse::ok(body)]).await;
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let recorded = reports.clone();
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .schema_mode(mode)
+        .on_schema_drift(move |drift| recorded.lock().unwrap().push(drift.clone()))
+        .build();
+    (steam, server, reports)
+}
+
+#[tokio::test]
+pub async fn default_mode_fails_on_missing_fields() {
+    let (steam, _server, reports) = steam_with(SchemaMode::Default, DRIFTED_BANS).await;
+
+    let error = steam
+        .get_player_bans(vec![EXAMPLE_STEAM_ID])
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SteamError::Decode { path, .. } if path == "players[0]"));
+    assert!(reports.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+pub async fn lenient_mode_fills_missing_fields() {
+    let (steam, _server, reports) = steam_with(SchemaMode::Lenient, DRIFTED_BANS).await;
+
+    let players = steam.get_player_bans(vec![EXAMPLE_STEAM_ID]).await.unwrap();
+    assert_eq!(players[0].economy_ban, "");
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].endpoint, "ISteamUser/GetPlayerBans/v1");
+    assert_eq!(reports[0].missing, ["players[0].EconomyBan"]);
+    assert_eq!(reports[0].extra["players[0].Foo"], json!({ "bar": 1 }));
+}
+
+#[tokio::test]
+pub async fn lenient_mode_fills_missing_structures() {
+    let (steam, _server, reports) = steam_with(
+        SchemaMode::Lenient,
+        r#"{"response":{"games":[{"appid":440}]}}"#,
+    )
+    .await;
+
+    let games = steam
+        .get_recently_played_games(EXAMPLE_STEAM_ID)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(games.total_count, 0);
+    assert_eq!(games.games[0].playtime_forever, 0);
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(
+        reports[0].missing,
+        ["response.games[0].playtime_forever", "response.total_count"]
+    );
+}
+
+#[tokio::test]
+pub async fn lenient_mode_fills_missing_objects() {
+    let (steam, _server, reports) = steam_with(SchemaMode::Lenient, "{}").await;
+
+    let games = steam
+        .get_recently_played_games(EXAMPLE_STEAM_ID)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(games.total_count, 0);
+    assert!(games.games.is_empty());
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(
+        reports[0].missing,
+        ["response", "response.total_count", "response.games"]
+    );
+}
+
+#[tokio::test]
+pub async fn lenient_mode_fills_every_element_of_large_arrays() {
+    // Far more elements than lenient decoding would repair one field at a time.
+    let players: Vec<_> = (0..1_000)
+        .map(|index| {
+            json!({
+                "SteamId": (EXAMPLE_STEAM_ID.0 + index).to_string(),
+                "CommunityBanned": false,
+                "VACBanned": false,
+                "NumberOfVACBans": 0,
+                "DaysSinceLastBan": 0,
+            })
+        })
+        .collect();
+    let body = json!({ "players": players }).to_string();
+    let (steam, _server, reports) = steam_with(SchemaMode::Lenient, &body).await;
+
+    let players = steam.get_player_bans(vec![EXAMPLE_STEAM_ID]).await.unwrap();
+    assert_eq!(players.len(), 1_000);
+    assert!(players
+        .iter()
+        .all(|player| player.economy_ban.is_empty() && player.number_of_game_bans == 0));
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports[0].missing.len(), 2_000);
+    assert!(reports[0]
+        .missing
+        .contains(&"players[999].NumberOfGameBans".to_string()));
+}
+
+#[tokio::test]
+pub async fn strict_mode_fails_on_unknown_fields() {
+    let (steam, _server, reports) = steam_with(
+        SchemaMode::Strict,
+        r#"{"servertime":1700000000,"servertimestring":"Tue Nov 14 22:13:20 2023","servertimezone":"UTC"}"#,
+    )
+    .await;
+
+    let error = steam.get_server_info().await.unwrap_err();
+
+    let SteamError::SchemaDrift(drift) = error else {
+        panic!("expected schema drift, got {error:?}");
+    };
+    assert_eq!(drift.extra["servertimezone"], "UTC");
+    assert_eq!(
+        drift.to_string(),
+        "Schema drift in ISteamWebAPIUtil/GetServerInfo/v1, unknown fields: servertimezone"
+    );
+    assert_eq!(reports.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+pub async fn strict_mode_applies_to_global_stats() {
+    let (steam, _server, reports) = steam_with(
+        SchemaMode::Strict,
+        r#"{"response":{"globalstats":{"kills":{"total":"12"}},"result":1,"timestamp":1700000000}}"#,
+    )
+    .await;
+
+    let error = steam
+        .get_global_stats_for_game(AppId(440), 1, vec!["kills".to_string()])
+        .await
+        .unwrap_err();
+
+    let SteamError::SchemaDrift(drift) = error else {
+        panic!("expected schema drift, got {error:?}");
+    };
+    assert_eq!(drift.endpoint, "ISteamUserStats/GetGlobalStatsForGame/v1");
+    assert_eq!(drift.extra["response.timestamp"], 1700000000);
+    assert_eq!(reports.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+pub async fn strict_mode_accepts_matching_responses() {
+    let (steam, _server, reports) = steam_with(
+        SchemaMode::Strict,
+        r#"{"servertime":1700000000,"servertimestring":"Tue Nov 14 22:13:20 2023"}"#,
+    )
+    .await;
+
+    assert!(steam.get_server_info().await.is_ok());
+    assert!(reports.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+pub async fn lenient_mode_fills_missing_steam_ids() {
+    let (steam, _server, reports) = steam_with(
+        SchemaMode::Lenient,
+        r#"{"players":[{"CommunityBanned":false,"VACBanned":false,"NumberOfVACBans":0,"DaysSinceLastBan":0,"NumberOfGameBans":0,"EconomyBan":"none"}]}"#,
+    )
+    .await;
+
+    let players = steam.get_player_bans(vec![EXAMPLE_STEAM_ID]).await.unwrap();
+    assert_eq!(players[0].steam_id, SteamId(0));
+    assert_eq!(reports.lock().unwrap()[0].missing, ["players[0].SteamId"]);
+}
@@ -0,0 +1,85 @@
+#[cfg(not(feature = "partner"))]
+use steam_rs::PUBLIC_BASE_URL;
+use steam_rs::{Steam, PARTNER_BASE_URL};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+const SERVER_INFO: &str =
+    r#"{"servertime":1700000000,"servertimestring":"Tue Nov 14 22:13:20 2023"}"#;
+
+#[tokio::test]
+pub async fn default_urls() {
+    let steam = Steam::new("key");
+    #[cfg(not(feature = "partner"))]
+    assert_eq!(steam.base_url(), PUBLIC_BASE_URL);
+    assert_eq!(steam.partner_url(), PARTNER_BASE_URL);
+}
+
+#[tokio::test]
+pub async fn builder_trims_trailing_slash() {
+    let steam = Steam::builder()
+        .base_url("http://127.0.0.1:8080/")
+        .partner_url("http://127.0.0.1:8081/")
+        .build();
+    assert_eq!(steam.base_url(), "http://127.0.0.1:8080");
+    assert_eq!(steam.partner_url(), "http://127.0.0.1:8081");
+}
+
+#[tokio::test]
+pub async fn requests_use_base_url() {
+    let server = MockServer::start(vec![MockResponse::ok(SERVER_INFO)]).await;
+    let steam = Steam::builder().base_url(&server.url).build();
+
+    let info = steam.get_server_info().await.unwrap();
+    assert_eq!(info.server_time, 1700000000);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].target, "/ISteamWebAPIUtil/GetServerInfo/v1/");
+}
+
+#[tokio::test]
+pub async fn publisher_requests_use_partner_url() {
+    let server = MockServer::start(vec![MockResponse::ok(
+        r#"{"response":{"sites":[],"game_names":[]}}"#,
+    )])
+    .await;
+    let steam = Steam::builder()
+        .base_url("http://127.0.0.1:1")
+        .partner_url(&server.url)
+        .build();
+
+    steam
+        .get_current_client_connections("publisher", 0)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0]
+        .target
+        .starts_with("/ISiteLicenseService/GetCurrentClientConnections/v1/"));
+}
+
+#[tokio::test]
+pub async fn requests_use_custom_client() {
+    let server = MockServer::start(vec![MockResponse::ok(SERVER_INFO)]).await;
+    let client = reqwest::Client::builder()
+        .user_agent("steam-rs-tests")
+        .build()
+        .unwrap();
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .client(client)
+        .build();
+
+    steam.get_server_info().await.unwrap();
+
+    let requests = server.requests();
+    assert!(requests[0]
+        .headers
+        .iter()
+        .any(|(name, value)| name == "user-agent" && value == "steam-rs-tests"));
+}
@@ -1,16 +1,17 @@
-use steam_rs::Steam;
+use steam_rs::{ids::AppId, Steam};
 mod common;
 
 #[tokio::test]
 pub async fn get_app_list() {
-    println!("{:?}", Steam::get_app_list().await.unwrap());
+    println!("{:?}", Steam::default().get_app_list().await.unwrap());
 }
 
 #[tokio::test]
 pub async fn get_servers_at_address() {
     println!(
         "{:?}",
-        Steam::get_servers_at_address("158.255.2.173:27016")
+        Steam::default()
+            .get_servers_at_address("158.255.2.173:27016")
             .await
             .unwrap()
     );
@@ -18,5 +19,11 @@ pub async fn get_servers_at_address() {
 
 #[tokio::test]
 pub async fn up_to_date_check() {
-    println!("{:?}", Steam::up_to_date_check(440, 8227024).await.unwrap());
+    println!(
+        "{:?}",
+        Steam::default()
+            .up_to_date_check(AppId(440), 8227024)
+            .await
+            .unwrap()
+    );
 }
@@ -1,4 +1,7 @@
-use steam_rs::Steam;
+use steam_rs::{
+    ids::{AppId, ClassId},
+    Steam,
+};
 mod common;
 
 #[tokio::test]
@@ -6,7 +9,7 @@ pub async fn get_asset_class_info() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
 
     let data = steam
-        .get_asset_class_info(440, None, 1, 211447708, None)
+        .get_asset_class_info(AppId(440), 1, ClassId(211447708))
         .await
         .unwrap();
     println!("{data:?}")
@@ -16,14 +19,10 @@ pub async fn get_asset_class_info() {
 pub async fn get_asset_prices() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
 
-    let tf2_data = steam.get_asset_prices(440, None, None).await.unwrap();
+    let tf2_data = steam.get_asset_prices(AppId(440)).await.unwrap();
     println!("{tf2_data:?}");
 
-    let cs2_data = steam
-        .get_asset_prices(730, None, None)
-        .await
-        .unwrap()
-        .assets;
+    let cs2_data = steam.get_asset_prices(AppId(730)).await.unwrap().assets;
 
     println!("{cs2_data:?}");
 }
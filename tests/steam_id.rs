@@ -1,4 +1,12 @@
-use steam_rs::steam_id::{SteamId, Universe};
+use std::str::FromStr;
+
+use steam_rs::{
+    ids::{AppId, GroupId},
+    steam_id::{
+        GameId, GameType, ResolveSteamIdError, SteamId, SteamIdError, SteamIdForm, SteamIdInput,
+        Universe,
+    },
+};
 
 mod common;
 
@@ -55,3 +63,266 @@ async fn from_account_id() {
     let sid = SteamId::from_account_id(322428629);
     assert_eq!(Ok(76561198282694357), sid.map(|s| s.0))
 }
+
+#[test]
+fn from_individual_account_id() {
+    const SID: SteamId = SteamId::from_individual_account_id(322428629);
+    assert_eq!(SID, SteamId(76561198282694357));
+    assert_eq!(
+        SteamId::from_individual_account_id(u32::MAX).get_account_id(),
+        u32::MAX
+    );
+}
+
+#[test]
+fn parses_every_form() {
+    let expected = SteamId(76561197960287930);
+
+    assert_eq!(SteamId::from_str("76561197960287930"), Ok(expected));
+    assert_eq!("STEAM_1:0:11101".parse(), Ok(expected));
+    assert_eq!(SteamId::try_from("[U:1:22202]"), Ok(expected));
+    assert_eq!(SteamId::try_from("[U:1:22202]".to_string()), Ok(expected));
+}
+
+#[test]
+fn rejects_malformed_strings() {
+    assert_eq!(
+        "gabe".parse::<SteamId>(),
+        Err(steam_rs::steam_id::SteamIdError::Invalid)
+    );
+    assert!(SteamId::try_from("").is_err());
+    assert!(SteamId::try_from("-1").is_err());
+}
+
+#[test]
+fn rejects_out_of_range_parts() {
+    assert_eq!(
+        SteamId::new_smart("[A:1:2:99999999999999999999999]"),
+        Err(SteamIdError::TooBig("Account instance".to_owned(), 0xFFFFF))
+    );
+    assert_eq!(
+        SteamId::new_smart("[A:1:2:1048576]"),
+        Err(SteamIdError::TooBig("Account instance".to_owned(), 0xFFFFF))
+    );
+    assert_eq!(
+        SteamId::new_smart("[U:1:4294967296]"),
+        Err(SteamIdError::TooBig("SteamID".to_owned(), u32::MAX as u64))
+    );
+    assert!("[A:1:2:99999999999999999999999]"
+        .parse::<SteamId>()
+        .is_err());
+    assert!(serde_json::from_str::<SteamId>(r#""[A:1:2:99999999999999999999999]""#).is_err());
+}
+
+#[test]
+fn deserializes_numbers_and_strings() {
+    let ids: Vec<SteamId> =
+        serde_json::from_str(r#"[76561197960287930, "76561197960287930", "STEAM_1:0:11101"]"#)
+            .unwrap();
+    assert_eq!(ids, [SteamId(76561197960287930); 3]);
+
+    // Serializing keeps the string form Steam sends.
+    assert_eq!(
+        serde_json::to_string(&ids[0]).unwrap(),
+        r#""76561197960287930""#
+    );
+}
+
+#[test]
+fn malformed_ids_fail_to_deserialize() {
+    for json in [r#""gabe""#, "-1", "1.5", "null"] {
+        assert!(
+            serde_json::from_str::<SteamId>(json).is_err(),
+            "{json} was accepted"
+        );
+    }
+}
+
+#[test]
+fn parses_user_input() {
+    let id = |form| SteamIdInput::Id {
+        steam_id: SteamId(76561197960287930),
+        form,
+    };
+
+    for (input, expected) in [
+        ("76561197960287930", id(SteamIdForm::SteamId64)),
+        ("STEAM_0:0:11101", id(SteamIdForm::SteamId2)),
+        ("[U:1:22202]", id(SteamIdForm::SteamId3)),
+        ("U:1:22202", id(SteamIdForm::SteamId3)),
+        (
+            "https://steamcommunity.com/profiles/76561197960287930/",
+            id(SteamIdForm::ProfileUrl),
+        ),
+        (
+            "steamcommunity.com/profiles/[U:1:22202]",
+            id(SteamIdForm::ProfileUrl),
+        ),
+        ("https://s.team/p/hj-qp", id(SteamIdForm::InviteLink)),
+        ("SUCVS-FADA", id(SteamIdForm::FriendCode)),
+        (
+            "https://steamcommunity.com/user/hj-qp/",
+            id(SteamIdForm::InviteLink),
+        ),
+        (
+            "steam://url/SteamIDPage/76561197960287930",
+            id(SteamIdForm::SteamLink),
+        ),
+        (
+            " https://www.steamcommunity.com/id/gabelogannewell/games/?tab=all ",
+            SteamIdInput::Vanity {
+                name: "gabelogannewell".to_string(),
+                form: SteamIdForm::VanityUrl,
+            },
+        ),
+        (
+            "gabelogannewell",
+            SteamIdInput::Vanity {
+                name: "gabelogannewell".to_string(),
+                form: SteamIdForm::VanityName,
+            },
+        ),
+    ] {
+        assert_eq!(SteamIdInput::parse(input).unwrap(), expected, "{input}");
+    }
+}
+
+#[test]
+fn reports_the_recognised_form() {
+    for (input, expected) in [
+        ("STEAM_0:2:11101", SteamIdForm::SteamId2),
+        ("[U:1:99999999999]", SteamIdForm::SteamId3),
+        ("[A:1:2:99999999999999999999999]", SteamIdForm::SteamId3),
+        ("A:1:2:99999999999999999999999", SteamIdForm::SteamId3),
+        ("99999999999999999999", SteamIdForm::SteamId64),
+        (
+            "https://steamcommunity.com/profiles/gabe",
+            SteamIdForm::ProfileUrl,
+        ),
+        ("https://s.team/p/xyz", SteamIdForm::InviteLink),
+        (
+            "https://steamcommunity.com/gid/76561197960287930",
+            SteamIdForm::GroupUrl,
+        ),
+    ] {
+        let error = SteamIdInput::parse(input).unwrap_err();
+        assert!(
+            matches!(error, ResolveSteamIdError::Invalid { form, .. } if form == expected),
+            "{input}: {error:?}"
+        );
+    }
+
+    let error = SteamIdInput::parse("https://store.steampowered.com/app/440").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "`https://store.steampowered.com/app/440` is not a Steam ID, profile URL or vanity name"
+    );
+}
+
+#[test]
+fn clan_ids() {
+    let valve = SteamId(103582791429521412);
+    assert_eq!(SteamId::from_clan_id(4), Ok(valve));
+    assert_eq!(SteamId::from_group_id(GroupId(4)), Ok(valve));
+    assert_eq!(SteamId::from_group_id(GroupId(valve.0)), Ok(valve));
+    assert_eq!(
+        SteamId::from_group_id(GroupId(EXAMPLE_STEAM_ID64)),
+        Err(SteamIdError::Invalid)
+    );
+
+    assert!(valve.is_clan());
+    assert_eq!(valve.get_group_id(), Some(GroupId(4)));
+    assert_eq!(valve.to_id3_string(), "[g:1:4]");
+    assert_eq!(SteamId::new_smart("[g:1:4]"), Ok(valve));
+    assert_eq!(
+        valve.to_group_url().unwrap(),
+        "https://steamcommunity.com/gid/103582791429521412"
+    );
+
+    let user = SteamId::new(EXAMPLE_STEAM_ID64);
+    assert_eq!(user.get_group_id(), None);
+    assert_eq!(user.to_group_url(), None);
+
+    for input in [
+        "https://steamcommunity.com/gid/103582791429521412",
+        "steamcommunity.com/gid/[g:1:4]/",
+    ] {
+        assert_eq!(
+            SteamIdInput::parse(input).unwrap(),
+            SteamIdInput::Id {
+                steam_id: valve,
+                form: SteamIdForm::GroupUrl
+            },
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn friend_codes() {
+    let sid = SteamId(76561197960287930);
+    assert_eq!(sid.to_friend_code(), "SUCVS-FADA");
+    assert_eq!(SteamId::from_friend_code("SUCVS-FADA"), Ok(sid));
+    assert_eq!(SteamId::from_friend_code("sucvs-fada"), Ok(sid));
+
+    let sid = SteamId::new(EXAMPLE_STEAM_ID64);
+    assert_eq!(SteamId::from_friend_code(&sid.to_friend_code()), Ok(sid));
+
+    // The last character carries part of the hash.
+    assert!(SteamId::from_friend_code("SUCVS-FADB").is_err());
+    assert!(SteamId::from_friend_code("SUCVSFADA").is_err());
+}
+
+#[test]
+fn invite_codes() {
+    let sid = SteamId(76561197960287930);
+    assert_eq!(sid.to_invite_code(), "hj-qp");
+    assert_eq!(SteamId::from_invite_code("hj-qp"), Ok(sid));
+    assert_eq!(SteamId::from_invite_code("hjqp"), Ok(sid));
+
+    let sid = SteamId::new(EXAMPLE_STEAM_ID64);
+    assert_eq!(SteamId::from_invite_code(&sid.to_invite_code()), Ok(sid));
+
+    assert!(SteamId::from_invite_code("hj-qa").is_err());
+    assert!(SteamId::from_invite_code("").is_err());
+}
+
+#[test]
+fn game_ids() {
+    let app = GameId::from_app_id(AppId(440));
+    assert_eq!(app, GameId(440));
+    assert!(app.is_app());
+    assert_eq!(app.get_mod_id(), 0);
+
+    let game_mod = GameId::from_mod(AppId(240), "cstrike");
+    assert_eq!(game_mod, GameId(11184425126080807152));
+    assert_eq!(game_mod.get_app_id(), AppId(240));
+    assert_eq!(game_mod.get_game_type(), Some(GameType::GameMod));
+    assert!(game_mod.is_mod());
+
+    let shortcut = GameId::from_shortcut("\"C:\\Games\\doom.exe\"", "Doom");
+    assert_eq!(shortcut, GameId(15663844087591600128));
+    assert_eq!(shortcut.get_app_id(), AppId(0));
+    assert_eq!(shortcut.get_mod_id(), 3647022901);
+    assert!(shortcut.is_shortcut());
+
+    assert!(GameId::from_parts(AppId(440), GameType::P2P, 1).is_p2p());
+    assert_eq!(GameId(0x7F00_0000).get_game_type(), None);
+}
+
+#[test]
+fn parses_game_ids() {
+    assert_eq!(
+        "15663844087591600128".parse(),
+        Ok(GameId(15663844087591600128))
+    );
+    assert!("doom".parse::<GameId>().is_err());
+
+    let game_id: GameId = serde_json::from_str(r#""15663844087591600128""#).unwrap();
+    assert!(game_id.is_shortcut());
+    assert_eq!(serde_json::from_str::<GameId>("440").unwrap(), GameId(440));
+    assert_eq!(
+        serde_json::to_string(&game_id).unwrap(),
+        r#""15663844087591600128""#
+    );
+}
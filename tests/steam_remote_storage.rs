@@ -1,13 +1,14 @@
-use steam_rs::Steam;
+use steam_rs::{ids::PublishedFileId, Steam};
 mod common;
 
-const EXAMPLE_COLLECTION: u64 = 532551393;
+const EXAMPLE_COLLECTION: PublishedFileId = PublishedFileId(532551393);
 
 #[tokio::test]
 pub async fn get_collection_details() {
     println!(
         "{:?}",
-        Steam::get_collection_details(&[EXAMPLE_COLLECTION])
+        Steam::default()
+            .get_collection_details(&[EXAMPLE_COLLECTION])
             .await
             .unwrap()
     );
@@ -17,7 +18,8 @@ pub async fn get_collection_details() {
 pub async fn get_published_file() {
     println!(
         "{:?}",
-        Steam::get_published_file(&[EXAMPLE_COLLECTION])
+        Steam::default()
+            .get_published_file(&[EXAMPLE_COLLECTION])
             .await
             .unwrap()
     );
@@ -1,4 +1,8 @@
-use steam_rs::{steam_id::SteamId, Steam};
+use steam_rs::{
+    steam_id::SteamId,
+    steam_user::get_player_summaries::{CommunityVisibility, PersonaState, Player, ProfileState},
+    Steam,
+};
 
 mod common;
 
@@ -13,7 +17,7 @@ pub async fn get_friend_list() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
     println!(
         "{:?}",
-        steam.get_friend_list(EXAMPLE_STEAM_ID, None).await.unwrap()
+        steam.get_friend_list(EXAMPLE_STEAM_ID).await.unwrap()
     );
 }
 
@@ -53,7 +57,27 @@ pub async fn resolve_vanity_url() {
         let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
         println!(
             "{:?}",
-            steam.resolve_vanity_url(url, Some(1)).await.unwrap()
+            steam.resolve_vanity_url(url).url_type(1).await.unwrap()
         );
     }
 }
+
+#[test]
+pub fn decodes_player_states() {
+    let player: Player = serde_json::from_str(
+        r#"{"steamid":"76561197960435530","communityvisibilitystate":3,"profilestate":1,"personaname":"Robin","profileurl":"https://steamcommunity.com/id/robinwalker/","avatar":"","avatarmedium":"","avatarfull":"","avatarhash":"","personastate":9}"#,
+    )
+    .unwrap();
+
+    assert_eq!(player.persona_state, PersonaState::Unknown(9));
+    assert_eq!(
+        player.community_visibility_state,
+        CommunityVisibility::Public
+    );
+    assert_eq!(player.profile_state, Some(ProfileState::Configured));
+    assert!(player.is_public());
+
+    let json = serde_json::to_value(&player).unwrap();
+    assert_eq!(json["personastate"], 9);
+    assert_eq!(json["communityvisibilitystate"], 3);
+}
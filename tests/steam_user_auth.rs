@@ -1,11 +1,11 @@
-use steam_rs::Steam;
+use steam_rs::{ids::AppId, Steam};
 
 mod common;
 
 #[tokio::test]
 pub async fn authenticate_user_ticket() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
-    let app_id = 12900; // Audiosurf
+    let app_id = AppId(12900); // Audiosurf
     let ticket = &std::env::var("STEAM_GAME_TICKET").unwrap_or(String::new());
 
     if !ticket.is_empty() {
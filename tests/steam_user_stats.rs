@@ -1,7 +1,7 @@
-use steam_rs::{steam_id::SteamId, Steam};
+use steam_rs::{ids::AppId, steam_id::SteamId, Steam};
 mod common;
 
-const EXAMPLE_APP_ID: u32 = 440; // Team Fortress 2
+const EXAMPLE_APP_ID: AppId = AppId(440); // Team Fortress 2
 const EXAMPLE_STEAM_ID_PUBLIC: SteamId = SteamId(76561198136162943); // Garrett Howard
 const EXAMPLE_STEAM_ID_PRIVATE: SteamId = SteamId(76561197960435530); // Robin Walker
 
@@ -25,7 +25,7 @@ pub async fn get_number_of_current_players() {
         .is_ok());
 
     // Error condition (nonexistent app)
-    assert!(steam.get_number_of_current_players(1).await.is_err());
+    assert!(steam.get_number_of_current_players(AppId(1)).await.is_err());
 }
 
 #[tokio::test]
@@ -34,13 +34,13 @@ pub async fn get_player_achievements() {
 
     // Expected result (public profile)
     assert!(steam
-        .get_player_achievements(EXAMPLE_STEAM_ID_PUBLIC, EXAMPLE_APP_ID, None)
+        .get_player_achievements(EXAMPLE_STEAM_ID_PUBLIC, EXAMPLE_APP_ID)
         .await
         .is_ok());
 
     // Error condition (private profile)
     assert!(steam
-        .get_player_achievements(EXAMPLE_STEAM_ID_PRIVATE, EXAMPLE_APP_ID, None)
+        .get_player_achievements(EXAMPLE_STEAM_ID_PRIVATE, EXAMPLE_APP_ID)
         .await
         .is_err());
 }
@@ -50,7 +50,7 @@ pub async fn get_schema_for_game() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
 
     assert!(steam
-        .get_schema_for_game(EXAMPLE_APP_ID, None)
+        .get_schema_for_game(EXAMPLE_APP_ID)
         .await
         .is_ok());
 }
@@ -60,7 +60,7 @@ pub async fn get_schema_for_game() {
 pub async fn get_global_stats_for_game() {
     let steam = Steam::new(&std::env::var("STEAM_API_KEY").expect("Missing an API key"));
 
-    let request = steam.get_global_stats_for_game(3247750, 3, vec!["targetsKilled".to_string(), "damageTaken".to_string(), "playersKilled".to_string()]).await.unwrap();
+    let request = steam.get_global_stats_for_game(AppId(3247750), 3, vec!["targetsKilled".to_string(), "damageTaken".to_string(), "playersKilled".to_string()]).await.unwrap();
     println!("{:#?}", request);
     assert!(request.globalstats.get("playersKilled").is_some());
     assert!(request.globalstats.get("damageTaken").is_some());
@@ -4,7 +4,7 @@
 
 #[tokio::test]
 pub async fn get_server_info() {
-    println!("{:?}", Steam::get_server_info().await.unwrap());
+    println!("{:?}", Steam::default().get_server_info().await.unwrap());
 }
 
 #[tokio::test]
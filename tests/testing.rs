@@ -0,0 +1,392 @@
+#![cfg(feature = "testing")]
+
+use std::time::Duration;
+
+use steam_rs::{
+    errors::SteamError,
+    ids::{AppId, PublishedFileId, TradeId},
+    published_file_service::query_files::PublishedFileQueryType,
+    retry::RetryPolicy,
+    steam_id::{ResolveSteamIdError, SteamId, SteamIdForm},
+    steam_user::get_player_summaries::{
+        CommentPermission, CommunityVisibility, PersonaState, PlayerProfile,
+    },
+    testing::{seed, Fault, Game, Simulator, User, World},
+    Steam,
+};
+
+#[tokio::test]
+pub async fn serves_users() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator.client();
+
+    let players = steam
+        .get_player_summaries(vec![seed::GABE, seed::PRIVATE])
+        .await
+        .unwrap();
+    assert_eq!(players.len(), 2);
+    assert_eq!(players[0].real_name.as_deref(), Some("Gabe Newell"));
+    assert_eq!(players[0].persona_state, PersonaState::Online);
+    assert_eq!(
+        players[1].community_visibility_state,
+        CommunityVisibility::Private
+    );
+    assert_eq!(players[1].real_name, None);
+
+    let profiles: Vec<PlayerProfile> = players.into_iter().map(PlayerProfile::from).collect();
+    let PlayerProfile::Public(summary, details) = &profiles[0] else {
+        panic!("expected a public profile");
+    };
+    assert_eq!(summary.comment_permission, Some(CommentPermission::Public));
+    assert_eq!(details.real_name.as_deref(), Some("Gabe Newell"));
+    assert!(
+        matches!(&profiles[1], PlayerProfile::Private(summary) if summary.steam_id == seed::PRIVATE)
+    );
+    assert!(profiles[1].details().is_none());
+
+    let friends = steam.get_friend_list(seed::GABE).send().await.unwrap();
+    assert_eq!(friends.len(), 1);
+    assert_eq!(friends[0].steam_id, seed::ROBIN);
+
+    let bans = steam.get_player_bans(vec![seed::BANNED]).await.unwrap();
+    assert!(bans[0].vac_banned);
+    assert_eq!(bans[0].number_of_vac_bans, 2);
+
+    let resolved = steam
+        .resolve_vanity_url("gabelogannewell")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resolved.steamid, Some(seed::GABE));
+    let unresolved = steam.resolve_vanity_url("nobody").send().await.unwrap();
+    assert_eq!(unresolved.success, 42);
+}
+
+#[tokio::test]
+pub async fn resolves_steam_ids() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator.client();
+
+    for input in [
+        "https://steamcommunity.com/id/gabelogannewell/",
+        "gabelogannewell",
+        &format!("steamcommunity.com/profiles/{}", seed::GABE),
+    ] {
+        assert_eq!(steam.resolve_steam_id(input).await.unwrap(), seed::GABE);
+    }
+
+    let error = steam.resolve_steam_id("nobody").await.unwrap_err();
+    assert!(matches!(
+        error,
+        ResolveSteamIdError::NoMatch { form: SteamIdForm::VanityName, name } if name == "nobody"
+    ));
+
+    let error = steam
+        .resolve_steam_id("[A:1:2:99999999999999999999999]")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        ResolveSteamIdError::Invalid {
+            form: SteamIdForm::SteamId3,
+            ..
+        }
+    ));
+
+    // Only vanity names are sent to Steam.
+    assert_eq!(simulator.requests().len(), 3);
+}
+
+#[tokio::test]
+pub async fn serves_games() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator.client();
+
+    let owned = steam
+        .get_owned_games(seed::GABE)
+        .include_appinfo(true)
+        .appids_filter(&[AppId(440), AppId(570)])
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(owned.game_count, 2);
+    assert_eq!(owned.games[0].name.as_deref(), Some("Team Fortress 2"));
+
+    let recent = steam
+        .get_recently_played_games(seed::GABE)
+        .count(1)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(recent.total_count, 2);
+    assert_eq!(recent.games.len(), 1);
+    assert_eq!(recent.games[0].playtime_2weeks, Some(90));
+
+    assert_eq!(steam.get_steam_level(seed::ROBIN).await.unwrap(), 30);
+}
+
+#[tokio::test]
+pub async fn serves_workshop_files() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator.client();
+
+    let files = steam
+        .query_files(PublishedFileQueryType::RankedByPublicationDate)
+        .app_id(AppId(440))
+        .numperpage(10)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(files.total, 3);
+    assert_eq!(files.published_file_details[0].title, "Hats and maps");
+
+    let files = steam
+        .query_files(PublishedFileQueryType::RankedByPublicationDate)
+        .required_tags(&["Hat", "Map"])
+        .excluded_tags(&["Map"])
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(files.total, 1);
+    assert_eq!(
+        files.published_file_details[0].title,
+        "Towering Pillar of Hats"
+    );
+
+    let details = steam
+        .get_published_file(&[seed::HAT, PublishedFileId(404)])
+        .await
+        .unwrap();
+    assert_eq!(details.result_count, 2);
+    assert_eq!(details.published_file_details[0].result, 1);
+    assert_eq!(details.published_file_details[1].result, 9);
+
+    let collection = steam
+        .get_collection_details(&[seed::COLLECTION])
+        .await
+        .unwrap();
+    assert_eq!(collection.collection_details[0].result, 1);
+}
+
+#[tokio::test]
+pub async fn serves_trade_offers() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator.client();
+
+    let offers = steam
+        .get_trade_offers()
+        .get_received_offers(true)
+        .send()
+        .await
+        .unwrap();
+    let received = offers.trade_offers_received.unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].message, "Hat for a key?");
+    assert_eq!(received[0].partner(), seed::ROBIN);
+
+    let offer = steam
+        .get_trade_offer(seed::SENT_OFFER)
+        .send()
+        .await
+        .unwrap();
+    assert!(offer.is_our_offer);
+    assert_eq!(offer.partner(), seed::BANNED);
+
+    let summary = steam.get_trade_offers_summary().send().await.unwrap();
+    assert_eq!(summary.pending_sent_count, Some(1));
+}
+
+#[tokio::test]
+pub async fn serves_trade_history() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator.client();
+
+    let history = steam
+        .get_trade_history(10)
+        .include_total(true)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(history.total_trades, 0);
+
+    // 3 is `k_ETradeOfferStateAccepted`.
+    simulator.update(|world| world.trade_offers[0].state = 3);
+    let history = steam
+        .get_trade_history(10)
+        .include_total(true)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(history.total_trades, 1);
+    assert!(!history.more);
+    assert_eq!(history.trades[0].tradeid, TradeId(seed::RECEIVED_OFFER.0));
+    assert_eq!(history.trades[0].steamid_other, seed::ROBIN);
+    assert_eq!(history.trades[0].assets_given.as_ref().unwrap().len(), 1);
+}
+
+#[tokio::test]
+pub async fn serves_groups_and_badges() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator.client();
+
+    let groups = steam.get_user_group_list(seed::GABE).await.unwrap();
+    assert!(groups.success);
+    assert_eq!(
+        groups.groups[0].steam_id,
+        SteamId::from_group_id(seed::VALVE).unwrap()
+    );
+
+    let badges = steam.get_badges(seed::ROBIN).await.unwrap();
+    assert_eq!(badges.player_level, 30);
+    assert_eq!(badges.player_xp, 6_000);
+    assert_eq!(badges.player_xp_needed_to_level_up, 400);
+
+    let progress = steam
+        .get_community_badge_progress(seed::GABE)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(progress.quests.unwrap().len(), 0);
+}
+
+#[tokio::test]
+pub async fn serves_apps_and_api_list() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator.client();
+
+    let apps = steam.get_app_list().await.unwrap();
+    let app_ids: Vec<AppId> = apps.apps.iter().map(|app| app.appid).collect();
+    assert_eq!(app_ids, [AppId(220), AppId(440), AppId(570)]);
+
+    let server_info = steam.get_server_info().await.unwrap();
+    assert!(server_info.server_time > 1_700_000_000);
+
+    let api_list = steam.get_supported_api_list().await.unwrap();
+    let econ = api_list
+        .interfaces
+        .iter()
+        .find(|interface| interface.name == "IEconService")
+        .unwrap();
+    assert!(econ
+        .methods
+        .iter()
+        .any(|method| method.name == "GetTradeHistory"));
+}
+
+#[tokio::test]
+pub async fn splits_bulk_calls() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator.builder().max_concurrent_requests(2).build();
+
+    // 250 unknown IDs put the seeded users past the first 100, which Steam would drop.
+    let mut steam_ids: Vec<SteamId> = (1..=250)
+        .map(|id| SteamId(76561199000000000 + id))
+        .collect();
+    steam_ids.extend([seed::GABE, seed::BANNED, seed::GABE]);
+
+    let players = steam.get_player_summaries(steam_ids.clone()).await.unwrap();
+    assert_eq!(players.len(), 2);
+    assert_eq!(players[0].steam_id, seed::GABE);
+    assert_eq!(simulator.requests().len(), 3);
+    let sent: usize = simulator
+        .requests()
+        .iter()
+        .map(|request| request.param("steamids").unwrap().split(',').count())
+        .sum();
+    assert_eq!(sent, 252);
+
+    let bans = steam.get_player_bans_by_id(steam_ids).await.unwrap();
+    assert_eq!(bans.len(), 252);
+    assert!(bans[&seed::BANNED].as_ref().unwrap().vac_banned);
+    assert!(bans[&SteamId(76561199000000001)].is_none());
+}
+
+#[tokio::test]
+pub async fn failed_chunks_fail_bulk_calls() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator
+        .builder()
+        .retry_policy(RetryPolicy::none())
+        .build();
+
+    simulator.inject(Fault::ServerError);
+    let steam_ids: Vec<SteamId> = (1..=150)
+        .map(|id| SteamId(76561199000000000 + id))
+        .collect();
+    assert!(steam.get_player_summaries(steam_ids).await.is_err());
+}
+
+#[tokio::test]
+pub async fn reports_private_profiles() {
+    let simulator = Simulator::start(World::seeded()).await;
+
+    let result = simulator
+        .client()
+        .get_friend_list(seed::PRIVATE)
+        .send()
+        .await;
+
+    assert!(matches!(result, Err(SteamError::PrivateProfile)));
+}
+
+#[tokio::test]
+pub async fn rejects_other_keys() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = Steam::builder()
+        .api_key("WRONG")
+        .base_url(simulator.url())
+        .build();
+
+    let result = steam.get_steam_level(seed::GABE).await;
+
+    assert!(matches!(result, Err(SteamError::Unauthorized { .. })));
+}
+
+#[tokio::test]
+pub async fn injects_faults() {
+    let simulator = Simulator::start(World::seeded()).await;
+    let steam = simulator
+        .builder()
+        .retry_policy(
+            RetryPolicy::default()
+                .base_delay(Duration::from_millis(1))
+                .jitter(false),
+        )
+        .build();
+
+    simulator.inject(Fault::ServerError);
+    simulator.inject_on("GetSteamLevel", Fault::RateLimited { retry_after: None });
+    assert_eq!(steam.get_steam_level(seed::GABE).await.unwrap(), 50);
+    assert_eq!(simulator.requests().len(), 3);
+
+    simulator.inject_on("GetFriendList", Fault::Status(503));
+    for _ in 0..3 {
+        simulator.inject(Fault::RateLimited {
+            retry_after: Some(0),
+        });
+    }
+    assert!(steam.get_steam_level(seed::GABE).await.is_err());
+    assert!(steam.get_friend_list(seed::GABE).send().await.is_ok());
+}
+
+#[tokio::test]
+pub async fn applies_updates() {
+    let simulator = Simulator::start(World::new()).await;
+    let steam = simulator.client();
+    let steam_id = SteamId(76561198000000100);
+
+    simulator.update(|world| {
+        world
+            .users
+            .push(User::new(steam_id, "Newcomer").game(Game::new(AppId(730), "Counter-Strike 2")));
+    });
+    let owned = steam.get_owned_games(steam_id).send().await.unwrap();
+    assert_eq!(owned.games[0].appid, AppId(730));
+
+    simulator.update(|world| world.user_mut(steam_id).unwrap().public = false);
+    assert!(steam.get_steam_level(steam_id).await.is_err());
+
+    let request = simulator.requests().pop().unwrap();
+    assert_eq!(request.path, "/IPlayerService/GetSteamLevel/v1/");
+    assert_eq!(request.param("steamid"), Some("76561198000000100"));
+}
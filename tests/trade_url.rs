@@ -0,0 +1,59 @@
+use steam_rs::{
+    econ_service::trade_url::{TradeUrl, TradeUrlError},
+    steam_id::SteamId,
+};
+
+const PARTNER: SteamId = SteamId(76561197960287930);
+const URL: &str = "https://steamcommunity.com/tradeoffer/new/?partner=22202&token=AbCd-_12";
+
+#[test]
+fn parses_trade_urls() {
+    for input in [
+        URL,
+        "steamcommunity.com/tradeoffer/new?partner=22202&token=AbCd-_12",
+        "http://www.steamcommunity.com/tradeoffer/new/?token=AbCd-_12&partner=22202#top",
+    ] {
+        let url: TradeUrl = input.parse().unwrap();
+        assert_eq!(url.partner(), PARTNER, "{input}");
+        assert_eq!(url.token(), "AbCd-_12", "{input}");
+    }
+}
+
+#[test]
+fn builds_trade_urls() {
+    let url = TradeUrl::new(PARTNER, "AbCd-_12").unwrap();
+    assert_eq!(url.to_string(), URL);
+    assert_eq!(TradeUrl::parse(&url.to_string()), Ok(url));
+}
+
+#[test]
+fn rejects_invalid_trade_urls() {
+    assert_eq!(
+        TradeUrl::parse("https://steamcommunity.com/id/gabelogannewell"),
+        Err(TradeUrlError::NotATradeUrl(
+            "https://steamcommunity.com/id/gabelogannewell".to_string()
+        ))
+    );
+    assert!(matches!(
+        TradeUrl::parse("https://s.team/tradeoffer/new/?partner=22202&token=AbCd1234"),
+        Err(TradeUrlError::NotATradeUrl(_))
+    ));
+    assert_eq!(
+        TradeUrl::parse("https://steamcommunity.com/tradeoffer/new/?partner=gabe&token=AbCd1234"),
+        Err(TradeUrlError::InvalidPartner("gabe".to_string()))
+    );
+    assert_eq!(
+        TradeUrl::parse("https://steamcommunity.com/tradeoffer/new/?partner=22202"),
+        Err(TradeUrlError::MissingToken)
+    );
+    assert_eq!(
+        TradeUrl::parse("https://steamcommunity.com/tradeoffer/new/?partner=22202&token=short"),
+        Err(TradeUrlError::InvalidToken("short".to_string()))
+    );
+
+    // Groups can't trade.
+    assert!(matches!(
+        TradeUrl::new(SteamId(103582791429521412), "AbCd1234"),
+        Err(TradeUrlError::InvalidPartner(_))
+    ));
+}
@@ -0,0 +1,130 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use reqwest::{Method, StatusCode};
+use steam_rs::{
+    errors::SteamError,
+    ids::PublishedFileId,
+    retry::RetryPolicy,
+    transport::{BoxFuture, HttpRequest, HttpResponse, Transport, TransportError},
+    Steam,
+};
+
+const SERVER_INFO: &str =
+    r#"{"servertime":1700000000,"servertimestring":"Tue Nov 14 22:13:20 2023"}"#;
+
+/// Serves canned responses in order, repeating the last one, and records every request.
+#[derive(Debug, Clone, Default)]
+struct Canned {
+    responses: Arc<Mutex<Vec<Result<HttpResponse, String>>>>,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl Canned {
+    fn new(responses: Vec<Result<HttpResponse, String>>) -> Self {
+        Canned {
+            responses: Arc::new(Mutex::new(responses)),
+            ..Default::default()
+        }
+    }
+
+    fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Transport for Canned {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, TransportError>> {
+        self.requests.lock().unwrap().push(request);
+
+        let mut responses = self.responses.lock().unwrap();
+        let response = if responses.len() > 1 {
+            responses.remove(0)
+        } else {
+            responses[0].clone()
+        };
+
+        Box::pin(async move {
+            response
+                .map_err(|message| io::Error::new(io::ErrorKind::ConnectionRefused, message).into())
+        })
+    }
+}
+
+#[tokio::test]
+pub async fn requests_go_through_transport() {
+    let transport = Canned::new(vec![Ok(HttpResponse::new(StatusCode::OK, SERVER_INFO))]);
+    let steam = Steam::builder()
+        .base_url("http://steam.invalid")
+        .transport(transport.clone())
+        .build();
+
+    let info = steam.get_server_info().await.unwrap();
+    assert_eq!(info.server_time, 1700000000);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, Method::GET);
+    assert_eq!(
+        requests[0].url,
+        "http://steam.invalid/ISteamWebAPIUtil/GetServerInfo/v1/"
+    );
+}
+
+#[tokio::test]
+pub async fn post_requests_carry_body() {
+    let transport = Canned::new(vec![Ok(HttpResponse::new(
+        StatusCode::OK,
+        r#"{"response":{"result":1,"resultcount":0,"collectiondetails":[]}}"#,
+    ))]);
+    let steam = Steam::builder().transport(transport.clone()).build();
+
+    steam
+        .get_collection_details(&[PublishedFileId(1)])
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, Method::POST);
+    assert!(requests[0].body.is_some());
+}
+
+#[tokio::test]
+pub async fn statuses_are_interpreted() {
+    let transport = Canned::new(vec![Ok(HttpResponse::new(StatusCode::FORBIDDEN, ""))]);
+    let steam = Steam::builder().transport(transport).build();
+
+    assert!(matches!(
+        steam.get_server_info().await.unwrap_err(),
+        SteamError::Unauthorized { .. }
+    ));
+}
+
+#[tokio::test]
+pub async fn transport_errors_are_retried() {
+    let transport = Canned::new(vec![
+        Err("connection refused".to_string()),
+        Ok(HttpResponse::new(StatusCode::OK, SERVER_INFO)),
+    ]);
+    let steam = Steam::builder()
+        .transport(transport.clone())
+        .retry_policy(RetryPolicy::default().base_delay(Duration::from_millis(1)))
+        .build();
+
+    assert!(steam.get_server_info().await.is_ok());
+    assert_eq!(transport.requests().len(), 2);
+
+    let transport = Canned::new(vec![Err("connection refused".to_string())]);
+    let steam = Steam::builder()
+        .transport(transport)
+        .retry_policy(RetryPolicy::none())
+        .build();
+
+    assert!(matches!(
+        steam.get_server_info().await.unwrap_err(),
+        SteamError::Network(_)
+    ));
+}
@@ -0,0 +1,151 @@
+use std::{fs, path::PathBuf, time::Duration};
+
+use steam_rs::{
+    errors::SteamError,
+    retry::RetryPolicy,
+    steam_id::SteamId,
+    vcr::{Cassette, NoRecording, Recorder, Replayer, REDACTED},
+    Steam,
+};
+
+mod common;
+
+use common::{MockResponse, MockServer};
+
+const SERVER_INFO: &str =
+    r#"{"servertime":1700000000,"servertimestring":"Tue Nov 14 22:13:20 2023"}"#;
+const FRIEND_LIST: &str = r#"{"friendslist":{"friends":[{"steamid":"76561197960435530","relationship":"friend","friend_since":1200000000}]}}"#;
+
+fn cassette_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("steam-rs-{}-{name}.json", std::process::id()))
+}
+
+#[tokio::test]
+pub async fn records_and_replays() {
+    let path = cassette_path("records_and_replays");
+    let server = MockServer::start(vec![
+        MockResponse::ok(FRIEND_LIST),
+        MockResponse::ok(SERVER_INFO),
+    ])
+    .await;
+    let steam = Steam::builder()
+        .api_key("SECRET")
+        .base_url(&server.url)
+        .transport(Recorder::new(&path))
+        .build();
+
+    steam
+        .get_friend_list(SteamId(76561197960287930))
+        .send()
+        .await
+        .unwrap();
+    steam.get_server_info().await.unwrap();
+
+    let recorded = fs::read_to_string(&path).unwrap();
+    assert!(!recorded.contains("SECRET"));
+    let cassette = Cassette::load(&path).unwrap();
+    assert_eq!(cassette.interactions.len(), 2);
+    let request = &cassette.interactions[0].request;
+    assert_eq!(request.path, "/ISteamUser/GetFriendList/v1/");
+    assert_eq!(request.params["key"], REDACTED);
+    assert_eq!(request.params["steamid"], "76561197960287930");
+
+    // Replaying needs neither the server nor the same key.
+    let steam = Steam::builder()
+        .api_key("ANOTHER")
+        .base_url("http://steam.invalid")
+        .transport(Replayer::open(&path).unwrap())
+        .build();
+
+    let friends = steam
+        .get_friend_list(SteamId(76561197960287930))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(friends[0].steam_id, SteamId(76561197960435530));
+    assert_eq!(
+        steam.get_server_info().await.unwrap().server_time,
+        1700000000
+    );
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+pub async fn replays_repeated_requests_in_order() {
+    let path = cassette_path("replays_repeated_requests_in_order");
+    let server = MockServer::start(vec![
+        MockResponse::status(429, "").header("Retry-After", "0"),
+        MockResponse::ok(SERVER_INFO),
+    ])
+    .await;
+    let policy = RetryPolicy::default()
+        .base_delay(Duration::from_millis(1))
+        .jitter(false);
+    let recorder = Steam::builder()
+        .base_url(&server.url)
+        .retry_policy(policy.clone())
+        .transport(Recorder::new(&path))
+        .build();
+    recorder.get_server_info().await.unwrap();
+
+    let cassette = Cassette::load(&path).unwrap();
+    assert_eq!(cassette.interactions[0].response.status, 429);
+    assert_eq!(cassette.interactions[1].response.status, 200);
+
+    let steam = Steam::builder()
+        .retry_policy(policy)
+        .transport(Replayer::new(cassette))
+        .build();
+    assert!(steam.get_server_info().await.is_ok());
+    // The last recording keeps answering once the others are used up.
+    assert!(steam.get_server_info().await.is_ok());
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+pub async fn records_concurrent_requests() {
+    let path = cassette_path("records_concurrent_requests");
+    let server = MockServer::start(vec![MockResponse::ok(SERVER_INFO)]).await;
+    let steam = Steam::builder()
+        .base_url(&server.url)
+        .transport(Recorder::new(&path))
+        .build();
+
+    let mut requests = tokio::task::JoinSet::new();
+    for _ in 0..8 {
+        let steam = steam.clone();
+        requests.spawn(async move { steam.get_server_info().await });
+    }
+    while let Some(result) = requests.join_next().await {
+        result.unwrap().unwrap();
+    }
+
+    let cassette = Cassette::load(&path).unwrap();
+    assert_eq!(cassette.interactions.len(), 8);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+pub async fn unrecorded_requests_fail() {
+    let steam = Steam::builder()
+        .transport(Replayer::new(Cassette::default()))
+        .build();
+
+    let Err(SteamError::Network(error)) = steam
+        .get_friend_list(SteamId(76561197960287930))
+        .send()
+        .await
+    else {
+        panic!("expected a network error");
+    };
+
+    let missing = error.downcast_ref::<NoRecording>().unwrap();
+    assert_eq!(missing.request.path, "/ISteamUser/GetFriendList/v1/");
+    assert_eq!(
+        missing.to_string(),
+        "No recorded response for GET /ISteamUser/GetFriendList/v1/?key=REDACTED&steamid=76561197960287930"
+    );
+}